@@ -0,0 +1,150 @@
+use crate::is_leap_year;
+
+/// The number of days in a month in both common and leap years.
+const DAYS_IN_MONTH_COMMON_LEAP: [[u16; 12]; 2] = [
+    [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+    [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+];
+
+/// Cumulative days through the beginning of a month in both common and leap
+/// years.
+const DAYS_CUMULATIVE_COMMON_LEAP: [[u16; 12]; 2] = [
+    [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334],
+    [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335],
+];
+
+/// The rules of a calendar system, expressed in terms of a year, month, and
+/// day, as well as the ordinal day of the year.
+///
+/// `Date` stores its value as a year and ordinal day, and uses an
+/// implementation of this trait to convert between that and a month and day.
+/// Other operations, such as the number of days in a month, the Julian day
+/// conversions, and ISO week numbering, are implemented directly in terms of
+/// the proleptic Gregorian calendar. Callers are responsible for ensuring that the month and day values
+/// passed in are valid for the calendar.
+pub(crate) trait Calendar {
+    /// Returns if the provided year is a leap year.
+    fn is_leap_year(year: i32) -> bool;
+
+    /// Get the number of calendar days in a given year.
+    #[inline(always)]
+    fn days_in_year(year: i32) -> u16 {
+        365 + Self::is_leap_year(year) as u16
+    }
+
+    /// Get the ordinal day of the year from the month and day.
+    fn ordinal_from_month_day(year: i32, month: u8, day: u8) -> u16;
+
+    /// Get the month and day from the ordinal day of the year.
+    fn month_day_from_ordinal(year: i32, ordinal: u16) -> (u8, u8);
+}
+
+/// The proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Gregorian;
+
+impl Gregorian {
+    /// Get the number of days in the month of a given year. This is not part
+    /// of `Calendar` so that it can be a `const fn`.
+    #[inline(always)]
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn days_in_year_month(year: i32, month: u8) -> u8 {
+        DAYS_IN_MONTH_COMMON_LEAP[is_leap_year(year) as usize][month as usize - 1] as u8
+    }
+}
+
+impl Calendar for Gregorian {
+    #[inline(always)]
+    fn is_leap_year(year: i32) -> bool {
+        is_leap_year(year)
+    }
+
+    #[inline(always)]
+    fn ordinal_from_month_day(year: i32, month: u8, day: u8) -> u16 {
+        DAYS_CUMULATIVE_COMMON_LEAP[Self::is_leap_year(year) as usize][month as usize - 1]
+            + day as u16
+    }
+
+    #[inline]
+    fn month_day_from_ordinal(year: i32, mut ordinal: u16) -> (u8, u8) {
+        let days = DAYS_IN_MONTH_COMMON_LEAP[Self::is_leap_year(year) as usize];
+        let mut month = 0;
+        let month = loop {
+            if ordinal <= days[month] {
+                break month;
+            }
+            ordinal -= days[month];
+            month += 1;
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        (month as u8 + 1, ordinal as u8)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Date;
+
+    #[test]
+    fn is_leap_year() {
+        for &year in &[
+            -400, -100, -4, -1, 0, 1, 4, 100, 400, 1900, 2000, 2019, 2020,
+        ] {
+            assert_eq!(Gregorian::is_leap_year(year), super::is_leap_year(year));
+        }
+        assert!(Gregorian::is_leap_year(2000));
+        assert!(!Gregorian::is_leap_year(2100));
+    }
+
+    #[test]
+    fn days_in_year() {
+        for year in -500..=2500 {
+            assert_eq!(Gregorian::days_in_year(year), crate::days_in_year(year));
+        }
+    }
+
+    #[test]
+    fn days_in_year_month() {
+        assert_eq!(Gregorian::days_in_year_month(2019, 2), 28);
+        assert_eq!(Gregorian::days_in_year_month(2020, 2), 29);
+        assert_eq!(Gregorian::days_in_year_month(2100, 2), 28);
+        assert_eq!(Gregorian::days_in_year_month(2019, 12), 31);
+
+        for &year in &[2019, 2020] {
+            let total: u16 = (1..=12)
+                .map(|month| Gregorian::days_in_year_month(year, month) as u16)
+                .sum();
+            assert_eq!(total, crate::days_in_year(year));
+        }
+    }
+
+    #[test]
+    fn ordinal_month_day_round_trip() {
+        for &year in &[1900, 2000, 2019, 2020] {
+            let mut ordinal = 0;
+            for month in 1..=12 {
+                for day in 1..=Gregorian::days_in_year_month(year, month) {
+                    ordinal += 1;
+                    assert_eq!(Gregorian::ordinal_from_month_day(year, month, day), ordinal);
+                    assert_eq!(
+                        Gregorian::month_day_from_ordinal(year, ordinal),
+                        (month, day)
+                    );
+                }
+            }
+            assert_eq!(ordinal, crate::days_in_year(year));
+        }
+    }
+
+    #[test]
+    fn date_behavior_unchanged() {
+        assert_eq!(Date::from_ymd(2019, 3, 1).ordinal(), 60);
+        assert_eq!(Date::from_ymd(2020, 3, 1).ordinal(), 61);
+        assert_eq!(Date::from_ymd(2020, 12, 31).ordinal(), 366);
+        assert_eq!(Date::from_yo(2019, 60).month_day(), (3, 1));
+        assert_eq!(Date::from_yo(2020, 60).month_day(), (2, 29));
+        assert_eq!(Date::from_yo(2020, 366).month_day(), (12, 31));
+    }
+}
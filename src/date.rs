@@ -1,12 +1,17 @@
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
 use crate::{
-    format::parse::{parse, ParseError, ParseResult, ParsedItems},
+    calendar::{Calendar, Gregorian},
+    format::parse::{
+        parse, parse_any, parse_strict, parse_with_position, Component, ParseError, ParseErrorAt,
+        ParseResult, ParsedItems,
+    },
     DateTime, DeferredFormat, Duration, Language, Time,
     Weekday::{self, Friday, Monday, Saturday, Sunday, Thursday, Tuesday, Wednesday},
 };
 use core::{
     cmp::{Ord, Ordering, PartialOrd},
+    iter::successors,
     ops::{Add, AddAssign, Sub, SubAssign},
     time::Duration as StdDuration,
 };
@@ -15,17 +20,16 @@ use core::{
 // are explicitly not (and have linting disabled) as it could lead to
 // compatibility issues down the road if the internal structure is changed.
 
-/// The number of days in a month in both common and leap years.
-const DAYS_IN_MONTH_COMMON_LEAP: [[u16; 12]; 2] = [
-    [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
-    [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
-];
-
 /// Get the number of days in the month of a given year.
 #[inline(always)]
-#[allow(clippy::cast_possible_truncation)]
 const fn days_in_year_month(year: i32, month: u8) -> u8 {
-    DAYS_IN_MONTH_COMMON_LEAP[is_leap_year(year) as usize][month as usize - 1] as u8
+    Gregorian::days_in_year_month(year, month)
+}
+
+/// Get the number of days from `first_day` to `weekday`, going forward.
+#[inline(always)]
+fn days_since(weekday: Weekday, first_day: Weekday) -> u8 {
+    (weekday.number_days_from_monday() + 7 - first_day.number_days_from_monday()) % 7
 }
 
 /// Returns if the provided year is a leap year in the proleptic Gregorian
@@ -88,7 +92,9 @@ pub fn weeks_in_year(year: i32) -> u8 {
 /// that can change at any time without notice. If you need support outside this
 /// range, please [file an issue](https://github.com/time-rs/time/issues/new)
 /// with your use case.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///
+/// Only the year and day of the year are stored, so two equal dates always hash
+/// equally, regardless of how they were constructed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Date {
     #[allow(clippy::missing_docs_in_private_items)]
@@ -117,21 +123,12 @@ impl Date {
     /// ```
     #[inline]
     pub fn from_ymd(year: i32, month: u8, day: u8) -> Self {
-        /// Cumulative days through the beginning of a month in both common and
-        /// leap years.
-        const DAYS_CUMULATIVE_COMMON_LEAP: [[u16; 12]; 2] = [
-            [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334],
-            [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335],
-        ];
-
         assert_value_in_range!(month in 1 => 12);
         assert_value_in_range!(day in 1 => days_in_year_month(year, month), given year, month);
 
-        let ordinal = DAYS_CUMULATIVE_COMMON_LEAP[is_leap_year(year) as usize][month as usize - 1];
-
         Self {
             year,
-            ordinal: ordinal + day as u16,
+            ordinal: Gregorian::ordinal_from_month_day(year, month, day),
         }
     }
 
@@ -151,7 +148,7 @@ impl Date {
     /// ```
     #[inline(always)]
     pub fn from_yo(year: i32, ordinal: u16) -> Self {
-        assert_value_in_range!(ordinal in 1 => days_in_year(year), given year);
+        assert_value_in_range!(ordinal in 1 => Gregorian::days_in_year(year), given year);
         Self { year, ordinal }
     }
 
@@ -183,18 +180,23 @@ impl Date {
     pub fn from_iso_ywd(year: i32, week: u8, weekday: Weekday) -> Self {
         assert_value_in_range!(week in 1 => weeks_in_year(year), given year);
 
-        let ordinal = week as u16 * 7 + weekday.iso_weekday_number() as u16
-            - (Self::from_yo(year, 4).weekday().iso_weekday_number() as u16 + 3);
+        // The ordinal may be zero or negative when the date falls in the
+        // previous calendar year, so signed arithmetic is necessary.
+        let ordinal = week as i16 * 7 + weekday.iso_weekday_number() as i16
+            - (Self::from_yo(year, 4).weekday().iso_weekday_number() as i16 + 3);
 
-        if ordinal < 1 {
-            return Self::from_yo(year - 1, ordinal + days_in_year(year - 1));
-        }
+        #[allow(clippy::cast_sign_loss)]
+        {
+            if ordinal < 1 {
+                return Self::from_yo(year - 1, (ordinal + days_in_year(year - 1) as i16) as u16);
+            }
 
-        let days_in_cur_year = days_in_year(year);
-        if ordinal > days_in_cur_year {
-            Self::from_yo(year + 1, ordinal - days_in_cur_year)
-        } else {
-            Self::from_yo(year, ordinal)
+            let days_in_cur_year = days_in_year(year);
+            if ordinal as u16 > days_in_cur_year {
+                Self::from_yo(year + 1, ordinal as u16 - days_in_cur_year)
+            } else {
+                Self::from_yo(year, ordinal as u16)
+            }
         }
     }
 
@@ -269,19 +271,7 @@ impl Date {
     /// ```
     #[inline]
     pub fn month_day(self) -> (u8, u8) {
-        let mut ordinal = self.ordinal;
-        let days = DAYS_IN_MONTH_COMMON_LEAP[is_leap_year(self.year) as usize];
-        let mut month = 0;
-        let month = loop {
-            if ordinal <= days[month] {
-                break month;
-            }
-            ordinal -= days[month];
-            month += 1;
-        };
-
-        #[allow(clippy::cast_possible_truncation)]
-        (month as u8 + 1, ordinal as u8)
+        Gregorian::month_day_from_ordinal(self.year, self.ordinal)
     }
 
     /// Get the day of the year.
@@ -300,6 +290,36 @@ impl Date {
         self.ordinal
     }
 
+    /// Returns if the year of the date is a leap year in the proleptic
+    /// Gregorian calendar.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert!(!Date::from_ymd(1900, 1, 1).is_leap_year());
+    /// assert!(Date::from_ymd(2000, 1, 1).is_leap_year());
+    /// assert!(!Date::from_ymd(2019, 1, 1).is_leap_year());
+    /// ```
+    #[inline(always)]
+    pub fn is_leap_year(self) -> bool {
+        is_leap_year(self.year)
+    }
+
+    /// Get the number of days in the month of the date.
+    ///
+    /// The returned value will always be in the range `28..=31`.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(Date::from_ymd(2019, 1, 15).days_in_month(), 31);
+    /// assert_eq!(Date::from_ymd(2019, 2, 15).days_in_month(), 28);
+    /// assert_eq!(Date::from_ymd(2020, 2, 15).days_in_month(), 29);
+    /// assert_eq!(Date::from_ymd(2019, 4, 15).days_in_month(), 30);
+    /// ```
+    #[inline]
+    pub fn days_in_month(self) -> u8 {
+        days_in_year_month(self.year, self.month())
+    }
+
     /// Get the ISO 8601 year and week number.
     ///
     /// ```rust
@@ -420,31 +440,199 @@ impl Date {
     /// ```
     #[inline]
     pub fn weekday(self) -> Weekday {
-        let (month, day) = self.month_day();
-
-        let (month, adjusted_year) = if month < 3 {
-            (month + 12, self.year - 1)
-        } else {
-            (month, self.year)
-        };
+        /// The offset of each month, as used in Sakamoto's method.
+        const MONTH_OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
 
-        match (day as i32 + (13 * (month as i32 + 1)) / 5 + adjusted_year + adjusted_year / 4
-            - adjusted_year / 100
-            + adjusted_year / 400)
-            % 7
+        let (month, day) = self.month_day();
+        let year = if month < 3 { self.year - 1 } else { self.year };
+
+        // Euclidean division is necessary for the value to be correct for
+        // negative years.
+        match (year + year.div_euclid(4) - year.div_euclid(100)
+            + year.div_euclid(400)
+            + MONTH_OFFSETS[month as usize - 1]
+            + day as i32)
+            .rem_euclid(7)
         {
-            0 => Saturday,
-            1 => Sunday,
-            2 => Monday,
-            3 => Tuesday,
-            4 => Wednesday,
-            5 => Thursday,
-            6 => Friday,
+            0 => Sunday,
+            1 => Monday,
+            2 => Tuesday,
+            3 => Wednesday,
+            4 => Thursday,
+            5 => Friday,
+            6 => Saturday,
             // FIXME The compiler isn't able to optimize this away.
             _ => unreachable!("A value mod 7 is always in the range 0..7"),
         }
     }
 
+    /// Check if the date falls on a Saturday or Sunday.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert!(Date::from_ymd(2019, 6, 1).is_weekend());
+    /// assert!(!Date::from_ymd(2019, 6, 3).is_weekend());
+    /// ```
+    #[inline(always)]
+    pub fn is_weekend(self) -> bool {
+        match self.weekday() {
+            Saturday | Sunday => true,
+            Monday | Tuesday | Wednesday | Thursday | Friday => false,
+        }
+    }
+
+    /// Add the provided number of business days (Monday through Friday) to the
+    /// date. A negative value moves backwards. The result is always a business
+    /// day, except when `days` is zero, in which case the date is returned
+    /// unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the resulting year would overflow. Use
+    /// `checked_add_business_days` to handle this case.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// // 2019-06-07 is a Friday.
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 6, 7).add_business_days(1),
+    ///     Date::from_ymd(2019, 6, 10)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 6, 10).add_business_days(-1),
+    ///     Date::from_ymd(2019, 6, 7)
+    /// );
+    /// // Counting starts from the weekend itself.
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 6, 8).add_business_days(1),
+    ///     Date::from_ymd(2019, 6, 10)
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn add_business_days(self, days: i64) -> Self {
+        self.checked_add_business_days(days)
+            .expect("overflow when adding business days")
+    }
+
+    /// Add the provided number of business days (Monday through Friday) to the
+    /// date, returning `None` if the resulting year would overflow. This
+    /// otherwise behaves as `add_business_days`.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 6, 7).checked_add_business_days(1),
+    ///     Some(Date::from_ymd(2019, 6, 10))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 6, 7).checked_add_business_days(i64::max_value()),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn checked_add_business_days(self, days: i64) -> Option<Self> {
+        if days == 0 {
+            return Some(self);
+        }
+
+        // Move from a weekend to the adjacent business day that lies in the
+        // opposite direction, from which counting is equivalent.
+        let mut julian_day = self.julian_day();
+        let mut weekday = self.weekday().number_days_from_monday() as i64;
+        if weekday >= 5 {
+            if days > 0 {
+                julian_day -= weekday - 4;
+                weekday = 4;
+            } else {
+                julian_day += 7 - weekday;
+                weekday = 0;
+            }
+        }
+
+        // Every five business days is exactly one week. The remaining days
+        // cross a weekend if they would pass Friday or Monday.
+        let (weeks, remainder) = (days / 5, days % 5);
+        let mut julian_day = weeks
+            .checked_mul(7)
+            .and_then(|days| julian_day.checked_add(days + remainder))?;
+        if weekday + remainder > 4 {
+            julian_day = julian_day.checked_add(2)?;
+        } else if weekday + remainder < 0 {
+            julian_day = julian_day.checked_sub(2)?;
+        }
+
+        Self::checked_from_julian_day(julian_day)
+    }
+
+    /// Get the first date strictly after this one that falls on the provided
+    /// weekday. The result is always between one and seven days later.
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::*};
+    /// // 2019-06-07 is a Friday.
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 6, 7).next_weekday(Monday),
+    ///     Date::from_ymd(2019, 6, 10)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 6, 7).next_weekday(Friday),
+    ///     Date::from_ymd(2019, 6, 14)
+    /// );
+    /// ```
+    #[inline]
+    pub fn next_weekday(self, weekday: Weekday) -> Self {
+        self.next_day().next_or_same_weekday(weekday)
+    }
+
+    /// Get the first date on or after this one that falls on the provided
+    /// weekday. If the date already falls on that weekday, it is returned
+    /// unchanged.
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::*};
+    /// // 2019-06-07 is a Friday.
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 6, 7).next_or_same_weekday(Monday),
+    ///     Date::from_ymd(2019, 6, 10)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 6, 7).next_or_same_weekday(Friday),
+    ///     Date::from_ymd(2019, 6, 7)
+    /// );
+    /// ```
+    #[inline]
+    pub fn next_or_same_weekday(self, weekday: Weekday) -> Self {
+        let days = (weekday.number_days_from_monday() as i64
+            - self.weekday().number_days_from_monday() as i64)
+            .rem_euclid(7);
+        Self::from_julian_day(self.julian_day() + days)
+    }
+
+    /// Get the last date strictly before this one that falls on the provided
+    /// weekday. The result is always between one and seven days earlier.
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::*};
+    /// // 2019-06-07 is a Friday.
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 6, 7).previous_weekday(Monday),
+    ///     Date::from_ymd(2019, 6, 3)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 6, 7).previous_weekday(Friday),
+    ///     Date::from_ymd(2019, 5, 31)
+    /// );
+    /// ```
+    #[inline]
+    pub fn previous_weekday(self, weekday: Weekday) -> Self {
+        let days = (self.weekday().number_days_from_monday() as i64
+            - weekday.number_days_from_monday() as i64
+            - 1)
+        .rem_euclid(7)
+            + 1;
+        Self::from_julian_day(self.julian_day() - days)
+    }
+
     /// Get the next calendar date.
     ///
     /// ```rust
@@ -503,1491 +691,3465 @@ impl Date {
         self
     }
 
-    /// Get the Julian day for the date.
+    /// Get the next calendar date, returning `None` if the resulting year
+    /// would overflow.
     ///
     /// ```rust
-    /// # use time::Date;
-    /// assert_eq!(Date::from_ymd(-4713, 11, 24).julian_day(), 0);
-    /// assert_eq!(Date::from_ymd(2000, 1, 1).julian_day(), 2_451_545);
-    /// assert_eq!(Date::from_ymd(2019, 1, 1).julian_day(), 2_458_485);
-    /// assert_eq!(Date::from_ymd(2019, 12, 31).julian_day(), 2_458_849);
+    /// # use time::{days_in_year, Date};
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 12, 31).checked_next_day(),
+    ///     Some(Date::from_ymd(2020, 1, 1))
+    /// );
+    /// let last = Date::from_yo(i32::max_value(), days_in_year(i32::max_value()));
+    /// assert_eq!(last.checked_next_day(), None);
     /// ```
     #[inline]
-    pub fn julian_day(self) -> i64 {
-        let year = self.year as i64;
-        let (month, day) = self.month_day();
-        let month = month as i64;
-        let day = day as i64;
-        (1_461 * (year + 4_800 + (month - 14) / 12)) / 4
-            + (367 * (month - 2 - 12 * ((month - 14) / 12))) / 12
-            - (3 * ((year + 4_900 + (month - 14) / 12) / 100)) / 4
-            + day
-            - 32_075
+    pub fn checked_next_day(self) -> Option<Self> {
+        if self.ordinal == days_in_year(self.year) {
+            Some(Self {
+                year: self.year.checked_add(1)?,
+                ordinal: 1,
+            })
+        } else {
+            Some(Self {
+                ordinal: self.ordinal + 1,
+                ..self
+            })
+        }
     }
 
-    /// Create a `Date` from the Julian day.
-    ///
-    /// The algorithm to perform this conversion comes from E.G. Richards; it is
-    /// available in [section 15.11.3](https://aa.usno.navy.mil/publications/docs/c15_usb_online.pdf),
-    /// courtesy of the United States Naval Observatory.
+    /// Get the previous calendar date, returning `None` if the resulting year
+    /// would overflow.
     ///
     /// ```rust
     /// # use time::Date;
-    /// assert_eq!(Date::from_julian_day(0), Date::from_ymd(-4713, 11, 24));
-    /// assert_eq!(Date::from_julian_day(2_451_545), Date::from_ymd(2000, 1, 1));
-    /// assert_eq!(Date::from_julian_day(2_458_485), Date::from_ymd(2019, 1, 1));
     /// assert_eq!(
-    ///     Date::from_julian_day(2_458_849),
-    ///     Date::from_ymd(2019, 12, 31)
+    ///     Date::from_ymd(2020, 1, 1).checked_previous_day(),
+    ///     Some(Date::from_ymd(2019, 12, 31))
     /// );
+    /// assert_eq!(Date::from_yo(i32::min_value(), 1).checked_previous_day(), None);
     /// ```
     #[inline]
-    pub fn from_julian_day(julian_day: i64) -> Self {
-        #![allow(clippy::missing_docs_in_private_items)]
-        const Y: i64 = 4_716;
-        const J: i64 = 1_401;
-        const M: i64 = 2;
-        const N: i64 = 12;
-        const R: i64 = 4;
-        const P: i64 = 1_461;
-        const V: i64 = 3;
-        const U: i64 = 5;
-        const S: i64 = 153;
-        const W: i64 = 2;
-        const B: i64 = 274_277;
-        const C: i64 = -38;
-
-        let f = julian_day + J + (((4 * julian_day + B) / 146_097) * 3) / 4 + C;
-        let e = R * f + V;
-        let g = e.rem_euclid(P) / R;
-        let h = U * g + W;
-        let day = h.rem_euclid(S) / U + 1;
-        let month = (h / S + M).rem_euclid(N) + 1;
-        let year = (e / P) - Y + (N + M - month) / N;
-
-        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-        Self::from_ymd(year as i32, month as u8, day as u8)
+    pub fn checked_previous_day(self) -> Option<Self> {
+        if self.ordinal == 1 {
+            let year = self.year.checked_sub(1)?;
+            Some(Self {
+                year,
+                ordinal: days_in_year(year),
+            })
+        } else {
+            Some(Self {
+                ordinal: self.ordinal - 1,
+                ..self
+            })
+        }
     }
-}
 
-/// Methods to add a `Time` component, resulting in a `DateTime`.
-impl Date {
-    /// Create a `DateTime` using the existing date. The `Time` component will
-    /// be set to midnight.
+    /// Computes `self + duration`, returning `None` if the resulting year
+    /// would overflow. As with `Add`, only whole days are taken into account.
     ///
     /// ```rust
-    /// # use time::{Date, DateTime, Time};
+    /// # use time::{Date, Duration, NumericalDuration};
     /// assert_eq!(
-    ///     Date::from_ymd(1970, 1, 1).midnight(),
-    ///     DateTime::unix_epoch()
+    ///     Date::from_ymd(2019, 12, 31).checked_add(1.days()),
+    ///     Some(Date::from_ymd(2020, 1, 1))
     /// );
-    /// ```
-    #[inline(always)]
-    pub const fn midnight(self) -> DateTime {
-        DateTime::new(self, Time::midnight())
-    }
-
-    /// Create a `DateTime` using the existing date and the provided `Time`.
-    ///
-    /// ```rust
-    /// # use time::{Date, DateTime, Time};
     /// assert_eq!(
-    ///     Date::from_ymd(1970, 1, 1).with_time(Time::from_hms(0, 0, 0)),
-    ///     Date::from_ymd(1970, 1, 1).midnight(),
+    ///     Date::from_ymd(2019, 1, 1).checked_add((-1).days()),
+    ///     Some(Date::from_ymd(2018, 12, 31))
     /// );
-    /// ```
-    #[inline(always)]
-    pub const fn with_time(self, time: Time) -> DateTime {
-        DateTime::new(self, time)
-    }
-
-    /// Create a `DateTime` using the existing date and the provided time.
-    ///
-    /// ```rust
-    /// # use time::{Date, DateTime, Time};
     /// assert_eq!(
-    ///     Date::from_ymd(1970, 1, 1).with_hms(0, 0, 0),
-    ///     Date::from_ymd(1970, 1, 1).with_time(Time::from_hms(0, 0, 0)),
+    ///     Date::from_ymd(2019, 1, 1).checked_add(Duration::seconds(i64::max_value())),
+    ///     None
     /// );
     /// ```
-    #[inline(always)]
-    pub fn with_hms(self, hour: u8, minute: u8, second: u8) -> DateTime {
-        DateTime::new(self, Time::from_hms(hour, minute, second))
+    #[inline]
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        // `Duration::whole_days` is not used, as the number of seconds may not
+        // fit in an `i64`. The number of days always does.
+        #[allow(clippy::cast_possible_wrap)]
+        let days = duration.sign * (duration.std.as_secs() / 86_400) as i64;
+        Self::checked_from_julian_day(self.julian_day().checked_add(days)?)
     }
 
-    /// Create a `DateTime` using the existing date and the provided time.
+    /// Computes `self - duration`, returning `None` if the resulting year
+    /// would overflow. As with `Sub`, only whole days are taken into account.
     ///
     /// ```rust
-    /// # use time::{Date, DateTime, Time};
+    /// # use time::{Date, Duration, NumericalDuration};
     /// assert_eq!(
-    ///     Date::from_ymd(1970, 1, 1).with_hms_milli(0, 0, 0, 0),
-    ///     Date::from_ymd(1970, 1, 1).with_time(Time::from_hms_milli(0, 0, 0, 0)),
+    ///     Date::from_ymd(2020, 1, 1).checked_sub(1.days()),
+    ///     Some(Date::from_ymd(2019, 12, 31))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 1, 1).checked_sub(Duration::seconds(i64::max_value())),
+    ///     None
     /// );
     /// ```
     #[inline(always)]
-    pub fn with_hms_milli(self, hour: u8, minute: u8, second: u8, millisecond: u16) -> DateTime {
-        DateTime::new(
-            self,
-            Time::from_hms_milli(hour, minute, second, millisecond),
-        )
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.checked_add(-duration)
     }
 
-    /// Create a `DateTime` using the existing date and the provided time.
+    /// Iterate over every date, starting with this one. The iterator ends only
+    /// if the year would overflow.
     ///
     /// ```rust
-    /// # use time::{Date, DateTime, Time};
-    /// assert_eq!(
-    ///     Date::from_ymd(1970, 1, 1).with_hms_micro(0, 0, 0, 0),
-    ///     Date::from_ymd(1970, 1, 1).with_time(Time::from_hms_micro(0, 0, 0, 0)),
-    /// );
+    /// # use time::Date;
+    /// let mut days = Date::from_ymd(2020, 2, 28).iter_days();
+    /// assert_eq!(days.next(), Some(Date::from_ymd(2020, 2, 28)));
+    /// assert_eq!(days.next(), Some(Date::from_ymd(2020, 2, 29)));
+    /// assert_eq!(days.next(), Some(Date::from_ymd(2020, 3, 1)));
     /// ```
     #[inline(always)]
-    pub fn with_hms_micro(self, hour: u8, minute: u8, second: u8, microsecond: u32) -> DateTime {
-        DateTime::new(
-            self,
-            Time::from_hms_micro(hour, minute, second, microsecond),
-        )
+    pub fn iter_days(self) -> impl Iterator<Item = Self> {
+        successors(Some(self), |date| date.checked_next_day())
     }
 
-    /// Create a `DateTime` using the existing date and the provided time.
+    /// Iterate over the same weekday of every week, starting with this date.
+    /// The iterator ends only if the year would overflow.
     ///
     /// ```rust
-    /// # use time::{Date, DateTime, Time};
-    /// assert_eq!(
-    ///     Date::from_ymd(1970, 1, 1).with_hms_nano(0, 0, 0, 0),
-    ///     Date::from_ymd(1970, 1, 1).with_time(Time::from_hms_nano(0, 0, 0, 0)),
-    /// );
+    /// # use time::Date;
+    /// let mut weeks = Date::from_ymd(2019, 12, 25).iter_weeks();
+    /// assert_eq!(weeks.next(), Some(Date::from_ymd(2019, 12, 25)));
+    /// assert_eq!(weeks.next(), Some(Date::from_ymd(2020, 1, 1)));
     /// ```
     #[inline(always)]
-    pub fn with_hms_nano(self, hour: u8, minute: u8, second: u8, nanosecond: u32) -> DateTime {
-        DateTime::new(self, Time::from_hms_nano(hour, minute, second, nanosecond))
+    pub fn iter_weeks(self) -> impl Iterator<Item = Self> {
+        successors(Some(self), |&date| {
+            (0..7).try_fold(date, |date, _| date.checked_next_day())
+        })
     }
-}
 
-/// Methods that allow formatting the `Date`.
-impl Date {
-    /// Format the `Date` using the provided string. As no language is
-    /// specified, English is used.
+    /// Iterate over every date from this one (inclusive) to `end` (exclusive).
+    /// Nothing is yielded if `end` is not after this date.
     ///
     /// ```rust
     /// # use time::Date;
-    /// assert_eq!(Date::from_ymd(2019, 1, 2).format("%Y-%m-%d"), "2019-01-02");
+    /// let start = Date::from_ymd(2019, 12, 30);
+    /// let end = Date::from_ymd(2020, 1, 2);
+    /// assert_eq!(start.range(end).count(), 3);
+    /// assert_eq!(start.range(end).last(), Some(Date::from_ymd(2020, 1, 1)));
+    /// assert_eq!(end.range(start).count(), 0);
     /// ```
     #[inline(always)]
-    pub fn format(self, format: &str) -> String {
-        DeferredFormat {
-            date: Some(self),
-            time: None,
-            offset: None,
-            format: crate::format::parse_with_language(format, Language::en),
-        }
-        .to_string()
+    pub fn range(self, end: Self) -> impl Iterator<Item = Self> {
+        self.iter_days().take_while(move |&date| date < end)
     }
 
-    /// Format the `Date` using the provided string and language.
+    /// Get the same day of the next calendar month. If the day does not exist
+    /// in that month, the last day of the month is used instead.
+    ///
+    /// This panics if the resulting year would overflow. Use
+    /// `checked_next_month` to handle this case.
     ///
     /// ```rust
-    /// # use time::{Date, Language};
+    /// # use time::Date;
     /// assert_eq!(
-    ///     Date::from_ymd(2019, 1, 2).format_language("%B, %A", Language::en),
-    ///     "January, Wednesday",
+    ///     Date::from_ymd(2019, 1, 15).next_month(),
+    ///     Date::from_ymd(2019, 2, 15)
     /// );
     /// assert_eq!(
-    ///     Date::from_ymd(2019, 1, 2).format_language("%B, %A", Language::es),
-    ///     "enero, miércoles",
+    ///     Date::from_ymd(2019, 1, 31).next_month(),
+    ///     Date::from_ymd(2019, 2, 28)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 12, 31).next_month(),
+    ///     Date::from_ymd(2020, 1, 31)
     /// );
     /// ```
     #[inline(always)]
-    pub fn format_language(self, format: &str, language: Language) -> String {
-        DeferredFormat {
-            date: Some(self),
-            time: None,
-            offset: None,
-            format: crate::format::parse_with_language(format, language),
-        }
-        .to_string()
+    pub fn next_month(self) -> Self {
+        self.checked_next_month()
+            .expect("overflow when getting the next month")
     }
 
-    /// Attempt to parse a `Date` using the provided string.
+    /// Get the same day of the next calendar month, returning `None` if the
+    /// resulting year would overflow. If the day does not exist in that month,
+    /// the last day of the month is used instead.
     ///
     /// ```rust
-    /// # use time::{Date, Weekday::Wednesday};
+    /// # use time::Date;
     /// assert_eq!(
-    ///     Date::parse("2019-01-02", "%F"),
-    ///     Ok(Date::from_ymd(2019, 1, 2))
+    ///     Date::from_ymd(2019, 1, 31).checked_next_month(),
+    ///     Some(Date::from_ymd(2019, 2, 28))
     /// );
-    /// assert_eq!(Date::parse("2019-002", "%Y-%j"), Ok(Date::from_yo(2019, 2)));
     /// assert_eq!(
-    ///     Date::parse("2019-W01-3", "%G-W%V-%u"),
-    ///     Ok(Date::from_iso_ywd(2019, 1, Wednesday))
+    ///     Date::from_ymd(i32::max_value(), 12, 1).checked_next_month(),
+    ///     None
     /// );
     /// ```
-    #[inline(always)]
-    pub fn parse(s: &str, format: &str) -> ParseResult<Self> {
-        Self::parse_language(s, format, Language::en)
+    #[inline]
+    pub fn checked_next_month(self) -> Option<Self> {
+        let (year, month, day) = self.as_ymd();
+        let (year, month) = if month == 12 {
+            (year.checked_add(1)?, 1)
+        } else {
+            (year, month + 1)
+        };
+
+        Some(Self::from_ymd(
+            year,
+            month,
+            day.min(days_in_year_month(year, month)),
+        ))
     }
 
-    /// Attempt to parse a `Date` using the provided string.
+    /// Get the same day of the previous calendar month. If the day does not
+    /// exist in that month, the last day of the month is used instead.
+    ///
+    /// This panics if the resulting year would overflow. Use
+    /// `checked_previous_month` to handle this case.
     ///
     /// ```rust
-    /// # use time::{Date, Weekday::Wednesday, Language::{en, es}};
+    /// # use time::Date;
     /// assert_eq!(
-    ///     Date::parse_language("January 2 2019", "%B %-d %Y", en),
-    ///     Ok(Date::from_ymd(2019, 1, 2))
+    ///     Date::from_ymd(2019, 2, 15).previous_month(),
+    ///     Date::from_ymd(2019, 1, 15)
     /// );
     /// assert_eq!(
-    ///     Date::parse_language("2 de enero 2019", "%-d de %B %Y", es),
-    ///     Ok(Date::from_ymd(2019, 1, 2))
+    ///     Date::from_ymd(2020, 3, 31).previous_month(),
+    ///     Date::from_ymd(2020, 2, 29)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2020, 1, 31).previous_month(),
+    ///     Date::from_ymd(2019, 12, 31)
     /// );
     /// ```
     #[inline(always)]
-    pub fn parse_language(s: &str, format: &str, language: Language) -> ParseResult<Self> {
-        Self::try_from_parsed_items(parse(s, format, language)?)
+    pub fn previous_month(self) -> Self {
+        self.checked_previous_month()
+            .expect("overflow when getting the previous month")
     }
 
-    /// Given the items already parsed, attempt to create a `Date`.
+    /// Get the same day of the previous calendar month, returning `None` if
+    /// the resulting year would overflow. If the day does not exist in that
+    /// month, the last day of the month is used instead.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2020, 3, 31).checked_previous_month(),
+    ///     Some(Date::from_ymd(2020, 2, 29))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(i32::min_value(), 1, 31).checked_previous_month(),
+    ///     None
+    /// );
+    /// ```
     #[inline]
-    pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
-        macro_rules! items {
-            ($($item:ident),* $(,)?) => {
-                ParsedItems { $($item: Some($item)),*, .. }
-            };
-        }
+    pub fn checked_previous_month(self) -> Option<Self> {
+        let (year, month, day) = self.as_ymd();
+        let (year, month) = if month == 1 {
+            (year.checked_sub(1)?, 12)
+        } else {
+            (year, month - 1)
+        };
 
-        /// Get the value needed to adjust the ordinal day for Sunday and
-        /// Monday-based week numbering.
-        #[inline(always)]
-        fn adjustment(year: i32) -> i16 {
-            match Date::from_yo(year, 1).weekday() {
-                Monday => 7,
-                Tuesday => 1,
-                Wednesday => 2,
-                Thursday => 3,
-                Friday => 4,
-                Saturday => 5,
-                Sunday => 6,
-            }
-        }
+        Some(Self::from_ymd(
+            year,
+            month,
+            day.min(days_in_year_month(year, month)),
+        ))
+    }
 
-        match items {
-            items!(year, month, day) => Ok(Self::from_ymd(year, month.get(), day.get())),
-            items!(year, ordinal_day) => Ok(Self::from_yo(year, ordinal_day.get())),
-            items!(week_based_year, iso_week, weekday) => {
-                Ok(Self::from_iso_ywd(week_based_year, iso_week.get(), weekday))
-            }
-            items!(year, sunday_week, weekday) => Ok(Self::from_yo(
-                year,
-                #[allow(clippy::cast_sign_loss)]
-                {
-                    (sunday_week as i16 * 7 + weekday.number_days_from_sunday() as i16
-                        - adjustment(year)
-                        + 1) as u16
-                },
-            )),
-            items!(year, monday_week, weekday) => Ok(Self::from_yo(
-                year,
-                #[allow(clippy::cast_sign_loss)]
-                {
-                    (monday_week as i16 * 7 + weekday.number_days_from_monday() as i16
-                        - adjustment(year)
-                        + 1) as u16
-                },
-            )),
-            _ => Err(ParseError::InsufficientInformation),
+    /// Get the first day of the date's year.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 7, 4).start_of_year(),
+    ///     Date::from_ymd(2019, 1, 1)
+    /// );
+    /// ```
+    #[inline(always)]
+    pub const fn start_of_year(self) -> Self {
+        Self {
+            year: self.year,
+            ordinal: 1,
         }
     }
-}
-
-impl Add<Duration> for Date {
-    type Output = Self;
 
+    /// Get the last day of the date's year.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 7, 4).end_of_year(),
+    ///     Date::from_ymd(2019, 12, 31)
+    /// );
+    /// ```
     #[inline(always)]
-    fn add(self, duration: Duration) -> Self::Output {
-        Self::from_julian_day(self.julian_day() + duration.whole_days())
+    pub const fn end_of_year(self) -> Self {
+        Self {
+            year: self.year,
+            ordinal: days_in_year(self.year),
+        }
     }
-}
 
-impl Add<StdDuration> for Date {
-    type Output = Self;
+    /// Get the number of whole calendar months from this date to `other`. The
+    /// value is negative if `other` is earlier, and partial months are
+    /// truncated.
+    ///
+    /// A month has elapsed when the day of the month is reached again. If the
+    /// month being entered is too short to contain that day, its last day is
+    /// used instead, such that there is one month between January 31 and
+    /// February 28 of a common year.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 1, 15).months_between(Date::from_ymd(2019, 3, 14)),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 1, 31).months_between(Date::from_ymd(2019, 2, 28)),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 3, 15).months_between(Date::from_ymd(2019, 1, 15)),
+    ///     -2
+    /// );
+    /// ```
+    #[inline]
+    pub fn months_between(self, other: Self) -> i64 {
+        let (start, end, sign) = if other >= self {
+            (self, other, 1)
+        } else {
+            (other, self, -1)
+        };
 
-    #[inline(always)]
-    fn add(self, duration: StdDuration) -> Self::Output {
-        Self::from_julian_day(self.julian_day() + Duration::from(duration).whole_days())
-    }
-}
+        let (start_month, start_day) = start.month_day();
+        let (end_month, end_day) = end.month_day();
+        let mut months =
+            (end.year as i64 - start.year as i64) * 12 + end_month as i64 - start_month as i64;
+        if end_day < start_day && end_day != days_in_year_month(end.year, end_month) {
+            months -= 1;
+        }
 
-impl AddAssign<Duration> for Date {
-    #[inline(always)]
-    fn add_assign(&mut self, duration: Duration) {
-        *self = *self + duration;
+        sign * months
     }
-}
 
-impl AddAssign<StdDuration> for Date {
+    /// Get the number of whole years from this date to `other`. The value is
+    /// negative if `other` is earlier, and partial years are truncated.
+    ///
+    /// This follows the same rule as [`Date::months_between`], such that the
+    /// anniversary of February 29 in a common year is February 28.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2000, 6, 15).years_between(Date::from_ymd(2019, 6, 15)),
+    ///     19
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2000, 6, 15).years_between(Date::from_ymd(2019, 6, 14)),
+    ///     18
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2020, 2, 29).years_between(Date::from_ymd(2021, 2, 28)),
+    ///     1
+    /// );
+    /// ```
     #[inline(always)]
-    fn add_assign(&mut self, duration: StdDuration) {
-        *self = *self + duration;
+    pub fn years_between(self, other: Self) -> i64 {
+        self.months_between(other) / 12
     }
-}
-
-impl Sub<Duration> for Date {
-    type Output = Self;
 
-    #[inline(always)]
-    fn sub(self, duration: Duration) -> Self::Output {
-        self + -duration
+    /// Get the first day of the month containing the date.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 7, 4).start_of_month(),
+    ///     Date::from_ymd(2019, 7, 1)
+    /// );
+    /// ```
+    #[inline]
+    pub fn start_of_month(self) -> Self {
+        Self {
+            year: self.year,
+            ordinal: self.ordinal - self.day() as u16 + 1,
+        }
     }
-}
-
-impl Sub<StdDuration> for Date {
-    type Output = Self;
 
-    #[inline(always)]
-    fn sub(self, duration: StdDuration) -> Self::Output {
-        self + -Duration::from(duration)
+    /// Get the last day of the month containing the date.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 7, 4).end_of_month(),
+    ///     Date::from_ymd(2019, 7, 31)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2020, 2, 4).end_of_month(),
+    ///     Date::from_ymd(2020, 2, 29)
+    /// );
+    /// ```
+    #[inline]
+    pub fn end_of_month(self) -> Self {
+        let (month, day) = self.month_day();
+        Self {
+            year: self.year,
+            ordinal: self.ordinal - day as u16 + days_in_year_month(self.year, month) as u16,
+        }
     }
-}
 
-impl SubAssign<Duration> for Date {
+    /// Get the quarter of the year containing the date.
+    ///
+    /// The returned value will always be in the range `1..=4`.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(Date::from_ymd(2019, 1, 1).quarter(), 1);
+    /// assert_eq!(Date::from_ymd(2019, 5, 15).quarter(), 2);
+    /// assert_eq!(Date::from_ymd(2019, 12, 31).quarter(), 4);
+    /// ```
     #[inline(always)]
-    fn sub_assign(&mut self, duration: Duration) {
-        *self = *self - duration;
+    pub fn quarter(self) -> u8 {
+        (self.month() - 1) / 3 + 1
     }
-}
 
-impl SubAssign<StdDuration> for Date {
-    #[inline(always)]
-    fn sub_assign(&mut self, duration: StdDuration) {
-        *self = *self - duration;
+    /// Get the first day of the quarter containing the date.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 5, 15).start_of_quarter(),
+    ///     Date::from_ymd(2019, 4, 1)
+    /// );
+    /// ```
+    #[inline]
+    pub fn start_of_quarter(self) -> Self {
+        Self::from_ymd(self.year, self.quarter() * 3 - 2, 1)
     }
-}
-
-impl Sub<Date> for Date {
-    type Output = Duration;
 
-    #[inline(always)]
-    fn sub(self, other: Self) -> Self::Output {
-        Duration::days(self.julian_day() - other.julian_day())
+    /// Get the last day of the quarter containing the date.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 5, 15).end_of_quarter(),
+    ///     Date::from_ymd(2019, 6, 30)
+    /// );
+    /// ```
+    #[inline]
+    pub fn end_of_quarter(self) -> Self {
+        let month = self.quarter() * 3;
+        Self::from_ymd(self.year, month, days_in_year_month(self.year, month))
     }
-}
 
-impl PartialOrd for Date {
-    #[inline(always)]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    /// Get the first day of the week containing the date, where weeks begin on
+    /// `first_day`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::*};
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 7, 4).start_of_week(Monday),
+    ///     Date::from_ymd(2019, 7, 1)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 7, 1).start_of_week(Monday),
+    ///     Date::from_ymd(2019, 7, 1)
+    /// );
+    /// ```
+    #[inline]
+    pub fn start_of_week(self, first_day: Weekday) -> Self {
+        self - Duration::days(days_since(self.weekday(), first_day).into())
     }
-}
 
-impl Ord for Date {
-    #[inline(always)]
-    fn cmp(&self, other: &Self) -> Ordering {
-        match self.year.cmp(&other.year) {
-            Ordering::Less => Ordering::Less,
-            Ordering::Greater => Ordering::Greater,
-            Ordering::Equal => self.ordinal.cmp(&other.ordinal),
-        }
+    /// Get the last day of the week containing the date, where weeks begin on
+    /// `first_day`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::*};
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 7, 4).end_of_week(Sunday),
+    ///     Date::from_ymd(2019, 7, 6)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 7, 6).end_of_week(Sunday),
+    ///     Date::from_ymd(2019, 7, 6)
+    /// );
+    /// ```
+    #[inline]
+    pub fn end_of_week(self, first_day: Weekday) -> Self {
+        self + Duration::days((6 - days_since(self.weekday(), first_day)).into())
     }
-}
 
-#[cfg(test)]
-#[allow(clippy::non_ascii_literal)]
-mod test {
-    use super::*;
-    use crate::prelude::*;
-
-    macro_rules! yo {
-        ($year:literal, $ordinal:literal) => {
-            Date::from_yo($year, $ordinal)
-        };
+    /// Get the week of the month containing the date, where weeks begin on
+    /// `first_day`. The first week of the month is the one containing the
+    /// first day of the month, and is numbered 1.
+    ///
+    /// The returned value will always be in the range `1..=6`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::*};
+    /// assert_eq!(Date::from_ymd(2019, 6, 1).week_of_month(Monday), 1);
+    /// assert_eq!(Date::from_ymd(2019, 6, 3).week_of_month(Monday), 2);
+    /// assert_eq!(Date::from_ymd(2019, 6, 30).week_of_month(Monday), 5);
+    /// assert_eq!(Date::from_ymd(2019, 6, 30).week_of_month(Sunday), 6);
+    /// ```
+    #[inline]
+    pub fn week_of_month(self, first_day: Weekday) -> u8 {
+        (self.day() - 1 + days_since(self.start_of_month().weekday(), first_day)) / 7 + 1
     }
 
-    macro_rules! ymd {
-        ($year:literal, $month:literal, $day:literal) => {
-            Date::from_ymd($year, $month, $day)
-        };
+    /// Get the Julian day for the date.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(Date::from_ymd(-4713, 11, 24).julian_day(), 0);
+    /// assert_eq!(Date::from_ymd(2000, 1, 1).julian_day(), 2_451_545);
+    /// assert_eq!(Date::from_ymd(2019, 1, 1).julian_day(), 2_458_485);
+    /// assert_eq!(Date::from_ymd(2019, 12, 31).julian_day(), 2_458_849);
+    /// ```
+    #[inline]
+    pub fn julian_day(self) -> i64 {
+        let year = self.year as i64;
+        let (month, day) = self.month_day();
+        let month = month as i64;
+        let day = day as i64;
+        // Floored division is necessary for the result to be correct for
+        // years prior to -4800.
+        (1_461 * (year + 4_800 + (month - 14) / 12)).div_euclid(4)
+            + (367 * (month - 2 - 12 * ((month - 14) / 12))) / 12
+            - (3 * (year + 4_900 + (month - 14) / 12).div_euclid(100)).div_euclid(4)
+            + day
+            - 32_075
     }
 
-    macro_rules! ywd {
-        ($year:literal, $week:literal, $day:ident) => {
-            Date::from_iso_ywd($year, $week, $day)
-        };
-    }
+    /// Create a `Date` from the Julian day.
+    ///
+    /// The algorithm to perform this conversion comes from E.G. Richards; it is
+    /// available in [section 15.11.3](https://aa.usno.navy.mil/publications/docs/c15_usb_online.pdf),
+    /// courtesy of the United States Naval Observatory.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(Date::from_julian_day(0), Date::from_ymd(-4713, 11, 24));
+    /// assert_eq!(Date::from_julian_day(2_451_545), Date::from_ymd(2000, 1, 1));
+    /// assert_eq!(Date::from_julian_day(2_458_485), Date::from_ymd(2019, 1, 1));
+    /// assert_eq!(
+    ///     Date::from_julian_day(2_458_849),
+    ///     Date::from_ymd(2019, 12, 31)
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_julian_day(julian_day: i64) -> Self {
+        #![allow(clippy::missing_docs_in_private_items)]
+        const Y: i64 = 4_716;
+        const J: i64 = 1_401;
+        const M: i64 = 2;
+        const N: i64 = 12;
+        const R: i64 = 4;
+        const P: i64 = 1_461;
+        const V: i64 = 3;
+        const U: i64 = 5;
+        const S: i64 = 153;
+        const W: i64 = 2;
+        const B: i64 = 274_277;
+        const C: i64 = -38;
 
-    macro_rules! julian {
-        ($julian:literal) => {
-            Date::from_julian_day($julian)
-        };
+        // As with `Date::julian_day`, floored division is necessary for the
+        // result to be correct for negative Julian days.
+        let f = julian_day + J + ((4 * julian_day + B).div_euclid(146_097) * 3).div_euclid(4) + C;
+        let e = R * f + V;
+        let g = e.rem_euclid(P) / R;
+        let h = U * g + W;
+        let day = h.rem_euclid(S) / U + 1;
+        let month = (h.div_euclid(S) + M).rem_euclid(N) + 1;
+        let year = e.div_euclid(P) - Y + (N + M - month) / N;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Self::from_ymd(year as i32, month as u8, day as u8)
     }
 
-    #[test]
-    fn weeks_in_year_exhaustive() {
-        extern crate alloc;
-        let mut years_with_53 = alloc::collections::btree_set::BTreeSet::new();
-        for year in [
-            4, 9, 15, 20, 26, 32, 37, 43, 48, 54, 60, 65, 71, 76, 82, 88, 93, 99, 105, 111, 116,
-            122, 128, 133, 139, 144, 150, 156, 161, 167, 172, 178, 184, 189, 195, 201, 207, 212,
-            218, 224, 229, 235, 240, 246, 252, 257, 263, 268, 274, 280, 285, 291, 296, 303, 308,
-            314, 320, 325, 331, 336, 342, 348, 353, 359, 364, 370, 376, 381, 387, 392, 398,
-        ]
-        .iter()
-        {
-            years_with_53.insert(year);
+    /// Create a `Date` from the Julian day, returning `None` if the year would
+    /// be outside the range of an `i32`.
+    #[inline]
+    fn checked_from_julian_day(julian_day: i64) -> Option<Self> {
+        let min = Self::from_yo(i32::min_value(), 1).julian_day();
+        let max = Self::from_yo(i32::max_value(), days_in_year(i32::max_value())).julian_day();
+        if julian_day < min || julian_day > max {
+            return None;
         }
 
-        for year in 0..400 {
-            assert_eq!(
-                super::weeks_in_year(year),
-                if years_with_53.contains(&year) {
-                    53
-                } else {
-                    52
-                }
-            );
-        }
+        Some(Self::from_julian_day(julian_day))
     }
+}
 
-    #[test]
-    fn test_days_in_year_month() {
-        // Common year
-        assert_eq!(days_in_year_month(2019, 1), 31);
-        assert_eq!(days_in_year_month(2019, 2), 28);
-        assert_eq!(days_in_year_month(2019, 3), 31);
-        assert_eq!(days_in_year_month(2019, 4), 30);
-        assert_eq!(days_in_year_month(2019, 5), 31);
-        assert_eq!(days_in_year_month(2019, 6), 30);
-        assert_eq!(days_in_year_month(2019, 7), 31);
-        assert_eq!(days_in_year_month(2019, 8), 31);
-        assert_eq!(days_in_year_month(2019, 9), 30);
-        assert_eq!(days_in_year_month(2019, 10), 31);
-        assert_eq!(days_in_year_month(2019, 11), 30);
-        assert_eq!(days_in_year_month(2019, 12), 31);
+/// Methods to add a `Time` component, resulting in a `DateTime`.
+impl Date {
+    /// Create a `DateTime` using the existing date. The `Time` component will
+    /// be set to midnight.
+    ///
+    /// ```rust
+    /// # use time::{Date, DateTime, Time};
+    /// assert_eq!(
+    ///     Date::from_ymd(1970, 1, 1).midnight(),
+    ///     DateTime::unix_epoch()
+    /// );
+    /// ```
+    #[inline(always)]
+    pub const fn midnight(self) -> DateTime {
+        DateTime::new(self, Time::midnight())
+    }
 
-        // Leap year
-        assert_eq!(days_in_year_month(2020, 1), 31);
-        assert_eq!(days_in_year_month(2020, 2), 29);
-        assert_eq!(days_in_year_month(2020, 3), 31);
-        assert_eq!(days_in_year_month(2020, 4), 30);
-        assert_eq!(days_in_year_month(2020, 5), 31);
-        assert_eq!(days_in_year_month(2020, 6), 30);
-        assert_eq!(days_in_year_month(2020, 7), 31);
-        assert_eq!(days_in_year_month(2020, 8), 31);
-        assert_eq!(days_in_year_month(2020, 9), 30);
-        assert_eq!(days_in_year_month(2020, 10), 31);
-        assert_eq!(days_in_year_month(2020, 11), 30);
-        assert_eq!(days_in_year_month(2020, 12), 31);
+    /// Create a `DateTime` using the existing date and the provided `Time`.
+    ///
+    /// ```rust
+    /// # use time::{Date, DateTime, Time};
+    /// assert_eq!(
+    ///     Date::from_ymd(1970, 1, 1).with_time(Time::from_hms(0, 0, 0)),
+    ///     Date::from_ymd(1970, 1, 1).midnight(),
+    /// );
+    /// ```
+    #[inline(always)]
+    pub const fn with_time(self, time: Time) -> DateTime {
+        DateTime::new(self, time)
     }
 
-    // Test all dominical letters. For leap years, check the dates
-    // immediately preceding and after the leap day.
+    /// Create a `DateTime` using the existing date and the provided time.
+    ///
+    /// ```rust
+    /// # use time::{Date, DateTime, Time};
+    /// assert_eq!(
+    ///     Date::from_ymd(1970, 1, 1).with_hms(0, 0, 0),
+    ///     Date::from_ymd(1970, 1, 1).with_time(Time::from_hms(0, 0, 0)),
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn with_hms(self, hour: u8, minute: u8, second: u8) -> DateTime {
+        DateTime::new(self, Time::from_hms(hour, minute, second))
+    }
 
-    #[test]
-    #[allow(clippy::zero_prefixed_literal)]
-    fn test_monday_based_week() {
-        macro_rules! assert_monday_week {
-            ($y:literal - $m:literal - $d:literal => $week:literal) => {
-                assert_eq!(Date::from_ymd($y, $m, $d).monday_based_week(), $week);
-            };
-        }
+    /// Create a `DateTime` using the existing date and the provided time.
+    ///
+    /// ```rust
+    /// # use time::{Date, DateTime, Time};
+    /// assert_eq!(
+    ///     Date::from_ymd(1970, 1, 1).with_hms_milli(0, 0, 0, 0),
+    ///     Date::from_ymd(1970, 1, 1).with_time(Time::from_hms_milli(0, 0, 0, 0)),
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn with_hms_milli(self, hour: u8, minute: u8, second: u8, millisecond: u16) -> DateTime {
+        DateTime::new(
+            self,
+            Time::from_hms_milli(hour, minute, second, millisecond),
+        )
+    }
 
-        // A
-        assert_monday_week!(2023-01-01 => 0);
-        assert_monday_week!(2023-01-02 => 1);
-        assert_monday_week!(2023-01-03 => 1);
-        assert_monday_week!(2023-01-04 => 1);
-        assert_monday_week!(2023-01-05 => 1);
-        assert_monday_week!(2023-01-06 => 1);
-        assert_monday_week!(2023-01-07 => 1);
+    /// Create a `DateTime` using the existing date and the provided time.
+    ///
+    /// ```rust
+    /// # use time::{Date, DateTime, Time};
+    /// assert_eq!(
+    ///     Date::from_ymd(1970, 1, 1).with_hms_micro(0, 0, 0, 0),
+    ///     Date::from_ymd(1970, 1, 1).with_time(Time::from_hms_micro(0, 0, 0, 0)),
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn with_hms_micro(self, hour: u8, minute: u8, second: u8, microsecond: u32) -> DateTime {
+        DateTime::new(
+            self,
+            Time::from_hms_micro(hour, minute, second, microsecond),
+        )
+    }
 
-        // B
-        assert_monday_week!(2022-01-01 => 0);
-        assert_monday_week!(2022-01-02 => 0);
-        assert_monday_week!(2022-01-03 => 1);
-        assert_monday_week!(2022-01-04 => 1);
-        assert_monday_week!(2022-01-05 => 1);
-        assert_monday_week!(2022-01-06 => 1);
-        assert_monday_week!(2022-01-07 => 1);
+    /// Create a `DateTime` using the existing date and the provided time.
+    ///
+    /// ```rust
+    /// # use time::{Date, DateTime, Time};
+    /// assert_eq!(
+    ///     Date::from_ymd(1970, 1, 1).with_hms_nano(0, 0, 0, 0),
+    ///     Date::from_ymd(1970, 1, 1).with_time(Time::from_hms_nano(0, 0, 0, 0)),
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn with_hms_nano(self, hour: u8, minute: u8, second: u8, nanosecond: u32) -> DateTime {
+        DateTime::new(self, Time::from_hms_nano(hour, minute, second, nanosecond))
+    }
+}
 
-        // C
-        assert_monday_week!(2021-01-01 => 0);
-        assert_monday_week!(2021-01-02 => 0);
-        assert_monday_week!(2021-01-03 => 0);
-        assert_monday_week!(2021-01-04 => 1);
-        assert_monday_week!(2021-01-05 => 1);
-        assert_monday_week!(2021-01-06 => 1);
-        assert_monday_week!(2021-01-07 => 1);
+/// Methods that allow formatting the `Date`.
+impl Date {
+    /// Format the `Date` using the provided string. As no language is
+    /// specified, English is used.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(Date::from_ymd(2019, 1, 2).format("%Y-%m-%d"), "2019-01-02");
+    /// ```
+    #[inline(always)]
+    pub fn format(self, format: &str) -> String {
+        DeferredFormat {
+            date: Some(self),
+            time: None,
+            offset: None,
+            format: crate::format::parse_with_language(format, Language::en),
+        }
+        .to_string()
+    }
+
+    /// Format the `Date` using the provided string and language.
+    ///
+    /// ```rust
+    /// # use time::{Date, Language};
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 1, 2).format_language("%B, %A", Language::en),
+    ///     "January, Wednesday",
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 1, 2).format_language("%B, %A", Language::es),
+    ///     "enero, miércoles",
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn format_language(self, format: &str, language: Language) -> String {
+        DeferredFormat {
+            date: Some(self),
+            time: None,
+            offset: None,
+            format: crate::format::parse_with_language(format, language),
+        }
+        .to_string()
+    }
+
+    /// Attempt to parse a `Date` using the provided string.
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::Wednesday};
+    /// assert_eq!(
+    ///     Date::parse("2019-01-02", "%F"),
+    ///     Ok(Date::from_ymd(2019, 1, 2))
+    /// );
+    /// assert_eq!(Date::parse("2019-002", "%Y-%j"), Ok(Date::from_yo(2019, 2)));
+    /// assert_eq!(
+    ///     Date::parse("2019-W01-3", "%G-W%V-%u"),
+    ///     Ok(Date::from_iso_ywd(2019, 1, Wednesday))
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn parse(s: &str, format: &str) -> ParseResult<Self> {
+        Self::parse_language(s, format, Language::en)
+    }
+
+    /// Attempt to parse a `Date` using the provided string. Any error includes
+    /// the position in the input at which it occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, ParseError};
+    /// assert_eq!(
+    ///     Date::parse_with_position("2019-01-02", "%Y-%m-%d"),
+    ///     Ok(Date::from_ymd(2019, 1, 2))
+    /// );
+    /// let error = Date::parse_with_position("2019-01-x", "%Y-%m-%d").unwrap_err();
+    /// assert_eq!(error.kind(), ParseError::InvalidDayOfMonth);
+    /// assert_eq!(error.index(), 8);
+    /// ```
+    #[inline(always)]
+    pub fn parse_with_position(s: &str, format: &str) -> Result<Self, ParseErrorAt> {
+        parse_with_position(s, format, Language::en, Self::try_from_parsed_items)
+    }
+
+    /// Attempt to parse a `Date` using the provided string, returning an error if
+    /// any input remains once the format is exhausted.
+    ///
+    /// ```rust
+    /// # use time::{Date, ParseError};
+    /// assert_eq!(
+    ///     Date::parse_strict("2019-01-02", "%F"),
+    ///     Ok(Date::from_ymd(2019, 1, 2))
+    /// );
+    /// assert_eq!(
+    ///     Date::parse_strict("2019-01-02T00:00", "%F"),
+    ///     Err(ParseError::UnexpectedTrailingCharacters)
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn parse_strict(s: &str, format: &str) -> ParseResult<Self> {
+        Self::try_from_parsed_items(&parse_strict(s, format, Language::en)?)
+    }
+
+    /// Attempt to parse a `Date` using the provided string.
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::Wednesday, Language::{en, es}};
+    /// assert_eq!(
+    ///     Date::parse_language("January 2 2019", "%B %-d %Y", en),
+    ///     Ok(Date::from_ymd(2019, 1, 2))
+    /// );
+    /// assert_eq!(
+    ///     Date::parse_language("2 de enero 2019", "%-d de %B %Y", es),
+    ///     Ok(Date::from_ymd(2019, 1, 2))
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn parse_language(s: &str, format: &str, language: Language) -> ParseResult<Self> {
+        Self::try_from_parsed_items(&parse(s, format, language)?)
+    }
+
+    /// Attempt to parse a `Date` using each of the provided formats in turn,
+    /// returning the first success. If every format fails, the error from the
+    /// last one is returned.
+    /// If no formats are provided, `ParseError::InsufficientInformation` is
+    /// returned with the date as the missing component.
+    ///
+    /// ```rust
+    /// # use time::{Date, ParseError};
+    /// let formats = ["%F", "%m/%d/%Y", "%-d %B %Y"];
+    /// assert_eq!(
+    ///     Date::parse_any("2019-01-02", &formats),
+    ///     Ok(Date::from_ymd(2019, 1, 2))
+    /// );
+    /// assert_eq!(
+    ///     Date::parse_any("01/02/2019", &formats),
+    ///     Ok(Date::from_ymd(2019, 1, 2))
+    /// );
+    /// assert_eq!(
+    ///     Date::parse_any("2 January 2019", &formats),
+    ///     Ok(Date::from_ymd(2019, 1, 2))
+    /// );
+    /// assert_eq!(
+    ///     Date::parse_any("2 Foo 2019", &formats),
+    ///     Err(ParseError::InvalidMonth)
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_any(s: &str, formats: &[&str]) -> ParseResult<Self> {
+        parse_any(formats, |format| Self::parse(s, format))
+    }
+
+    /// Attempt to parse an ISO 8601 week date, in either the extended
+    /// (`2020-W01-3`) or basic (`2020W013`) form.
+    ///
+    /// ```rust
+    /// # use time::{Date, ParseError, Weekday::Wednesday};
+    /// assert_eq!(
+    ///     Date::from_iso_week_string("2020-W01-3"),
+    ///     Ok(Date::from_iso_ywd(2020, 1, Wednesday))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_iso_week_string("2020W013"),
+    ///     Ok(Date::from_iso_ywd(2020, 1, Wednesday))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_iso_week_string("2019-W53-1"),
+    ///     Err(ParseError::InvalidWeek)
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_iso_week_string(s: &str) -> ParseResult<Self> {
+        if s.contains("-W") {
+            Self::parse(s, "%G-W%V-%u")
+        } else {
+            Self::parse(s, "%GW%V%u")
+        }
+    }
+
+    /// Given the items already parsed, attempt to create a `Date`.
+    #[inline]
+    pub(crate) fn try_from_parsed_items(items: &ParsedItems) -> ParseResult<Self> {
+        macro_rules! items {
+            ($($item:ident),* $(,)?) => {
+                ParsedItems { $($item: Some($item)),*, .. }
+            };
+        }
+
+        /// Get the value needed to adjust the ordinal day for Sunday-based
+        /// week numbering.
+        #[inline(always)]
+        fn sunday_adjustment(year: i32) -> i16 {
+            match Date::from_yo(year, 1).weekday() {
+                Sunday => 7,
+                weekday => weekday.number_days_from_sunday() as i16,
+            }
+        }
+
+        /// Get the value needed to adjust the ordinal day for Monday-based
+        /// week numbering.
+        #[inline(always)]
+        fn monday_adjustment(year: i32) -> i16 {
+            match Date::from_yo(year, 1).weekday() {
+                Monday => 7,
+                Tuesday => 1,
+                Wednesday => 2,
+                Thursday => 3,
+                Friday => 4,
+                Saturday => 5,
+                Sunday => 6,
+            }
+        }
+
+        if let Some(datetime) = DateTime::from_parsed_timestamp(items)? {
+            return Ok(datetime.date());
+        }
+
+        let date = match *items {
+            items!(year, month, day) => {
+                if month.get() > 12 {
+                    return Err(ParseError::InvalidMonth);
+                }
+                if day.get() > days_in_year_month(year, month.get()) {
+                    return Err(ParseError::InvalidDayOfMonth);
+                }
+                Ok(Self::from_ymd(year, month.get(), day.get()))
+            }
+            items!(year, ordinal_day) => {
+                if ordinal_day.get() > days_in_year(year) {
+                    return Err(ParseError::InvalidDayOfYear);
+                }
+                Ok(Self::from_yo(year, ordinal_day.get()))
+            }
+            items!(week_based_year, iso_week, weekday) => {
+                if iso_week.get() > weeks_in_year(week_based_year) {
+                    return Err(ParseError::InvalidWeek);
+                }
+                Ok(Self::from_iso_ywd(week_based_year, iso_week.get(), weekday))
+            }
+            items!(year, sunday_week, weekday) => Ok(Self::from_yo(
+                year,
+                #[allow(clippy::cast_sign_loss)]
+                {
+                    (sunday_week as i16 * 7 + weekday.number_days_from_sunday() as i16
+                        - sunday_adjustment(year)
+                        + 1) as u16
+                },
+            )),
+            items!(year, monday_week, weekday) => Ok(Self::from_yo(
+                year,
+                #[allow(clippy::cast_sign_loss)]
+                {
+                    (monday_week as i16 * 7 + weekday.number_days_from_monday() as i16
+                        - monday_adjustment(year)
+                        + 1) as u16
+                },
+            )),
+            _ => Err(ParseError::InsufficientInformation {
+                missing: Component::Date,
+            }),
+        }?;
+
+        // If a weekday or quarter was parsed but not used to construct the
+        // date, ensure that it is consistent with the date.
+        match (items.weekday, items.quarter) {
+            (Some(weekday), _) if weekday != date.weekday() => Err(ParseError::ComponentConflict),
+            (_, Some(quarter)) if quarter.get() != date.quarter() => {
+                Err(ParseError::ComponentConflict)
+            }
+            _ => Ok(date),
+        }
+    }
+}
+
+impl Add<Duration> for Date {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, duration: Duration) -> Self::Output {
+        Self::from_julian_day(self.julian_day() + duration.whole_days())
+    }
+}
+
+impl Add<StdDuration> for Date {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, duration: StdDuration) -> Self::Output {
+        Self::from_julian_day(self.julian_day() + Duration::from(duration).whole_days())
+    }
+}
+
+impl AddAssign<Duration> for Date {
+    #[inline(always)]
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl AddAssign<StdDuration> for Date {
+    #[inline(always)]
+    fn add_assign(&mut self, duration: StdDuration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for Date {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, duration: Duration) -> Self::Output {
+        self + -duration
+    }
+}
+
+impl Sub<StdDuration> for Date {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, duration: StdDuration) -> Self::Output {
+        self + -Duration::from(duration)
+    }
+}
+
+impl SubAssign<Duration> for Date {
+    #[inline(always)]
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+impl SubAssign<StdDuration> for Date {
+    #[inline(always)]
+    fn sub_assign(&mut self, duration: StdDuration) {
+        *self = *self - duration;
+    }
+}
+
+impl Sub<Date> for Date {
+    type Output = Duration;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self::Output {
+        Duration::days(self.julian_day() - other.julian_day())
+    }
+}
+
+impl PartialOrd for Date {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Date {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.year.cmp(&other.year) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Equal => self.ordinal.cmp(&other.ordinal),
+        }
+    }
+}
+
+/// Human-readable formats (such as JSON) use the ISO 8601 `YYYY-MM-DD`
+/// representation. Compact formats use a `(year, ordinal)` tuple.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Date {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.format("%Y-%m-%d"))
+        } else {
+            (self.year, self.ordinal).serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for Date {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            Self::parse_strict(&s, "%Y-%m-%d").map_err(D::Error::custom)
+        } else {
+            let (year, ordinal) = <(i32, u16)>::deserialize(deserializer)?;
+
+            if ordinal == 0 || ordinal > days_in_year(year) {
+                return Err(D::Error::custom("ordinal day is out of range"));
+            }
+
+            Ok(Self { year, ordinal })
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::non_ascii_literal)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    macro_rules! yo {
+        ($year:literal, $ordinal:literal) => {
+            Date::from_yo($year, $ordinal)
+        };
+    }
+
+    macro_rules! ymd {
+        ($year:literal, $month:literal, $day:literal) => {
+            Date::from_ymd($year, $month, $day)
+        };
+    }
+
+    macro_rules! ywd {
+        ($year:literal, $week:literal, $day:ident) => {
+            Date::from_iso_ywd($year, $week, $day)
+        };
+    }
+
+    macro_rules! julian {
+        ($julian:literal) => {
+            Date::from_julian_day($julian)
+        };
+    }
+
+    #[test]
+    fn weeks_in_year_exhaustive() {
+        extern crate alloc;
+        let mut years_with_53 = alloc::collections::btree_set::BTreeSet::new();
+        for year in [
+            4, 9, 15, 20, 26, 32, 37, 43, 48, 54, 60, 65, 71, 76, 82, 88, 93, 99, 105, 111, 116,
+            122, 128, 133, 139, 144, 150, 156, 161, 167, 172, 178, 184, 189, 195, 201, 207, 212,
+            218, 224, 229, 235, 240, 246, 252, 257, 263, 268, 274, 280, 285, 291, 296, 303, 308,
+            314, 320, 325, 331, 336, 342, 348, 353, 359, 364, 370, 376, 381, 387, 392, 398,
+        ]
+        .iter()
+        {
+            years_with_53.insert(year);
+        }
+
+        for year in 0..400 {
+            assert_eq!(
+                super::weeks_in_year(year),
+                if years_with_53.contains(&year) {
+                    53
+                } else {
+                    52
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_days_in_year_month() {
+        // Common year
+        assert_eq!(days_in_year_month(2019, 1), 31);
+        assert_eq!(days_in_year_month(2019, 2), 28);
+        assert_eq!(days_in_year_month(2019, 3), 31);
+        assert_eq!(days_in_year_month(2019, 4), 30);
+        assert_eq!(days_in_year_month(2019, 5), 31);
+        assert_eq!(days_in_year_month(2019, 6), 30);
+        assert_eq!(days_in_year_month(2019, 7), 31);
+        assert_eq!(days_in_year_month(2019, 8), 31);
+        assert_eq!(days_in_year_month(2019, 9), 30);
+        assert_eq!(days_in_year_month(2019, 10), 31);
+        assert_eq!(days_in_year_month(2019, 11), 30);
+        assert_eq!(days_in_year_month(2019, 12), 31);
+
+        // Leap year
+        assert_eq!(days_in_year_month(2020, 1), 31);
+        assert_eq!(days_in_year_month(2020, 2), 29);
+        assert_eq!(days_in_year_month(2020, 3), 31);
+        assert_eq!(days_in_year_month(2020, 4), 30);
+        assert_eq!(days_in_year_month(2020, 5), 31);
+        assert_eq!(days_in_year_month(2020, 6), 30);
+        assert_eq!(days_in_year_month(2020, 7), 31);
+        assert_eq!(days_in_year_month(2020, 8), 31);
+        assert_eq!(days_in_year_month(2020, 9), 30);
+        assert_eq!(days_in_year_month(2020, 10), 31);
+        assert_eq!(days_in_year_month(2020, 11), 30);
+        assert_eq!(days_in_year_month(2020, 12), 31);
+    }
+
+    // Test all dominical letters. For leap years, check the dates
+    // immediately preceding and after the leap day.
+
+    #[test]
+    #[allow(clippy::zero_prefixed_literal)]
+    fn test_monday_based_week() {
+        macro_rules! assert_monday_week {
+            ($y:literal - $m:literal - $d:literal => $week:literal) => {
+                assert_eq!(Date::from_ymd($y, $m, $d).monday_based_week(), $week);
+            };
+        }
+
+        // A
+        assert_monday_week!(2023-01-01 => 0);
+        assert_monday_week!(2023-01-02 => 1);
+        assert_monday_week!(2023-01-03 => 1);
+        assert_monday_week!(2023-01-04 => 1);
+        assert_monday_week!(2023-01-05 => 1);
+        assert_monday_week!(2023-01-06 => 1);
+        assert_monday_week!(2023-01-07 => 1);
+
+        // B
+        assert_monday_week!(2022-01-01 => 0);
+        assert_monday_week!(2022-01-02 => 0);
+        assert_monday_week!(2022-01-03 => 1);
+        assert_monday_week!(2022-01-04 => 1);
+        assert_monday_week!(2022-01-05 => 1);
+        assert_monday_week!(2022-01-06 => 1);
+        assert_monday_week!(2022-01-07 => 1);
+
+        // C
+        assert_monday_week!(2021-01-01 => 0);
+        assert_monday_week!(2021-01-02 => 0);
+        assert_monday_week!(2021-01-03 => 0);
+        assert_monday_week!(2021-01-04 => 1);
+        assert_monday_week!(2021-01-05 => 1);
+        assert_monday_week!(2021-01-06 => 1);
+        assert_monday_week!(2021-01-07 => 1);
+
+        // D
+        assert_monday_week!(2026-01-01 => 0);
+        assert_monday_week!(2026-01-02 => 0);
+        assert_monday_week!(2026-01-03 => 0);
+        assert_monday_week!(2026-01-04 => 0);
+        assert_monday_week!(2026-01-05 => 1);
+        assert_monday_week!(2026-01-06 => 1);
+        assert_monday_week!(2026-01-07 => 1);
+
+        // E
+        assert_monday_week!(2025-01-01 => 0);
+        assert_monday_week!(2025-01-02 => 0);
+        assert_monday_week!(2025-01-03 => 0);
+        assert_monday_week!(2025-01-04 => 0);
+        assert_monday_week!(2025-01-05 => 0);
+        assert_monday_week!(2025-01-06 => 1);
+        assert_monday_week!(2025-01-07 => 1);
+
+        // F
+        assert_monday_week!(2019-01-01 => 0);
+        assert_monday_week!(2019-01-02 => 0);
+        assert_monday_week!(2019-01-03 => 0);
+        assert_monday_week!(2019-01-04 => 0);
+        assert_monday_week!(2019-01-05 => 0);
+        assert_monday_week!(2019-01-06 => 0);
+        assert_monday_week!(2019-01-07 => 1);
+
+        // G
+        assert_monday_week!(2018-01-01 => 1);
+        assert_monday_week!(2018-01-02 => 1);
+        assert_monday_week!(2018-01-03 => 1);
+        assert_monday_week!(2018-01-04 => 1);
+        assert_monday_week!(2018-01-05 => 1);
+        assert_monday_week!(2018-01-06 => 1);
+        assert_monday_week!(2018-01-07 => 1);
+
+        // AG
+        assert_monday_week!(2012-01-01 => 0);
+        assert_monday_week!(2012-01-02 => 1);
+        assert_monday_week!(2012-01-03 => 1);
+        assert_monday_week!(2012-01-04 => 1);
+        assert_monday_week!(2012-01-05 => 1);
+        assert_monday_week!(2012-01-06 => 1);
+        assert_monday_week!(2012-01-07 => 1);
+        assert_monday_week!(2012-02-28 => 9);
+        assert_monday_week!(2012-02-29 => 9);
+        assert_monday_week!(2012-03-01 => 9);
+        assert_monday_week!(2012-03-02 => 9);
+        assert_monday_week!(2012-03-03 => 9);
+        assert_monday_week!(2012-03-04 => 9);
+        assert_monday_week!(2012-03-05 => 10);
+        assert_monday_week!(2012-03-06 => 10);
+        assert_monday_week!(2012-03-07 => 10);
+
+        // BA
+        assert_monday_week!(2028-01-01 => 0);
+        assert_monday_week!(2028-01-02 => 0);
+        assert_monday_week!(2028-01-03 => 1);
+        assert_monday_week!(2028-01-04 => 1);
+        assert_monday_week!(2028-01-05 => 1);
+        assert_monday_week!(2028-01-06 => 1);
+        assert_monday_week!(2028-01-07 => 1);
+        assert_monday_week!(2028-02-28 => 9);
+        assert_monday_week!(2028-02-29 => 9);
+        assert_monday_week!(2028-03-01 => 9);
+        assert_monday_week!(2028-03-02 => 9);
+        assert_monday_week!(2028-03-03 => 9);
+        assert_monday_week!(2028-03-04 => 9);
+        assert_monday_week!(2028-03-05 => 9);
+        assert_monday_week!(2028-03-06 => 10);
+        assert_monday_week!(2028-03-07 => 10);
+
+        // CB
+        assert_monday_week!(2016-01-01 => 0);
+        assert_monday_week!(2016-01-02 => 0);
+        assert_monday_week!(2016-01-03 => 0);
+        assert_monday_week!(2016-01-04 => 1);
+        assert_monday_week!(2016-01-05 => 1);
+        assert_monday_week!(2016-01-06 => 1);
+        assert_monday_week!(2016-01-07 => 1);
+        assert_monday_week!(2016-02-28 => 8);
+        assert_monday_week!(2016-02-29 => 9);
+        assert_monday_week!(2016-03-01 => 9);
+        assert_monday_week!(2016-03-02 => 9);
+        assert_monday_week!(2016-03-03 => 9);
+        assert_monday_week!(2016-03-04 => 9);
+        assert_monday_week!(2016-03-05 => 9);
+        assert_monday_week!(2016-03-06 => 9);
+        assert_monday_week!(2016-03-07 => 10);
+
+        // DC
+        assert_monday_week!(2032-01-01 => 0);
+        assert_monday_week!(2032-01-02 => 0);
+        assert_monday_week!(2032-01-03 => 0);
+        assert_monday_week!(2032-01-04 => 0);
+        assert_monday_week!(2032-01-05 => 1);
+        assert_monday_week!(2032-01-06 => 1);
+        assert_monday_week!(2032-01-07 => 1);
+        assert_monday_week!(2032-02-28 => 8);
+        assert_monday_week!(2032-02-29 => 8);
+        assert_monday_week!(2032-03-01 => 9);
+        assert_monday_week!(2032-03-02 => 9);
+        assert_monday_week!(2032-03-03 => 9);
+        assert_monday_week!(2032-03-04 => 9);
+        assert_monday_week!(2032-03-05 => 9);
+        assert_monday_week!(2032-03-06 => 9);
+        assert_monday_week!(2032-03-07 => 9);
+
+        // ED
+        assert_monday_week!(2020-01-01 => 0);
+        assert_monday_week!(2020-01-02 => 0);
+        assert_monday_week!(2020-01-03 => 0);
+        assert_monday_week!(2020-01-04 => 0);
+        assert_monday_week!(2020-01-05 => 0);
+        assert_monday_week!(2020-01-06 => 1);
+        assert_monday_week!(2020-01-07 => 1);
+        assert_monday_week!(2020-02-28 => 8);
+        assert_monday_week!(2020-02-29 => 8);
+        assert_monday_week!(2020-03-01 => 8);
+        assert_monday_week!(2020-03-02 => 9);
+        assert_monday_week!(2020-03-03 => 9);
+        assert_monday_week!(2020-03-04 => 9);
+        assert_monday_week!(2020-03-05 => 9);
+        assert_monday_week!(2020-03-06 => 9);
+        assert_monday_week!(2020-03-07 => 9);
+
+        // FE
+        assert_monday_week!(2036-01-01 => 0);
+        assert_monday_week!(2036-01-02 => 0);
+        assert_monday_week!(2036-01-03 => 0);
+        assert_monday_week!(2036-01-04 => 0);
+        assert_monday_week!(2036-01-05 => 0);
+        assert_monday_week!(2036-01-06 => 0);
+        assert_monday_week!(2036-01-07 => 1);
+        assert_monday_week!(2036-02-28 => 8);
+        assert_monday_week!(2036-02-29 => 8);
+        assert_monday_week!(2036-03-01 => 8);
+        assert_monday_week!(2036-03-02 => 8);
+        assert_monday_week!(2036-03-03 => 9);
+        assert_monday_week!(2036-03-04 => 9);
+        assert_monday_week!(2036-03-05 => 9);
+        assert_monday_week!(2036-03-06 => 9);
+        assert_monday_week!(2036-03-07 => 9);
+
+        // GF
+        assert_monday_week!(2024-01-01 => 1);
+        assert_monday_week!(2024-01-02 => 1);
+        assert_monday_week!(2024-01-03 => 1);
+        assert_monday_week!(2024-01-04 => 1);
+        assert_monday_week!(2024-01-05 => 1);
+        assert_monday_week!(2024-01-06 => 1);
+        assert_monday_week!(2024-01-07 => 1);
+        assert_monday_week!(2024-02-28 => 9);
+        assert_monday_week!(2024-02-29 => 9);
+        assert_monday_week!(2024-03-01 => 9);
+        assert_monday_week!(2024-03-02 => 9);
+        assert_monday_week!(2024-03-03 => 9);
+        assert_monday_week!(2024-03-04 => 10);
+        assert_monday_week!(2024-03-05 => 10);
+        assert_monday_week!(2024-03-06 => 10);
+        assert_monday_week!(2024-03-07 => 10);
+    }
+
+    #[test]
+    #[allow(clippy::zero_prefixed_literal)]
+    fn test_sunday_based_week() {
+        macro_rules! assert_sunday_week {
+            ($y:literal - $m:literal - $d:literal => $week:literal) => {
+                assert_eq!(Date::from_ymd($y, $m, $d).sunday_based_week(), $week);
+            };
+        }
+
+        // A
+        assert_sunday_week!(2023-01-01 => 1);
+        assert_sunday_week!(2023-01-02 => 1);
+        assert_sunday_week!(2023-01-03 => 1);
+        assert_sunday_week!(2023-01-04 => 1);
+        assert_sunday_week!(2023-01-05 => 1);
+        assert_sunday_week!(2023-01-06 => 1);
+        assert_sunday_week!(2023-01-07 => 1);
+
+        // B
+        assert_sunday_week!(2022-01-01 => 0);
+        assert_sunday_week!(2022-01-02 => 1);
+        assert_sunday_week!(2022-01-03 => 1);
+        assert_sunday_week!(2022-01-04 => 1);
+        assert_sunday_week!(2022-01-05 => 1);
+        assert_sunday_week!(2022-01-06 => 1);
+        assert_sunday_week!(2022-01-07 => 1);
+
+        // C
+        assert_sunday_week!(2021-01-01 => 0);
+        assert_sunday_week!(2021-01-02 => 0);
+        assert_sunday_week!(2021-01-03 => 1);
+        assert_sunday_week!(2021-01-04 => 1);
+        assert_sunday_week!(2021-01-05 => 1);
+        assert_sunday_week!(2021-01-06 => 1);
+        assert_sunday_week!(2021-01-07 => 1);
+
+        // D
+        assert_sunday_week!(2026-01-01 => 0);
+        assert_sunday_week!(2026-01-02 => 0);
+        assert_sunday_week!(2026-01-03 => 0);
+        assert_sunday_week!(2026-01-04 => 1);
+        assert_sunday_week!(2026-01-05 => 1);
+        assert_sunday_week!(2026-01-06 => 1);
+        assert_sunday_week!(2026-01-07 => 1);
+
+        // E
+        assert_sunday_week!(2025-01-01 => 0);
+        assert_sunday_week!(2025-01-02 => 0);
+        assert_sunday_week!(2025-01-03 => 0);
+        assert_sunday_week!(2025-01-04 => 0);
+        assert_sunday_week!(2025-01-05 => 1);
+        assert_sunday_week!(2025-01-06 => 1);
+        assert_sunday_week!(2025-01-07 => 1);
+
+        // F
+        assert_sunday_week!(2019-01-01 => 0);
+        assert_sunday_week!(2019-01-02 => 0);
+        assert_sunday_week!(2019-01-03 => 0);
+        assert_sunday_week!(2019-01-04 => 0);
+        assert_sunday_week!(2019-01-05 => 0);
+        assert_sunday_week!(2019-01-06 => 1);
+        assert_sunday_week!(2019-01-07 => 1);
+
+        // G
+        assert_sunday_week!(2018-01-01 => 0);
+        assert_sunday_week!(2018-01-02 => 0);
+        assert_sunday_week!(2018-01-03 => 0);
+        assert_sunday_week!(2018-01-04 => 0);
+        assert_sunday_week!(2018-01-05 => 0);
+        assert_sunday_week!(2018-01-06 => 0);
+        assert_sunday_week!(2018-01-07 => 1);
+
+        // AG
+        assert_sunday_week!(2012-01-01 => 1);
+        assert_sunday_week!(2012-01-02 => 1);
+        assert_sunday_week!(2012-01-03 => 1);
+        assert_sunday_week!(2012-01-04 => 1);
+        assert_sunday_week!(2012-01-05 => 1);
+        assert_sunday_week!(2012-01-06 => 1);
+        assert_sunday_week!(2012-01-07 => 1);
+        assert_sunday_week!(2012-02-28 => 9);
+        assert_sunday_week!(2012-02-29 => 9);
+        assert_sunday_week!(2012-03-01 => 9);
+        assert_sunday_week!(2012-03-02 => 9);
+        assert_sunday_week!(2012-03-03 => 9);
+        assert_sunday_week!(2012-03-04 => 10);
+        assert_sunday_week!(2012-03-05 => 10);
+        assert_sunday_week!(2012-03-06 => 10);
+        assert_sunday_week!(2012-03-07 => 10);
+
+        // BA
+        assert_sunday_week!(2028-01-01 => 0);
+        assert_sunday_week!(2028-01-02 => 1);
+        assert_sunday_week!(2028-01-03 => 1);
+        assert_sunday_week!(2028-01-04 => 1);
+        assert_sunday_week!(2028-01-05 => 1);
+        assert_sunday_week!(2028-01-06 => 1);
+        assert_sunday_week!(2028-01-07 => 1);
+        assert_sunday_week!(2028-02-28 => 9);
+        assert_sunday_week!(2028-02-29 => 9);
+        assert_sunday_week!(2028-03-01 => 9);
+        assert_sunday_week!(2028-03-02 => 9);
+        assert_sunday_week!(2028-03-03 => 9);
+        assert_sunday_week!(2028-03-04 => 9);
+        assert_sunday_week!(2028-03-05 => 10);
+        assert_sunday_week!(2028-03-06 => 10);
+        assert_sunday_week!(2028-03-07 => 10);
+
+        // CB
+        assert_sunday_week!(2016-01-01 => 0);
+        assert_sunday_week!(2016-01-02 => 0);
+        assert_sunday_week!(2016-01-03 => 1);
+        assert_sunday_week!(2016-01-04 => 1);
+        assert_sunday_week!(2016-01-05 => 1);
+        assert_sunday_week!(2016-01-06 => 1);
+        assert_sunday_week!(2016-01-07 => 1);
+        assert_sunday_week!(2016-02-28 => 9);
+        assert_sunday_week!(2016-02-29 => 9);
+        assert_sunday_week!(2016-03-01 => 9);
+        assert_sunday_week!(2016-03-02 => 9);
+        assert_sunday_week!(2016-03-03 => 9);
+        assert_sunday_week!(2016-03-04 => 9);
+        assert_sunday_week!(2016-03-05 => 9);
+        assert_sunday_week!(2016-03-06 => 10);
+        assert_sunday_week!(2016-03-07 => 10);
+
+        // DC
+        assert_sunday_week!(2032-01-01 => 0);
+        assert_sunday_week!(2032-01-02 => 0);
+        assert_sunday_week!(2032-01-03 => 0);
+        assert_sunday_week!(2032-01-04 => 1);
+        assert_sunday_week!(2032-01-05 => 1);
+        assert_sunday_week!(2032-01-06 => 1);
+        assert_sunday_week!(2032-01-07 => 1);
+        assert_sunday_week!(2032-02-28 => 8);
+        assert_sunday_week!(2032-02-29 => 9);
+        assert_sunday_week!(2032-03-01 => 9);
+        assert_sunday_week!(2032-03-02 => 9);
+        assert_sunday_week!(2032-03-03 => 9);
+        assert_sunday_week!(2032-03-04 => 9);
+        assert_sunday_week!(2032-03-05 => 9);
+        assert_sunday_week!(2032-03-06 => 9);
+        assert_sunday_week!(2032-03-07 => 10);
+
+        // ED
+        assert_sunday_week!(2020-01-01 => 0);
+        assert_sunday_week!(2020-01-02 => 0);
+        assert_sunday_week!(2020-01-03 => 0);
+        assert_sunday_week!(2020-01-04 => 0);
+        assert_sunday_week!(2020-01-05 => 1);
+        assert_sunday_week!(2020-01-06 => 1);
+        assert_sunday_week!(2020-01-07 => 1);
+        assert_sunday_week!(2020-02-28 => 8);
+        assert_sunday_week!(2020-02-29 => 8);
+        assert_sunday_week!(2020-03-01 => 9);
+        assert_sunday_week!(2020-03-02 => 9);
+        assert_sunday_week!(2020-03-03 => 9);
+        assert_sunday_week!(2020-03-04 => 9);
+        assert_sunday_week!(2020-03-05 => 9);
+        assert_sunday_week!(2020-03-06 => 9);
+        assert_sunday_week!(2020-03-07 => 9);
+
+        // FE
+        assert_sunday_week!(2036-01-01 => 0);
+        assert_sunday_week!(2036-01-02 => 0);
+        assert_sunday_week!(2036-01-03 => 0);
+        assert_sunday_week!(2036-01-04 => 0);
+        assert_sunday_week!(2036-01-05 => 0);
+        assert_sunday_week!(2036-01-06 => 1);
+        assert_sunday_week!(2036-01-07 => 1);
+        assert_sunday_week!(2036-02-28 => 8);
+        assert_sunday_week!(2036-02-29 => 8);
+        assert_sunday_week!(2036-03-01 => 8);
+        assert_sunday_week!(2036-03-02 => 9);
+        assert_sunday_week!(2036-03-03 => 9);
+        assert_sunday_week!(2036-03-04 => 9);
+        assert_sunday_week!(2036-03-05 => 9);
+        assert_sunday_week!(2036-03-06 => 9);
+        assert_sunday_week!(2036-03-07 => 9);
+
+        // GF
+        assert_sunday_week!(2024-01-01 => 0);
+        assert_sunday_week!(2024-01-02 => 0);
+        assert_sunday_week!(2024-01-03 => 0);
+        assert_sunday_week!(2024-01-04 => 0);
+        assert_sunday_week!(2024-01-05 => 0);
+        assert_sunday_week!(2024-01-06 => 0);
+        assert_sunday_week!(2024-01-07 => 1);
+        assert_sunday_week!(2024-02-28 => 8);
+        assert_sunday_week!(2024-02-29 => 8);
+        assert_sunday_week!(2024-03-01 => 8);
+        assert_sunday_week!(2024-03-02 => 8);
+        assert_sunday_week!(2024-03-03 => 9);
+        assert_sunday_week!(2024-03-04 => 9);
+        assert_sunday_week!(2024-03-05 => 9);
+        assert_sunday_week!(2024-03-06 => 9);
+        assert_sunday_week!(2024-03-07 => 9);
+    }
+
+    #[test]
+    #[allow(clippy::zero_prefixed_literal)]
+    fn test_parse_monday_based_week() {
+        macro_rules! assert_dwy {
+            ($weekday:ident $week:literal $year:literal => $ordinal:literal) => {
+                assert_eq!(
+                    Date::parse(
+                        concat!(
+                            stringify!($weekday),
+                            " ",
+                            stringify!($week),
+                            " ",
+                            stringify!($year)
+                        ),
+                        "%a %W %Y"
+                    ),
+                    Ok(Date::from_yo($year, $ordinal))
+                );
+            };
+        }
+
+        // A
+        assert_dwy!(Sun 00 2023 => 001);
+        assert_dwy!(Mon 01 2023 => 002);
+        assert_dwy!(Tue 01 2023 => 003);
+        assert_dwy!(Wed 01 2023 => 004);
+        assert_dwy!(Thu 01 2023 => 005);
+        assert_dwy!(Fri 01 2023 => 006);
+        assert_dwy!(Sat 01 2023 => 007);
+
+        // B
+        assert_dwy!(Sat 00 2022 => 001);
+        assert_dwy!(Sun 00 2022 => 002);
+        assert_dwy!(Mon 01 2022 => 003);
+        assert_dwy!(Tue 01 2022 => 004);
+        assert_dwy!(Wed 01 2022 => 005);
+        assert_dwy!(Thu 01 2022 => 006);
+        assert_dwy!(Fri 01 2022 => 007);
+
+        // C
+        assert_dwy!(Fri 00 2021 => 001);
+        assert_dwy!(Sat 00 2021 => 002);
+        assert_dwy!(Sun 00 2021 => 003);
+        assert_dwy!(Mon 01 2021 => 004);
+        assert_dwy!(Tue 01 2021 => 005);
+        assert_dwy!(Wed 01 2021 => 006);
+        assert_dwy!(Thu 01 2021 => 007);
+
+        // D
+        assert_dwy!(Thu 00 2026 => 001);
+        assert_dwy!(Fri 00 2026 => 002);
+        assert_dwy!(Sat 00 2026 => 003);
+        assert_dwy!(Sun 00 2026 => 004);
+        assert_dwy!(Mon 01 2026 => 005);
+        assert_dwy!(Tue 01 2026 => 006);
+        assert_dwy!(Wed 01 2026 => 007);
+
+        // E
+        assert_dwy!(Wed 00 2025 => 001);
+        assert_dwy!(Thu 00 2025 => 002);
+        assert_dwy!(Fri 00 2025 => 003);
+        assert_dwy!(Sat 00 2025 => 004);
+        assert_dwy!(Sun 00 2025 => 005);
+        assert_dwy!(Mon 01 2025 => 006);
+        assert_dwy!(Tue 01 2025 => 007);
+
+        // F
+        assert_dwy!(Tue 00 2019 => 001);
+        assert_dwy!(Wed 00 2019 => 002);
+        assert_dwy!(Thu 00 2019 => 003);
+        assert_dwy!(Fri 00 2019 => 004);
+        assert_dwy!(Sat 00 2019 => 005);
+        assert_dwy!(Sun 00 2019 => 006);
+        assert_dwy!(Mon 01 2019 => 007);
+
+        // G
+        assert_dwy!(Mon 01 2018 => 001);
+        assert_dwy!(Tue 01 2018 => 002);
+        assert_dwy!(Wed 01 2018 => 003);
+        assert_dwy!(Thu 01 2018 => 004);
+        assert_dwy!(Fri 01 2018 => 005);
+        assert_dwy!(Sat 01 2018 => 006);
+        assert_dwy!(Sun 01 2018 => 007);
+
+        // AG
+        assert_dwy!(Sun 00 2012 => 001);
+        assert_dwy!(Mon 01 2012 => 002);
+        assert_dwy!(Tue 01 2012 => 003);
+        assert_dwy!(Wed 01 2012 => 004);
+        assert_dwy!(Thu 01 2012 => 005);
+        assert_dwy!(Fri 01 2012 => 006);
+        assert_dwy!(Sat 01 2012 => 007);
+        assert_dwy!(Tue 09 2012 => 059);
+        assert_dwy!(Wed 09 2012 => 060);
+        assert_dwy!(Thu 09 2012 => 061);
+        assert_dwy!(Fri 09 2012 => 062);
+        assert_dwy!(Sat 09 2012 => 063);
+        assert_dwy!(Sun 09 2012 => 064);
+        assert_dwy!(Mon 10 2012 => 065);
+        assert_dwy!(Tue 10 2012 => 066);
+        assert_dwy!(Wed 10 2012 => 067);
+
+        // BA
+        assert_dwy!(Sat 00 2028 => 001);
+        assert_dwy!(Sun 00 2028 => 002);
+        assert_dwy!(Mon 01 2028 => 003);
+        assert_dwy!(Tue 01 2028 => 004);
+        assert_dwy!(Wed 01 2028 => 005);
+        assert_dwy!(Thu 01 2028 => 006);
+        assert_dwy!(Fri 01 2028 => 007);
+        assert_dwy!(Mon 09 2028 => 059);
+        assert_dwy!(Tue 09 2028 => 060);
+        assert_dwy!(Wed 09 2028 => 061);
+        assert_dwy!(Thu 09 2028 => 062);
+        assert_dwy!(Fri 09 2028 => 063);
+        assert_dwy!(Sat 09 2028 => 064);
+        assert_dwy!(Sun 09 2028 => 065);
+        assert_dwy!(Mon 10 2028 => 066);
+        assert_dwy!(Tue 10 2028 => 067);
+
+        // CB
+        assert_dwy!(Fri 00 2016 => 001);
+        assert_dwy!(Sat 00 2016 => 002);
+        assert_dwy!(Sun 00 2016 => 003);
+        assert_dwy!(Mon 01 2016 => 004);
+        assert_dwy!(Tue 01 2016 => 005);
+        assert_dwy!(Wed 01 2016 => 006);
+        assert_dwy!(Thu 01 2016 => 007);
+        assert_dwy!(Sun 08 2016 => 059);
+        assert_dwy!(Mon 09 2016 => 060);
+        assert_dwy!(Tue 09 2016 => 061);
+        assert_dwy!(Wed 09 2016 => 062);
+        assert_dwy!(Thu 09 2016 => 063);
+        assert_dwy!(Fri 09 2016 => 064);
+        assert_dwy!(Sat 09 2016 => 065);
+        assert_dwy!(Sun 09 2016 => 066);
+        assert_dwy!(Mon 10 2016 => 067);
+
+        // DC
+        assert_dwy!(Thu 00 2032 => 001);
+        assert_dwy!(Fri 00 2032 => 002);
+        assert_dwy!(Sat 00 2032 => 003);
+        assert_dwy!(Sun 00 2032 => 004);
+        assert_dwy!(Mon 01 2032 => 005);
+        assert_dwy!(Tue 01 2032 => 006);
+        assert_dwy!(Wed 01 2032 => 007);
+        assert_dwy!(Sat 08 2032 => 059);
+        assert_dwy!(Sun 08 2032 => 060);
+        assert_dwy!(Mon 09 2032 => 061);
+        assert_dwy!(Tue 09 2032 => 062);
+        assert_dwy!(Wed 09 2032 => 063);
+        assert_dwy!(Thu 09 2032 => 064);
+        assert_dwy!(Fri 09 2032 => 065);
+        assert_dwy!(Sat 09 2032 => 066);
+        assert_dwy!(Sun 09 2032 => 067);
+
+        // ED
+        assert_dwy!(Wed 00 2020 => 001);
+        assert_dwy!(Thu 00 2020 => 002);
+        assert_dwy!(Fri 00 2020 => 003);
+        assert_dwy!(Sat 00 2020 => 004);
+        assert_dwy!(Sun 00 2020 => 005);
+        assert_dwy!(Mon 01 2020 => 006);
+        assert_dwy!(Tue 01 2020 => 007);
+        assert_dwy!(Fri 08 2020 => 059);
+        assert_dwy!(Sat 08 2020 => 060);
+        assert_dwy!(Sun 08 2020 => 061);
+        assert_dwy!(Mon 09 2020 => 062);
+        assert_dwy!(Tue 09 2020 => 063);
+        assert_dwy!(Wed 09 2020 => 064);
+        assert_dwy!(Thu 09 2020 => 065);
+        assert_dwy!(Fri 09 2020 => 066);
+        assert_dwy!(Sat 09 2020 => 067);
+
+        // FE
+        assert_dwy!(Tue 00 2036 => 001);
+        assert_dwy!(Wed 00 2036 => 002);
+        assert_dwy!(Thu 00 2036 => 003);
+        assert_dwy!(Fri 00 2036 => 004);
+        assert_dwy!(Sat 00 2036 => 005);
+        assert_dwy!(Sun 00 2036 => 006);
+        assert_dwy!(Mon 01 2036 => 007);
+        assert_dwy!(Thu 08 2036 => 059);
+        assert_dwy!(Fri 08 2036 => 060);
+        assert_dwy!(Sat 08 2036 => 061);
+        assert_dwy!(Sun 08 2036 => 062);
+        assert_dwy!(Mon 09 2036 => 063);
+        assert_dwy!(Tue 09 2036 => 064);
+        assert_dwy!(Wed 09 2036 => 065);
+        assert_dwy!(Thu 09 2036 => 066);
+        assert_dwy!(Fri 09 2036 => 067);
+
+        // GF
+        assert_dwy!(Mon 01 2024 => 001);
+        assert_dwy!(Tue 01 2024 => 002);
+        assert_dwy!(Wed 01 2024 => 003);
+        assert_dwy!(Thu 01 2024 => 004);
+        assert_dwy!(Fri 01 2024 => 005);
+        assert_dwy!(Sat 01 2024 => 006);
+        assert_dwy!(Sun 01 2024 => 007);
+        assert_dwy!(Wed 09 2024 => 059);
+        assert_dwy!(Thu 09 2024 => 060);
+        assert_dwy!(Fri 09 2024 => 061);
+        assert_dwy!(Sat 09 2024 => 062);
+        assert_dwy!(Sun 09 2024 => 063);
+        assert_dwy!(Mon 10 2024 => 064);
+        assert_dwy!(Tue 10 2024 => 065);
+        assert_dwy!(Wed 10 2024 => 066);
+        assert_dwy!(Thu 10 2024 => 067);
+    }
+
+    #[test]
+    #[allow(clippy::zero_prefixed_literal)]
+    fn test_parse_sunday_based_week() {
+        macro_rules! assert_dwy {
+            ($weekday:ident $week:literal $year:literal => $ordinal:literal) => {
+                assert_eq!(
+                    Date::parse(
+                        concat!(
+                            stringify!($weekday),
+                            " ",
+                            stringify!($week),
+                            " ",
+                            stringify!($year)
+                        ),
+                        "%a %U %Y"
+                    ),
+                    Ok(Date::from_yo($year, $ordinal))
+                );
+            };
+        }
+
+        // A
+        assert_dwy!(Mon 00 2018 => 001);
+        assert_dwy!(Tue 00 2018 => 002);
+        assert_dwy!(Wed 00 2018 => 003);
+        assert_dwy!(Thu 00 2018 => 004);
+        assert_dwy!(Fri 00 2018 => 005);
+        assert_dwy!(Sat 00 2018 => 006);
+        assert_dwy!(Sun 01 2018 => 007);
+
+        // B
+        assert_dwy!(Sun 01 2023 => 001);
+        assert_dwy!(Mon 01 2023 => 002);
+        assert_dwy!(Tue 01 2023 => 003);
+        assert_dwy!(Wed 01 2023 => 004);
+        assert_dwy!(Thu 01 2023 => 005);
+        assert_dwy!(Fri 01 2023 => 006);
+        assert_dwy!(Sat 01 2023 => 007);
+
+        // C
+        assert_dwy!(Sat 00 2022 => 001);
+        assert_dwy!(Sun 01 2022 => 002);
+        assert_dwy!(Mon 01 2022 => 003);
+        assert_dwy!(Tue 01 2022 => 004);
+        assert_dwy!(Wed 01 2022 => 005);
+        assert_dwy!(Thu 01 2022 => 006);
+        assert_dwy!(Fri 01 2022 => 007);
+
+        // D
+        assert_dwy!(Fri 00 2021 => 001);
+        assert_dwy!(Sat 00 2021 => 002);
+        assert_dwy!(Sun 01 2021 => 003);
+        assert_dwy!(Mon 01 2021 => 004);
+        assert_dwy!(Tue 01 2021 => 005);
+        assert_dwy!(Wed 01 2021 => 006);
+        assert_dwy!(Thu 01 2021 => 007);
+
+        // E
+        assert_dwy!(Thu 00 2026 => 001);
+        assert_dwy!(Fri 00 2026 => 002);
+        assert_dwy!(Sat 00 2026 => 003);
+        assert_dwy!(Sun 01 2026 => 004);
+        assert_dwy!(Mon 01 2026 => 005);
+        assert_dwy!(Tue 01 2026 => 006);
+        assert_dwy!(Wed 01 2026 => 007);
+
+        // F
+        assert_dwy!(Wed 00 2025 => 001);
+        assert_dwy!(Thu 00 2025 => 002);
+        assert_dwy!(Fri 00 2025 => 003);
+        assert_dwy!(Sat 00 2025 => 004);
+        assert_dwy!(Sun 01 2025 => 005);
+        assert_dwy!(Mon 01 2025 => 006);
+        assert_dwy!(Tue 01 2025 => 007);
+
+        // G
+        assert_dwy!(Tue 00 2019 => 001);
+        assert_dwy!(Wed 00 2019 => 002);
+        assert_dwy!(Thu 00 2019 => 003);
+        assert_dwy!(Fri 00 2019 => 004);
+        assert_dwy!(Sat 00 2019 => 005);
+        assert_dwy!(Sun 01 2019 => 006);
+        assert_dwy!(Mon 01 2019 => 007);
+
+        // AG
+        assert_dwy!(Mon 00 2024 => 001);
+        assert_dwy!(Tue 00 2024 => 002);
+        assert_dwy!(Wed 00 2024 => 003);
+        assert_dwy!(Thu 00 2024 => 004);
+        assert_dwy!(Fri 00 2024 => 005);
+        assert_dwy!(Sat 00 2024 => 006);
+        assert_dwy!(Sun 01 2024 => 007);
+        assert_dwy!(Wed 08 2024 => 059);
+        assert_dwy!(Thu 08 2024 => 060);
+        assert_dwy!(Fri 08 2024 => 061);
+        assert_dwy!(Sat 08 2024 => 062);
+        assert_dwy!(Sun 09 2024 => 063);
+        assert_dwy!(Mon 09 2024 => 064);
+        assert_dwy!(Tue 09 2024 => 065);
+        assert_dwy!(Wed 09 2024 => 066);
+        assert_dwy!(Thu 09 2024 => 067);
+
+        // BA
+        assert_dwy!(Sun 01 2012 => 001);
+        assert_dwy!(Mon 01 2012 => 002);
+        assert_dwy!(Tue 01 2012 => 003);
+        assert_dwy!(Wed 01 2012 => 004);
+        assert_dwy!(Thu 01 2012 => 005);
+        assert_dwy!(Fri 01 2012 => 006);
+        assert_dwy!(Sat 01 2012 => 007);
+        assert_dwy!(Tue 09 2012 => 059);
+        assert_dwy!(Wed 09 2012 => 060);
+        assert_dwy!(Thu 09 2012 => 061);
+        assert_dwy!(Fri 09 2012 => 062);
+        assert_dwy!(Sat 09 2012 => 063);
+        assert_dwy!(Sun 10 2012 => 064);
+        assert_dwy!(Mon 10 2012 => 065);
+        assert_dwy!(Tue 10 2012 => 066);
+        assert_dwy!(Wed 10 2012 => 067);
+
+        // CB
+        assert_dwy!(Sat 00 2028 => 001);
+        assert_dwy!(Sun 01 2028 => 002);
+        assert_dwy!(Mon 01 2028 => 003);
+        assert_dwy!(Tue 01 2028 => 004);
+        assert_dwy!(Wed 01 2028 => 005);
+        assert_dwy!(Thu 01 2028 => 006);
+        assert_dwy!(Fri 01 2028 => 007);
+        assert_dwy!(Mon 09 2028 => 059);
+        assert_dwy!(Tue 09 2028 => 060);
+        assert_dwy!(Wed 09 2028 => 061);
+        assert_dwy!(Thu 09 2028 => 062);
+        assert_dwy!(Fri 09 2028 => 063);
+        assert_dwy!(Sat 09 2028 => 064);
+        assert_dwy!(Sun 10 2028 => 065);
+        assert_dwy!(Mon 10 2028 => 066);
+        assert_dwy!(Tue 10 2028 => 067);
+
+        // DC
+        assert_dwy!(Fri 00 2016 => 001);
+        assert_dwy!(Sat 00 2016 => 002);
+        assert_dwy!(Sun 01 2016 => 003);
+        assert_dwy!(Mon 01 2016 => 004);
+        assert_dwy!(Tue 01 2016 => 005);
+        assert_dwy!(Wed 01 2016 => 006);
+        assert_dwy!(Thu 01 2016 => 007);
+        assert_dwy!(Sun 09 2016 => 059);
+        assert_dwy!(Mon 09 2016 => 060);
+        assert_dwy!(Tue 09 2016 => 061);
+        assert_dwy!(Wed 09 2016 => 062);
+        assert_dwy!(Thu 09 2016 => 063);
+        assert_dwy!(Fri 09 2016 => 064);
+        assert_dwy!(Sat 09 2016 => 065);
+        assert_dwy!(Sun 10 2016 => 066);
+        assert_dwy!(Mon 10 2016 => 067);
+
+        // ED
+        assert_dwy!(Thu 00 2032 => 001);
+        assert_dwy!(Fri 00 2032 => 002);
+        assert_dwy!(Sat 00 2032 => 003);
+        assert_dwy!(Sun 01 2032 => 004);
+        assert_dwy!(Mon 01 2032 => 005);
+        assert_dwy!(Tue 01 2032 => 006);
+        assert_dwy!(Wed 01 2032 => 007);
+        assert_dwy!(Sat 08 2032 => 059);
+        assert_dwy!(Sun 09 2032 => 060);
+        assert_dwy!(Mon 09 2032 => 061);
+        assert_dwy!(Tue 09 2032 => 062);
+        assert_dwy!(Wed 09 2032 => 063);
+        assert_dwy!(Thu 09 2032 => 064);
+        assert_dwy!(Fri 09 2032 => 065);
+        assert_dwy!(Sat 09 2032 => 066);
+        assert_dwy!(Sun 10 2032 => 067);
+
+        // FE
+        assert_dwy!(Wed 00 2020 => 001);
+        assert_dwy!(Thu 00 2020 => 002);
+        assert_dwy!(Fri 00 2020 => 003);
+        assert_dwy!(Sat 00 2020 => 004);
+        assert_dwy!(Sun 01 2020 => 005);
+        assert_dwy!(Mon 01 2020 => 006);
+        assert_dwy!(Tue 01 2020 => 007);
+        assert_dwy!(Fri 08 2020 => 059);
+        assert_dwy!(Sat 08 2020 => 060);
+        assert_dwy!(Sun 09 2020 => 061);
+        assert_dwy!(Mon 09 2020 => 062);
+        assert_dwy!(Tue 09 2020 => 063);
+        assert_dwy!(Wed 09 2020 => 064);
+        assert_dwy!(Thu 09 2020 => 065);
+        assert_dwy!(Fri 09 2020 => 066);
+        assert_dwy!(Sat 09 2020 => 067);
+
+        // GF
+        assert_dwy!(Tue 00 2036 => 001);
+        assert_dwy!(Wed 00 2036 => 002);
+        assert_dwy!(Thu 00 2036 => 003);
+        assert_dwy!(Fri 00 2036 => 004);
+        assert_dwy!(Sat 00 2036 => 005);
+        assert_dwy!(Sun 01 2036 => 006);
+        assert_dwy!(Mon 01 2036 => 007);
+        assert_dwy!(Thu 08 2036 => 059);
+        assert_dwy!(Fri 08 2036 => 060);
+        assert_dwy!(Sat 08 2036 => 061);
+        assert_dwy!(Sun 09 2036 => 062);
+        assert_dwy!(Mon 09 2036 => 063);
+        assert_dwy!(Tue 09 2036 => 064);
+        assert_dwy!(Wed 09 2036 => 065);
+        assert_dwy!(Thu 09 2036 => 066);
+        assert_dwy!(Fri 09 2036 => 067);
+    }
+
+    #[test]
+    fn is_leap_year() {
+        use super::is_leap_year;
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(2004));
+        assert!(!is_leap_year(2005));
+        assert!(!is_leap_year(2100));
+    }
+
+    #[test]
+    fn date_is_leap_year() {
+        assert!(!ymd!(1900, 2, 1).is_leap_year());
+        assert!(ymd!(2000, 2, 1).is_leap_year());
+        assert!(ymd!(2004, 12, 31).is_leap_year());
+        assert!(!ymd!(2005, 6, 1).is_leap_year());
+        assert!(!ymd!(2100, 1, 1).is_leap_year());
+        assert!(ymd!(-4, 1, 1).is_leap_year());
+    }
+
+    #[test]
+    fn days_in_month() {
+        let expected = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+        for (month, &days) in (1..=12).zip(expected.iter()) {
+            assert_eq!(Date::from_ymd(2019, month, 1).days_in_month(), days);
+        }
+        assert_eq!(ymd!(1900, 2, 1).days_in_month(), 28);
+        assert_eq!(ymd!(2000, 2, 29).days_in_month(), 29);
+        assert_eq!(ymd!(2020, 2, 10).days_in_month(), 29);
+        assert_eq!(ymd!(2100, 2, 28).days_in_month(), 28);
+    }
+
+    #[test]
+    fn days_in_year() {
+        use super::days_in_year;
+        assert_eq!(days_in_year(1900), 365);
+        assert_eq!(days_in_year(2000), 366);
+        assert_eq!(days_in_year(2004), 366);
+        assert_eq!(days_in_year(2005), 365);
+        assert_eq!(days_in_year(2100), 365);
+    }
 
-        // D
-        assert_monday_week!(2026-01-01 => 0);
-        assert_monday_week!(2026-01-02 => 0);
-        assert_monday_week!(2026-01-03 => 0);
-        assert_monday_week!(2026-01-04 => 0);
-        assert_monday_week!(2026-01-05 => 1);
-        assert_monday_week!(2026-01-06 => 1);
-        assert_monday_week!(2026-01-07 => 1);
+    #[test]
+    fn weeks_in_year() {
+        use super::weeks_in_year;
+        assert_eq!(weeks_in_year(2019), 52);
+        assert_eq!(weeks_in_year(2020), 53);
+    }
 
-        // E
-        assert_monday_week!(2025-01-01 => 0);
-        assert_monday_week!(2025-01-02 => 0);
-        assert_monday_week!(2025-01-03 => 0);
-        assert_monday_week!(2025-01-04 => 0);
-        assert_monday_week!(2025-01-05 => 0);
-        assert_monday_week!(2025-01-06 => 1);
-        assert_monday_week!(2025-01-07 => 1);
+    #[test]
+    fn year() {
+        assert_eq!(yo!(2019, 2).year(), 2019);
+        assert_eq!(yo!(2020, 2).year(), 2020);
+    }
 
-        // F
-        assert_monday_week!(2019-01-01 => 0);
-        assert_monday_week!(2019-01-02 => 0);
-        assert_monday_week!(2019-01-03 => 0);
-        assert_monday_week!(2019-01-04 => 0);
-        assert_monday_week!(2019-01-05 => 0);
-        assert_monday_week!(2019-01-06 => 0);
-        assert_monday_week!(2019-01-07 => 1);
+    #[test]
+    fn month() {
+        assert_eq!(yo!(2019, 2).month(), 1);
+        assert_eq!(yo!(2020, 2).month(), 1);
+        assert_eq!(yo!(2019, 60).month(), 3);
+        assert_eq!(yo!(2020, 60).month(), 2);
+    }
 
-        // G
-        assert_monday_week!(2018-01-01 => 1);
-        assert_monday_week!(2018-01-02 => 1);
-        assert_monday_week!(2018-01-03 => 1);
-        assert_monday_week!(2018-01-04 => 1);
-        assert_monday_week!(2018-01-05 => 1);
-        assert_monday_week!(2018-01-06 => 1);
-        assert_monday_week!(2018-01-07 => 1);
+    #[test]
+    fn day() {
+        assert_eq!(yo!(2019, 2).day(), 2);
+        assert_eq!(yo!(2020, 2).day(), 2);
+        assert_eq!(yo!(2019, 60).day(), 1);
+        assert_eq!(yo!(2020, 60).day(), 29);
+    }
 
-        // AG
-        assert_monday_week!(2012-01-01 => 0);
-        assert_monday_week!(2012-01-02 => 1);
-        assert_monday_week!(2012-01-03 => 1);
-        assert_monday_week!(2012-01-04 => 1);
-        assert_monday_week!(2012-01-05 => 1);
-        assert_monday_week!(2012-01-06 => 1);
-        assert_monday_week!(2012-01-07 => 1);
-        assert_monday_week!(2012-02-28 => 9);
-        assert_monday_week!(2012-02-29 => 9);
-        assert_monday_week!(2012-03-01 => 9);
-        assert_monday_week!(2012-03-02 => 9);
-        assert_monday_week!(2012-03-03 => 9);
-        assert_monday_week!(2012-03-04 => 9);
-        assert_monday_week!(2012-03-05 => 10);
-        assert_monday_week!(2012-03-06 => 10);
-        assert_monday_week!(2012-03-07 => 10);
+    #[test]
+    fn iso_year_week() {
+        assert_eq!(ymd!(2019, 1, 1).iso_year_week(), (2019, 1));
+        assert_eq!(ymd!(2019, 10, 4).iso_year_week(), (2019, 40));
+        assert_eq!(ymd!(2020, 1, 1).iso_year_week(), (2020, 1));
+        assert_eq!(ymd!(2020, 12, 31).iso_year_week(), (2020, 53));
+        assert_eq!(ymd!(2021, 1, 1).iso_year_week(), (2020, 53));
+    }
 
-        // BA
-        assert_monday_week!(2028-01-01 => 0);
-        assert_monday_week!(2028-01-02 => 0);
-        assert_monday_week!(2028-01-03 => 1);
-        assert_monday_week!(2028-01-04 => 1);
-        assert_monday_week!(2028-01-05 => 1);
-        assert_monday_week!(2028-01-06 => 1);
-        assert_monday_week!(2028-01-07 => 1);
-        assert_monday_week!(2028-02-28 => 9);
-        assert_monday_week!(2028-02-29 => 9);
-        assert_monday_week!(2028-03-01 => 9);
-        assert_monday_week!(2028-03-02 => 9);
-        assert_monday_week!(2028-03-03 => 9);
-        assert_monday_week!(2028-03-04 => 9);
-        assert_monday_week!(2028-03-05 => 9);
-        assert_monday_week!(2028-03-06 => 10);
-        assert_monday_week!(2028-03-07 => 10);
+    #[test]
+    fn week() {
+        assert_eq!(ymd!(2019, 1, 1).week(), 1);
+        assert_eq!(ymd!(2019, 10, 4).week(), 40);
+        assert_eq!(ymd!(2020, 1, 1).week(), 1);
+        assert_eq!(ymd!(2020, 12, 31).week(), 53);
+        assert_eq!(ymd!(2021, 1, 1).week(), 53);
+    }
 
-        // CB
-        assert_monday_week!(2016-01-01 => 0);
-        assert_monday_week!(2016-01-02 => 0);
-        assert_monday_week!(2016-01-03 => 0);
-        assert_monday_week!(2016-01-04 => 1);
-        assert_monday_week!(2016-01-05 => 1);
-        assert_monday_week!(2016-01-06 => 1);
-        assert_monday_week!(2016-01-07 => 1);
-        assert_monday_week!(2016-02-28 => 8);
-        assert_monday_week!(2016-02-29 => 9);
-        assert_monday_week!(2016-03-01 => 9);
-        assert_monday_week!(2016-03-02 => 9);
-        assert_monday_week!(2016-03-03 => 9);
-        assert_monday_week!(2016-03-04 => 9);
-        assert_monday_week!(2016-03-05 => 9);
-        assert_monday_week!(2016-03-06 => 9);
-        assert_monday_week!(2016-03-07 => 10);
+    #[test]
+    fn weekday() {
+        use Weekday::*;
+
+        // Verified against an independent implementation. Years before 1 were
+        // checked using the 400-year cycle of the proleptic Gregorian calendar.
+        let vectors: &[(i32, u8, u8, Weekday)] = &[
+            (-2000, 1, 1, Saturday),
+            (-2000, 2, 29, Tuesday),
+            (-1999, 12, 31, Monday),
+            (-1600, 3, 1, Wednesday),
+            (-1000, 6, 15, Sunday),
+            (-500, 2, 28, Wednesday),
+            (-401, 12, 31, Friday),
+            (-400, 1, 1, Saturday),
+            (-100, 3, 1, Thursday),
+            (-4, 2, 29, Thursday),
+            (-1, 1, 1, Friday),
+            (-1, 12, 31, Friday),
+            (0, 1, 1, Saturday),
+            (0, 2, 29, Tuesday),
+            (0, 3, 1, Wednesday),
+            (0, 12, 31, Sunday),
+            (1, 1, 1, Monday),
+            (4, 2, 29, Sunday),
+            (100, 3, 1, Monday),
+            (400, 2, 29, Tuesday),
+            (1000, 1, 1, Wednesday),
+            (1582, 10, 15, Friday),
+            (1600, 2, 29, Tuesday),
+            (1700, 3, 1, Monday),
+            (1776, 7, 4, Thursday),
+            (1800, 1, 1, Wednesday),
+            (1899, 12, 31, Sunday),
+            (1900, 1, 1, Monday),
+            (1900, 2, 28, Wednesday),
+            (1900, 3, 1, Thursday),
+            (1969, 7, 20, Sunday),
+            (1970, 1, 1, Thursday),
+            (1999, 12, 31, Friday),
+            (2000, 1, 1, Saturday),
+            (2000, 2, 29, Tuesday),
+            (2000, 3, 1, Wednesday),
+            (2019, 1, 1, Tuesday),
+            (2020, 2, 29, Saturday),
+            (2038, 1, 19, Tuesday),
+            (2100, 2, 28, Sunday),
+            (2100, 3, 1, Monday),
+            (2400, 2, 29, Tuesday),
+            (3000, 1, 1, Wednesday),
+            (4000, 12, 31, Sunday),
+            (5000, 6, 30, Monday),
+            (6000, 2, 29, Tuesday),
+            (7777, 7, 7, Monday),
+            (8000, 1, 1, Saturday),
+            (9000, 3, 1, Saturday),
+            (9999, 1, 1, Friday),
+            (9999, 12, 31, Friday),
+        ];
 
-        // DC
-        assert_monday_week!(2032-01-01 => 0);
-        assert_monday_week!(2032-01-02 => 0);
-        assert_monday_week!(2032-01-03 => 0);
-        assert_monday_week!(2032-01-04 => 0);
-        assert_monday_week!(2032-01-05 => 1);
-        assert_monday_week!(2032-01-06 => 1);
-        assert_monday_week!(2032-01-07 => 1);
-        assert_monday_week!(2032-02-28 => 8);
-        assert_monday_week!(2032-02-29 => 8);
-        assert_monday_week!(2032-03-01 => 9);
-        assert_monday_week!(2032-03-02 => 9);
-        assert_monday_week!(2032-03-03 => 9);
-        assert_monday_week!(2032-03-04 => 9);
-        assert_monday_week!(2032-03-05 => 9);
-        assert_monday_week!(2032-03-06 => 9);
-        assert_monday_week!(2032-03-07 => 9);
+        for &(year, month, day, weekday) in vectors {
+            assert_eq!(
+                Date::from_ymd(year, month, day).weekday(),
+                weekday,
+                "{}-{}-{}",
+                year,
+                month,
+                day
+            );
+        }
+    }
 
-        // ED
-        assert_monday_week!(2020-01-01 => 0);
-        assert_monday_week!(2020-01-02 => 0);
-        assert_monday_week!(2020-01-03 => 0);
-        assert_monday_week!(2020-01-04 => 0);
-        assert_monday_week!(2020-01-05 => 0);
-        assert_monday_week!(2020-01-06 => 1);
-        assert_monday_week!(2020-01-07 => 1);
-        assert_monday_week!(2020-02-28 => 8);
-        assert_monday_week!(2020-02-29 => 8);
-        assert_monday_week!(2020-03-01 => 8);
-        assert_monday_week!(2020-03-02 => 9);
-        assert_monday_week!(2020-03-03 => 9);
-        assert_monday_week!(2020-03-04 => 9);
-        assert_monday_week!(2020-03-05 => 9);
-        assert_monday_week!(2020-03-06 => 9);
-        assert_monday_week!(2020-03-07 => 9);
+    #[test]
+    fn weekday_consecutive_years() {
+        let mut expected = ymd!(-2000, 1, 1).weekday();
+        for year in -2000..=9999 {
+            assert_eq!(Date::from_ymd(year, 1, 1).weekday(), expected, "{}", year);
+            assert_eq!(
+                Date::from_ymd(year, 1, 1).next_day().weekday(),
+                expected.next(),
+                "{}",
+                year
+            );
+            for _ in 0..super::days_in_year(year) {
+                expected = expected.next();
+            }
+        }
+    }
 
-        // FE
-        assert_monday_week!(2036-01-01 => 0);
-        assert_monday_week!(2036-01-02 => 0);
-        assert_monday_week!(2036-01-03 => 0);
-        assert_monday_week!(2036-01-04 => 0);
-        assert_monday_week!(2036-01-05 => 0);
-        assert_monday_week!(2036-01-06 => 0);
-        assert_monday_week!(2036-01-07 => 1);
-        assert_monday_week!(2036-02-28 => 8);
-        assert_monday_week!(2036-02-29 => 8);
-        assert_monday_week!(2036-03-01 => 8);
-        assert_monday_week!(2036-03-02 => 8);
-        assert_monday_week!(2036-03-03 => 9);
-        assert_monday_week!(2036-03-04 => 9);
-        assert_monday_week!(2036-03-05 => 9);
-        assert_monday_week!(2036-03-06 => 9);
-        assert_monday_week!(2036-03-07 => 9);
+    #[test]
+    fn weekday_period() {
+        // The proleptic Gregorian calendar repeats every 400 years, which is
+        // exactly 20,871 weeks.
+        for &(month, day) in &[(1, 1), (2, 28), (2, 29), (3, 1), (12, 31)] {
+            for year in (-2000..=-1601).filter(|&year| days_in_year_month(year, month) >= day) {
+                let weekday = Date::from_ymd(year, month, day).weekday();
+                for cycle in 1..=29 {
+                    assert_eq!(
+                        Date::from_ymd(year + 400 * cycle, month, day).weekday(),
+                        weekday
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sunday_based_week() {
+        assert_eq!(ymd!(2019, 1, 1).sunday_based_week(), 0);
+        assert_eq!(ymd!(2020, 1, 1).sunday_based_week(), 0);
+        assert_eq!(ymd!(2020, 12, 31).sunday_based_week(), 52);
+        assert_eq!(ymd!(2021, 1, 1).sunday_based_week(), 0);
+
+        // The last day of the year
+        assert_eq!(ymd!(2000, 12, 31).sunday_based_week(), 53);
+        assert_eq!(ymd!(2012, 12, 31).sunday_based_week(), 53);
+        assert_eq!(ymd!(2017, 12, 31).sunday_based_week(), 53);
+        assert_eq!(ymd!(2018, 12, 31).sunday_based_week(), 52);
+        assert_eq!(ymd!(2019, 12, 31).sunday_based_week(), 52);
+        assert_eq!(ymd!(2023, 12, 31).sunday_based_week(), 53);
+        assert_eq!(ymd!(2024, 12, 31).sunday_based_week(), 52);
+        assert_eq!(ymd!(2040, 12, 31).sunday_based_week(), 53);
+    }
+
+    #[test]
+    fn monday_based_week() {
+        assert_eq!(ymd!(2019, 1, 1).monday_based_week(), 0);
+        assert_eq!(ymd!(2020, 1, 1).monday_based_week(), 0);
+        assert_eq!(ymd!(2020, 12, 31).monday_based_week(), 52);
+        assert_eq!(ymd!(2021, 1, 1).monday_based_week(), 0);
+
+        // The last day of the year
+        assert_eq!(ymd!(2000, 12, 31).monday_based_week(), 52);
+        assert_eq!(ymd!(2012, 12, 31).monday_based_week(), 53);
+        assert_eq!(ymd!(2017, 12, 31).monday_based_week(), 52);
+        assert_eq!(ymd!(2018, 12, 31).monday_based_week(), 53);
+        assert_eq!(ymd!(2019, 12, 31).monday_based_week(), 52);
+        assert_eq!(ymd!(2023, 12, 31).monday_based_week(), 52);
+        assert_eq!(ymd!(2024, 12, 31).monday_based_week(), 53);
+        assert_eq!(ymd!(2040, 12, 31).monday_based_week(), 53);
+    }
+
+    #[test]
+    fn sunday_monday_based_week_exhaustive() {
+        // The week number is the number of Sundays (or Mondays) in the year on
+        // or before the date.
+        for year in (-1_000..=-900).chain(1_900..=2_100) {
+            let (mut sundays, mut mondays) = (0, 0);
+            for ordinal in 1..=super::days_in_year(year) {
+                let date = Date::from_yo(year, ordinal);
+                match date.weekday() {
+                    Sunday => sundays += 1,
+                    Monday => mondays += 1,
+                    _ => {}
+                }
+                assert_eq!(date.sunday_based_week(), sundays);
+                assert_eq!(date.monday_based_week(), mondays);
+                assert!(sundays <= 53 && mondays <= 53);
+            }
+        }
+    }
+
+    #[test]
+    fn as_ymd() {
+        assert_eq!(ymd!(2019, 1, 2).as_ymd(), (2019, 1, 2));
+    }
+
+    #[test]
+    fn as_wo() {
+        assert_eq!(ymd!(2019, 1, 1).as_yo(), (2019, 1));
+    }
+
+    #[test]
+    fn next_day() {
+        assert_eq!(ymd!(2019, 1, 1).next_day(), ymd!(2019, 1, 2));
+        assert_eq!(ymd!(2019, 1, 31).next_day(), ymd!(2019, 2, 1));
+        assert_eq!(ymd!(2019, 12, 31).next_day(), ymd!(2020, 1, 1));
+    }
+
+    #[test]
+    fn previous_day() {
+        assert_eq!(ymd!(2019, 1, 2).previous_day(), ymd!(2019, 1, 1));
+        assert_eq!(ymd!(2019, 2, 1).previous_day(), ymd!(2019, 1, 31));
+        assert_eq!(ymd!(2020, 1, 1).previous_day(), ymd!(2019, 12, 31));
+    }
+
+    #[test]
+    fn checked_next_day() {
+        assert_eq!(ymd!(2019, 1, 1).checked_next_day(), Some(ymd!(2019, 1, 2)));
+        assert_eq!(ymd!(2019, 1, 31).checked_next_day(), Some(ymd!(2019, 2, 1)));
+        assert_eq!(
+            ymd!(2019, 12, 31).checked_next_day(),
+            Some(ymd!(2020, 1, 1))
+        );
+        assert_eq!(
+            ymd!(2020, 12, 30).checked_next_day(),
+            Some(ymd!(2020, 12, 31))
+        );
+
+        let max_year = i32::max_value();
+        assert_eq!(
+            Date::from_yo(max_year, super::days_in_year(max_year)).checked_next_day(),
+            None
+        );
+        assert_eq!(
+            Date::from_yo(max_year, 1).checked_next_day(),
+            Some(Date::from_yo(max_year, 2))
+        );
+    }
+
+    #[test]
+    fn next_month() {
+        assert_eq!(ymd!(2019, 1, 1).next_month(), ymd!(2019, 2, 1));
+        assert_eq!(ymd!(2019, 1, 28).next_month(), ymd!(2019, 2, 28));
+        assert_eq!(ymd!(2019, 1, 29).next_month(), ymd!(2019, 2, 28));
+        assert_eq!(ymd!(2019, 1, 31).next_month(), ymd!(2019, 2, 28));
+        assert_eq!(ymd!(2020, 1, 31).next_month(), ymd!(2020, 2, 29));
+        assert_eq!(ymd!(2100, 1, 31).next_month(), ymd!(2100, 2, 28));
+        assert_eq!(ymd!(2019, 3, 31).next_month(), ymd!(2019, 4, 30));
+        assert_eq!(ymd!(2019, 4, 30).next_month(), ymd!(2019, 5, 30));
+        assert_eq!(ymd!(2019, 12, 31).next_month(), ymd!(2020, 1, 31));
+        assert_eq!(ymd!(-1, 12, 15).next_month(), ymd!(0, 1, 15));
+    }
+
+    #[test]
+    fn previous_month() {
+        assert_eq!(ymd!(2019, 2, 1).previous_month(), ymd!(2019, 1, 1));
+        assert_eq!(ymd!(2019, 3, 28).previous_month(), ymd!(2019, 2, 28));
+        assert_eq!(ymd!(2019, 3, 29).previous_month(), ymd!(2019, 2, 28));
+        assert_eq!(ymd!(2019, 3, 31).previous_month(), ymd!(2019, 2, 28));
+        assert_eq!(ymd!(2020, 3, 31).previous_month(), ymd!(2020, 2, 29));
+        assert_eq!(ymd!(2019, 5, 31).previous_month(), ymd!(2019, 4, 30));
+        assert_eq!(ymd!(2019, 4, 30).previous_month(), ymd!(2019, 3, 30));
+        assert_eq!(ymd!(2020, 1, 31).previous_month(), ymd!(2019, 12, 31));
+        assert_eq!(ymd!(0, 1, 15).previous_month(), ymd!(-1, 12, 15));
+    }
 
-        // GF
-        assert_monday_week!(2024-01-01 => 1);
-        assert_monday_week!(2024-01-02 => 1);
-        assert_monday_week!(2024-01-03 => 1);
-        assert_monday_week!(2024-01-04 => 1);
-        assert_monday_week!(2024-01-05 => 1);
-        assert_monday_week!(2024-01-06 => 1);
-        assert_monday_week!(2024-01-07 => 1);
-        assert_monday_week!(2024-02-28 => 9);
-        assert_monday_week!(2024-02-29 => 9);
-        assert_monday_week!(2024-03-01 => 9);
-        assert_monday_week!(2024-03-02 => 9);
-        assert_monday_week!(2024-03-03 => 9);
-        assert_monday_week!(2024-03-04 => 10);
-        assert_monday_week!(2024-03-05 => 10);
-        assert_monday_week!(2024-03-06 => 10);
-        assert_monday_week!(2024-03-07 => 10);
+    #[test]
+    fn checked_next_month() {
+        assert_eq!(
+            ymd!(2019, 1, 31).checked_next_month(),
+            Some(ymd!(2019, 2, 28))
+        );
+        assert_eq!(
+            ymd!(2019, 12, 31).checked_next_month(),
+            Some(ymd!(2020, 1, 31))
+        );
+
+        let max_year = i32::max_value();
+        assert_eq!(
+            Date::from_ymd(max_year, 11, 30).checked_next_month(),
+            Some(Date::from_ymd(max_year, 12, 30))
+        );
+        assert_eq!(Date::from_ymd(max_year, 12, 1).checked_next_month(), None);
     }
 
     #[test]
-    #[allow(clippy::zero_prefixed_literal)]
-    fn test_sunday_based_week() {
-        macro_rules! assert_sunday_week {
-            ($y:literal - $m:literal - $d:literal => $week:literal) => {
-                assert_eq!(Date::from_ymd($y, $m, $d).sunday_based_week(), $week);
-            };
-        }
+    #[cfg(feature = "std")]
+    fn next_month_overflow() {
+        assert_panics!(Date::from_ymd(i32::max_value(), 12, 1).next_month());
+    }
 
-        // A
-        assert_sunday_week!(2023-01-01 => 1);
-        assert_sunday_week!(2023-01-02 => 1);
-        assert_sunday_week!(2023-01-03 => 1);
-        assert_sunday_week!(2023-01-04 => 1);
-        assert_sunday_week!(2023-01-05 => 1);
-        assert_sunday_week!(2023-01-06 => 1);
-        assert_sunday_week!(2023-01-07 => 1);
+    #[test]
+    fn checked_previous_month() {
+        assert_eq!(
+            ymd!(2020, 3, 31).checked_previous_month(),
+            Some(ymd!(2020, 2, 29))
+        );
+        assert_eq!(
+            ymd!(2020, 1, 31).checked_previous_month(),
+            Some(ymd!(2019, 12, 31))
+        );
 
-        // B
-        assert_sunday_week!(2022-01-01 => 0);
-        assert_sunday_week!(2022-01-02 => 1);
-        assert_sunday_week!(2022-01-03 => 1);
-        assert_sunday_week!(2022-01-04 => 1);
-        assert_sunday_week!(2022-01-05 => 1);
-        assert_sunday_week!(2022-01-06 => 1);
-        assert_sunday_week!(2022-01-07 => 1);
+        let min_year = i32::min_value();
+        assert_eq!(
+            Date::from_ymd(min_year, 2, 1).checked_previous_month(),
+            Some(Date::from_ymd(min_year, 1, 1))
+        );
+        assert_eq!(
+            Date::from_ymd(min_year, 1, 31).checked_previous_month(),
+            None
+        );
+    }
 
-        // C
-        assert_sunday_week!(2021-01-01 => 0);
-        assert_sunday_week!(2021-01-02 => 0);
-        assert_sunday_week!(2021-01-03 => 1);
-        assert_sunday_week!(2021-01-04 => 1);
-        assert_sunday_week!(2021-01-05 => 1);
-        assert_sunday_week!(2021-01-06 => 1);
-        assert_sunday_week!(2021-01-07 => 1);
+    #[test]
+    #[cfg(feature = "std")]
+    fn previous_month_overflow() {
+        assert_panics!(Date::from_ymd(i32::min_value(), 1, 31).previous_month());
+    }
 
-        // D
-        assert_sunday_week!(2026-01-01 => 0);
-        assert_sunday_week!(2026-01-02 => 0);
-        assert_sunday_week!(2026-01-03 => 0);
-        assert_sunday_week!(2026-01-04 => 1);
-        assert_sunday_week!(2026-01-05 => 1);
-        assert_sunday_week!(2026-01-06 => 1);
-        assert_sunday_week!(2026-01-07 => 1);
+    #[test]
+    fn start_of_year() {
+        assert_eq!(ymd!(2019, 7, 4).start_of_year(), ymd!(2019, 1, 1));
+        assert_eq!(ymd!(2020, 1, 1).start_of_year(), ymd!(2020, 1, 1));
+        assert_eq!(ymd!(2020, 12, 31).start_of_year(), ymd!(2020, 1, 1));
+    }
 
-        // E
-        assert_sunday_week!(2025-01-01 => 0);
-        assert_sunday_week!(2025-01-02 => 0);
-        assert_sunday_week!(2025-01-03 => 0);
-        assert_sunday_week!(2025-01-04 => 0);
-        assert_sunday_week!(2025-01-05 => 1);
-        assert_sunday_week!(2025-01-06 => 1);
-        assert_sunday_week!(2025-01-07 => 1);
+    #[test]
+    fn end_of_year() {
+        assert_eq!(ymd!(2019, 7, 4).end_of_year(), ymd!(2019, 12, 31));
+        assert_eq!(ymd!(2020, 7, 4).end_of_year(), ymd!(2020, 12, 31));
+        assert_eq!(ymd!(2020, 7, 4).end_of_year().ordinal(), 366);
+        assert_eq!(ymd!(2020, 12, 31).end_of_year(), ymd!(2020, 12, 31));
+    }
 
-        // F
-        assert_sunday_week!(2019-01-01 => 0);
-        assert_sunday_week!(2019-01-02 => 0);
-        assert_sunday_week!(2019-01-03 => 0);
-        assert_sunday_week!(2019-01-04 => 0);
-        assert_sunday_week!(2019-01-05 => 0);
-        assert_sunday_week!(2019-01-06 => 1);
-        assert_sunday_week!(2019-01-07 => 1);
+    #[test]
+    fn months_between() {
+        assert_eq!(ymd!(2019, 1, 1).months_between(ymd!(2019, 1, 1)), 0);
+        assert_eq!(ymd!(2019, 1, 1).months_between(ymd!(2019, 1, 31)), 0);
+        assert_eq!(ymd!(2019, 1, 15).months_between(ymd!(2019, 2, 14)), 0);
+        assert_eq!(ymd!(2019, 1, 15).months_between(ymd!(2019, 2, 15)), 1);
+        assert_eq!(ymd!(2019, 1, 15).months_between(ymd!(2019, 12, 31)), 11);
+
+        // The end of a shorter month counts as a whole month.
+        assert_eq!(ymd!(2019, 1, 31).months_between(ymd!(2019, 2, 27)), 0);
+        assert_eq!(ymd!(2019, 1, 31).months_between(ymd!(2019, 2, 28)), 1);
+        assert_eq!(ymd!(2020, 1, 31).months_between(ymd!(2020, 2, 28)), 0);
+        assert_eq!(ymd!(2020, 1, 31).months_between(ymd!(2020, 2, 29)), 1);
+        assert_eq!(ymd!(2019, 1, 31).months_between(ymd!(2019, 3, 30)), 1);
+        assert_eq!(ymd!(2019, 1, 31).months_between(ymd!(2019, 3, 31)), 2);
+        assert_eq!(ymd!(2019, 3, 31).months_between(ymd!(2019, 4, 30)), 1);
+        assert_eq!(ymd!(2019, 2, 28).months_between(ymd!(2019, 3, 28)), 1);
+
+        // Multiple years.
+        assert_eq!(ymd!(2019, 6, 15).months_between(ymd!(2021, 6, 15)), 24);
+        assert_eq!(ymd!(2019, 6, 15).months_between(ymd!(2021, 6, 14)), 23);
+        assert_eq!(ymd!(2019, 12, 31).months_between(ymd!(2020, 1, 1)), 0);
+        assert_eq!(ymd!(2016, 2, 29).months_between(ymd!(2020, 2, 29)), 48);
+        assert_eq!(ymd!(-1, 12, 1).months_between(ymd!(1, 1, 1)), 13);
+
+        // The result is negated when the other date is earlier.
+        assert_eq!(ymd!(2019, 3, 15).months_between(ymd!(2019, 1, 15)), -2);
+        assert_eq!(ymd!(2019, 2, 28).months_between(ymd!(2019, 1, 31)), -1);
+        assert_eq!(ymd!(2021, 6, 14).months_between(ymd!(2019, 6, 15)), -23);
+
+        let min = Date::from_yo(i32::min_value(), 1);
+        let max = Date::from_yo(i32::max_value(), 365);
+        assert_eq!(min.months_between(max), 51_539_607_551);
+        assert_eq!(max.months_between(min), -51_539_607_551);
+    }
 
-        // G
-        assert_sunday_week!(2018-01-01 => 0);
-        assert_sunday_week!(2018-01-02 => 0);
-        assert_sunday_week!(2018-01-03 => 0);
-        assert_sunday_week!(2018-01-04 => 0);
-        assert_sunday_week!(2018-01-05 => 0);
-        assert_sunday_week!(2018-01-06 => 0);
-        assert_sunday_week!(2018-01-07 => 1);
+    #[test]
+    fn years_between() {
+        assert_eq!(ymd!(2019, 1, 1).years_between(ymd!(2019, 12, 31)), 0);
+        assert_eq!(ymd!(2019, 1, 1).years_between(ymd!(2020, 1, 1)), 1);
+        assert_eq!(ymd!(2000, 6, 15).years_between(ymd!(2019, 6, 15)), 19);
+        assert_eq!(ymd!(2000, 6, 15).years_between(ymd!(2019, 6, 14)), 18);
+        assert_eq!(ymd!(2019, 6, 15).years_between(ymd!(2000, 6, 15)), -19);
+        assert_eq!(ymd!(2019, 6, 14).years_between(ymd!(2000, 6, 15)), -18);
+
+        // The anniversary of February 29 is February 28 in a common year.
+        assert_eq!(ymd!(2020, 2, 29).years_between(ymd!(2021, 2, 27)), 0);
+        assert_eq!(ymd!(2020, 2, 29).years_between(ymd!(2021, 2, 28)), 1);
+        assert_eq!(ymd!(2020, 2, 29).years_between(ymd!(2024, 2, 28)), 3);
+        assert_eq!(ymd!(2020, 2, 29).years_between(ymd!(2024, 2, 29)), 4);
+        assert_eq!(ymd!(2019, 2, 28).years_between(ymd!(2020, 2, 28)), 1);
+        assert_eq!(ymd!(2021, 2, 28).years_between(ymd!(2020, 2, 29)), -1);
+
+        assert_eq!(ymd!(-1, 1, 1).years_between(ymd!(1, 1, 1)), 2);
+        assert_eq!(
+            Date::from_yo(i32::min_value(), 1).years_between(Date::from_yo(i32::max_value(), 365)),
+            u32::max_value() as i64
+        );
+    }
 
-        // AG
-        assert_sunday_week!(2012-01-01 => 1);
-        assert_sunday_week!(2012-01-02 => 1);
-        assert_sunday_week!(2012-01-03 => 1);
-        assert_sunday_week!(2012-01-04 => 1);
-        assert_sunday_week!(2012-01-05 => 1);
-        assert_sunday_week!(2012-01-06 => 1);
-        assert_sunday_week!(2012-01-07 => 1);
-        assert_sunday_week!(2012-02-28 => 9);
-        assert_sunday_week!(2012-02-29 => 9);
-        assert_sunday_week!(2012-03-01 => 9);
-        assert_sunday_week!(2012-03-02 => 9);
-        assert_sunday_week!(2012-03-03 => 9);
-        assert_sunday_week!(2012-03-04 => 10);
-        assert_sunday_week!(2012-03-05 => 10);
-        assert_sunday_week!(2012-03-06 => 10);
-        assert_sunday_week!(2012-03-07 => 10);
+    #[test]
+    fn start_of_month() {
+        assert_eq!(ymd!(2019, 1, 1).start_of_month(), ymd!(2019, 1, 1));
+        assert_eq!(ymd!(2019, 1, 31).start_of_month(), ymd!(2019, 1, 1));
+        assert_eq!(ymd!(2019, 3, 15).start_of_month(), ymd!(2019, 3, 1));
+        assert_eq!(ymd!(2020, 3, 15).start_of_month(), ymd!(2020, 3, 1));
+        assert_eq!(ymd!(2020, 2, 29).start_of_month(), ymd!(2020, 2, 1));
+        assert_eq!(ymd!(2019, 12, 31).start_of_month(), ymd!(2019, 12, 1));
+    }
 
-        // BA
-        assert_sunday_week!(2028-01-01 => 0);
-        assert_sunday_week!(2028-01-02 => 1);
-        assert_sunday_week!(2028-01-03 => 1);
-        assert_sunday_week!(2028-01-04 => 1);
-        assert_sunday_week!(2028-01-05 => 1);
-        assert_sunday_week!(2028-01-06 => 1);
-        assert_sunday_week!(2028-01-07 => 1);
-        assert_sunday_week!(2028-02-28 => 9);
-        assert_sunday_week!(2028-02-29 => 9);
-        assert_sunday_week!(2028-03-01 => 9);
-        assert_sunday_week!(2028-03-02 => 9);
-        assert_sunday_week!(2028-03-03 => 9);
-        assert_sunday_week!(2028-03-04 => 9);
-        assert_sunday_week!(2028-03-05 => 10);
-        assert_sunday_week!(2028-03-06 => 10);
-        assert_sunday_week!(2028-03-07 => 10);
+    #[test]
+    fn end_of_month() {
+        assert_eq!(ymd!(2019, 1, 1).end_of_month(), ymd!(2019, 1, 31));
+        assert_eq!(ymd!(2019, 2, 1).end_of_month(), ymd!(2019, 2, 28));
+        assert_eq!(ymd!(2020, 2, 1).end_of_month(), ymd!(2020, 2, 29));
+        assert_eq!(ymd!(1900, 2, 1).end_of_month(), ymd!(1900, 2, 28));
+        assert_eq!(ymd!(2000, 2, 1).end_of_month(), ymd!(2000, 2, 29));
+        assert_eq!(ymd!(2019, 4, 30).end_of_month(), ymd!(2019, 4, 30));
+        assert_eq!(ymd!(2019, 12, 25).end_of_month(), ymd!(2019, 12, 31));
+
+        for date in ymd!(2020, 1, 1).range(ymd!(2021, 1, 1)) {
+            assert_eq!(date.end_of_month().day(), date.days_in_month());
+            assert_eq!(date.end_of_month().month(), date.month());
+            assert_eq!(date.start_of_month().month(), date.month());
+        }
+    }
 
-        // CB
-        assert_sunday_week!(2016-01-01 => 0);
-        assert_sunday_week!(2016-01-02 => 0);
-        assert_sunday_week!(2016-01-03 => 1);
-        assert_sunday_week!(2016-01-04 => 1);
-        assert_sunday_week!(2016-01-05 => 1);
-        assert_sunday_week!(2016-01-06 => 1);
-        assert_sunday_week!(2016-01-07 => 1);
-        assert_sunday_week!(2016-02-28 => 9);
-        assert_sunday_week!(2016-02-29 => 9);
-        assert_sunday_week!(2016-03-01 => 9);
-        assert_sunday_week!(2016-03-02 => 9);
-        assert_sunday_week!(2016-03-03 => 9);
-        assert_sunday_week!(2016-03-04 => 9);
-        assert_sunday_week!(2016-03-05 => 9);
-        assert_sunday_week!(2016-03-06 => 10);
-        assert_sunday_week!(2016-03-07 => 10);
+    #[test]
+    fn quarter() {
+        assert_eq!(ymd!(2019, 1, 1).quarter(), 1);
+        assert_eq!(ymd!(2019, 3, 31).quarter(), 1);
+        assert_eq!(ymd!(2019, 4, 1).quarter(), 2);
+        assert_eq!(ymd!(2019, 6, 30).quarter(), 2);
+        assert_eq!(ymd!(2019, 7, 1).quarter(), 3);
+        assert_eq!(ymd!(2019, 9, 30).quarter(), 3);
+        assert_eq!(ymd!(2019, 10, 1).quarter(), 4);
+        assert_eq!(ymd!(2019, 12, 31).quarter(), 4);
+        assert_eq!(ymd!(2020, 2, 29).quarter(), 1);
+
+        for date in ymd!(2020, 1, 1).range(ymd!(2021, 1, 1)) {
+            assert_eq!(date.start_of_quarter().quarter(), date.quarter());
+            assert_eq!(date.end_of_quarter().quarter(), date.quarter());
+            assert_eq!(date.quarter().to_string(), date.format("%q"));
+        }
+    }
 
-        // DC
-        assert_sunday_week!(2032-01-01 => 0);
-        assert_sunday_week!(2032-01-02 => 0);
-        assert_sunday_week!(2032-01-03 => 0);
-        assert_sunday_week!(2032-01-04 => 1);
-        assert_sunday_week!(2032-01-05 => 1);
-        assert_sunday_week!(2032-01-06 => 1);
-        assert_sunday_week!(2032-01-07 => 1);
-        assert_sunday_week!(2032-02-28 => 8);
-        assert_sunday_week!(2032-02-29 => 9);
-        assert_sunday_week!(2032-03-01 => 9);
-        assert_sunday_week!(2032-03-02 => 9);
-        assert_sunday_week!(2032-03-03 => 9);
-        assert_sunday_week!(2032-03-04 => 9);
-        assert_sunday_week!(2032-03-05 => 9);
-        assert_sunday_week!(2032-03-06 => 9);
-        assert_sunday_week!(2032-03-07 => 10);
+    #[test]
+    fn start_of_quarter() {
+        assert_eq!(ymd!(2019, 1, 1).start_of_quarter(), ymd!(2019, 1, 1));
+        assert_eq!(ymd!(2019, 2, 15).start_of_quarter(), ymd!(2019, 1, 1));
+        assert_eq!(ymd!(2019, 3, 31).start_of_quarter(), ymd!(2019, 1, 1));
+        assert_eq!(ymd!(2019, 4, 1).start_of_quarter(), ymd!(2019, 4, 1));
+        assert_eq!(ymd!(2019, 6, 30).start_of_quarter(), ymd!(2019, 4, 1));
+        assert_eq!(ymd!(2019, 8, 15).start_of_quarter(), ymd!(2019, 7, 1));
+        assert_eq!(ymd!(2019, 10, 1).start_of_quarter(), ymd!(2019, 10, 1));
+        assert_eq!(ymd!(2019, 12, 31).start_of_quarter(), ymd!(2019, 10, 1));
+        assert_eq!(ymd!(2020, 2, 29).start_of_quarter(), ymd!(2020, 1, 1));
+    }
 
-        // ED
-        assert_sunday_week!(2020-01-01 => 0);
-        assert_sunday_week!(2020-01-02 => 0);
-        assert_sunday_week!(2020-01-03 => 0);
-        assert_sunday_week!(2020-01-04 => 0);
-        assert_sunday_week!(2020-01-05 => 1);
-        assert_sunday_week!(2020-01-06 => 1);
-        assert_sunday_week!(2020-01-07 => 1);
-        assert_sunday_week!(2020-02-28 => 8);
-        assert_sunday_week!(2020-02-29 => 8);
-        assert_sunday_week!(2020-03-01 => 9);
-        assert_sunday_week!(2020-03-02 => 9);
-        assert_sunday_week!(2020-03-03 => 9);
-        assert_sunday_week!(2020-03-04 => 9);
-        assert_sunday_week!(2020-03-05 => 9);
-        assert_sunday_week!(2020-03-06 => 9);
-        assert_sunday_week!(2020-03-07 => 9);
+    #[test]
+    fn end_of_quarter() {
+        assert_eq!(ymd!(2019, 1, 1).end_of_quarter(), ymd!(2019, 3, 31));
+        assert_eq!(ymd!(2019, 2, 28).end_of_quarter(), ymd!(2019, 3, 31));
+        assert_eq!(ymd!(2020, 2, 29).end_of_quarter(), ymd!(2020, 3, 31));
+        assert_eq!(ymd!(2020, 2, 29).end_of_quarter().ordinal(), 91);
+        assert_eq!(ymd!(2019, 2, 28).end_of_quarter().ordinal(), 90);
+        assert_eq!(ymd!(2019, 4, 1).end_of_quarter(), ymd!(2019, 6, 30));
+        assert_eq!(ymd!(2019, 7, 1).end_of_quarter(), ymd!(2019, 9, 30));
+        assert_eq!(ymd!(2019, 9, 30).end_of_quarter(), ymd!(2019, 9, 30));
+        assert_eq!(ymd!(2019, 10, 1).end_of_quarter(), ymd!(2019, 12, 31));
+        assert_eq!(ymd!(2019, 12, 31).end_of_quarter(), ymd!(2019, 12, 31));
+    }
 
-        // FE
-        assert_sunday_week!(2036-01-01 => 0);
-        assert_sunday_week!(2036-01-02 => 0);
-        assert_sunday_week!(2036-01-03 => 0);
-        assert_sunday_week!(2036-01-04 => 0);
-        assert_sunday_week!(2036-01-05 => 0);
-        assert_sunday_week!(2036-01-06 => 1);
-        assert_sunday_week!(2036-01-07 => 1);
-        assert_sunday_week!(2036-02-28 => 8);
-        assert_sunday_week!(2036-02-29 => 8);
-        assert_sunday_week!(2036-03-01 => 8);
-        assert_sunday_week!(2036-03-02 => 9);
-        assert_sunday_week!(2036-03-03 => 9);
-        assert_sunday_week!(2036-03-04 => 9);
-        assert_sunday_week!(2036-03-05 => 9);
-        assert_sunday_week!(2036-03-06 => 9);
-        assert_sunday_week!(2036-03-07 => 9);
+    #[test]
+    fn start_of_week() {
+        // 2019-07-04 is a Thursday.
+        let date = Date::from_ymd(2019, 7, 4);
+        assert_eq!(date.start_of_week(Monday), Date::from_ymd(2019, 7, 1));
+        assert_eq!(date.start_of_week(Tuesday), Date::from_ymd(2019, 7, 2));
+        assert_eq!(date.start_of_week(Wednesday), Date::from_ymd(2019, 7, 3));
+        assert_eq!(date.start_of_week(Thursday), date);
+        assert_eq!(date.start_of_week(Friday), Date::from_ymd(2019, 6, 28));
+        assert_eq!(date.start_of_week(Saturday), Date::from_ymd(2019, 6, 29));
+        assert_eq!(date.start_of_week(Sunday), Date::from_ymd(2019, 6, 30));
+
+        // Crossing a year boundary.
+        assert_eq!(
+            Date::from_ymd(2020, 1, 1).start_of_week(Monday),
+            Date::from_ymd(2019, 12, 30)
+        );
+    }
 
-        // GF
-        assert_sunday_week!(2024-01-01 => 0);
-        assert_sunday_week!(2024-01-02 => 0);
-        assert_sunday_week!(2024-01-03 => 0);
-        assert_sunday_week!(2024-01-04 => 0);
-        assert_sunday_week!(2024-01-05 => 0);
-        assert_sunday_week!(2024-01-06 => 0);
-        assert_sunday_week!(2024-01-07 => 1);
-        assert_sunday_week!(2024-02-28 => 8);
-        assert_sunday_week!(2024-02-29 => 8);
-        assert_sunday_week!(2024-03-01 => 8);
-        assert_sunday_week!(2024-03-02 => 8);
-        assert_sunday_week!(2024-03-03 => 9);
-        assert_sunday_week!(2024-03-04 => 9);
-        assert_sunday_week!(2024-03-05 => 9);
-        assert_sunday_week!(2024-03-06 => 9);
-        assert_sunday_week!(2024-03-07 => 9);
+    #[test]
+    fn end_of_week() {
+        let date = Date::from_ymd(2019, 7, 4);
+        assert_eq!(date.end_of_week(Monday), Date::from_ymd(2019, 7, 7));
+        assert_eq!(date.end_of_week(Friday), date);
+        assert_eq!(date.end_of_week(Saturday), Date::from_ymd(2019, 7, 5));
+        assert_eq!(date.end_of_week(Sunday), Date::from_ymd(2019, 7, 6));
+
+        assert_eq!(
+            Date::from_ymd(2019, 12, 30).end_of_week(Monday),
+            Date::from_ymd(2020, 1, 5)
+        );
+    }
+
+    #[test]
+    fn week_bounds_contain_date() {
+        for &first_day in &[Monday, Wednesday, Sunday] {
+            let mut date = Date::from_ymd(2020, 2, 20);
+            while date < Date::from_ymd(2020, 3, 10) {
+                let start = date.start_of_week(first_day);
+                let end = date.end_of_week(first_day);
+                assert_eq!(start.weekday(), first_day);
+                assert_eq!(end.weekday(), first_day.previous());
+                assert!(start <= date && date <= end);
+                assert_eq!(end - start, Duration::days(6));
+                date = date.next_day();
+            }
+        }
     }
 
     #[test]
-    #[allow(clippy::zero_prefixed_literal)]
-    fn test_parse_monday_based_week() {
-        macro_rules! assert_dwy {
-            ($weekday:ident $week:literal $year:literal => $ordinal:literal) => {
-                assert_eq!(
-                    Date::parse(
-                        concat!(
-                            stringify!($weekday),
-                            " ",
-                            stringify!($week),
-                            " ",
-                            stringify!($year)
-                        ),
-                        "%a %W %Y"
-                    ),
-                    Ok(Date::from_yo($year, $ordinal))
-                );
-            };
-        }
+    fn week_of_month() {
+        // 2019-06-01 is a Saturday.
+        assert_eq!(Date::from_ymd(2019, 6, 1).week_of_month(Monday), 1);
+        assert_eq!(Date::from_ymd(2019, 6, 2).week_of_month(Monday), 1);
+        assert_eq!(Date::from_ymd(2019, 6, 3).week_of_month(Monday), 2);
+        assert_eq!(Date::from_ymd(2019, 6, 2).week_of_month(Sunday), 2);
+        assert_eq!(Date::from_ymd(2019, 6, 1).week_of_month(Saturday), 1);
+        assert_eq!(Date::from_ymd(2019, 6, 8).week_of_month(Saturday), 2);
+        assert_eq!(Date::from_ymd(2019, 6, 30).week_of_month(Monday), 5);
+        assert_eq!(Date::from_ymd(2019, 6, 30).week_of_month(Sunday), 6);
+
+        // 2021-02-01 is a Monday, so February fits in four weeks.
+        assert_eq!(Date::from_ymd(2021, 2, 1).week_of_month(Monday), 1);
+        assert_eq!(Date::from_ymd(2021, 2, 28).week_of_month(Monday), 4);
+    }
 
-        // A
-        assert_dwy!(Sun 00 2023 => 001);
-        assert_dwy!(Mon 01 2023 => 002);
-        assert_dwy!(Tue 01 2023 => 003);
-        assert_dwy!(Wed 01 2023 => 004);
-        assert_dwy!(Thu 01 2023 => 005);
-        assert_dwy!(Fri 01 2023 => 006);
-        assert_dwy!(Sat 01 2023 => 007);
+    #[test]
+    fn checked_previous_day() {
+        assert_eq!(
+            ymd!(2019, 1, 2).checked_previous_day(),
+            Some(ymd!(2019, 1, 1))
+        );
+        assert_eq!(
+            ymd!(2019, 2, 1).checked_previous_day(),
+            Some(ymd!(2019, 1, 31))
+        );
+        assert_eq!(
+            ymd!(2020, 1, 1).checked_previous_day(),
+            Some(ymd!(2019, 12, 31))
+        );
+        assert_eq!(
+            ymd!(2021, 1, 1).checked_previous_day(),
+            Some(ymd!(2020, 12, 31))
+        );
 
-        // B
-        assert_dwy!(Sat 00 2022 => 001);
-        assert_dwy!(Sun 00 2022 => 002);
-        assert_dwy!(Mon 01 2022 => 003);
-        assert_dwy!(Tue 01 2022 => 004);
-        assert_dwy!(Wed 01 2022 => 005);
-        assert_dwy!(Thu 01 2022 => 006);
-        assert_dwy!(Fri 01 2022 => 007);
+        let min_year = i32::min_value();
+        assert_eq!(Date::from_yo(min_year, 1).checked_previous_day(), None);
+        assert_eq!(
+            Date::from_yo(min_year, 2).checked_previous_day(),
+            Some(Date::from_yo(min_year, 1))
+        );
+    }
 
-        // C
-        assert_dwy!(Fri 00 2021 => 001);
-        assert_dwy!(Sat 00 2021 => 002);
-        assert_dwy!(Sun 00 2021 => 003);
-        assert_dwy!(Mon 01 2021 => 004);
-        assert_dwy!(Tue 01 2021 => 005);
-        assert_dwy!(Wed 01 2021 => 006);
-        assert_dwy!(Thu 01 2021 => 007);
+    #[test]
+    fn checked_add() {
+        assert_eq!(
+            ymd!(2019, 1, 1).checked_add(0.days()),
+            Some(ymd!(2019, 1, 1))
+        );
+        assert_eq!(
+            ymd!(2019, 1, 1).checked_add(5.days()),
+            Some(ymd!(2019, 1, 6))
+        );
+        assert_eq!(
+            ymd!(2019, 1, 1).checked_add(23.hours()),
+            Some(ymd!(2019, 1, 1))
+        );
+        assert_eq!(
+            ymd!(2019, 12, 31).checked_add(1.days()),
+            Some(ymd!(2020, 1, 1))
+        );
+        assert_eq!(
+            ymd!(2020, 1, 1).checked_add((-1).days()),
+            Some(ymd!(2019, 12, 31))
+        );
+        assert_eq!(
+            ymd!(2019, 1, 1).checked_add(Duration::seconds(i64::max_value())),
+            None
+        );
+        assert_eq!(
+            ymd!(2019, 1, 1).checked_add(Duration::seconds(-i64::max_value())),
+            None
+        );
 
-        // D
-        assert_dwy!(Thu 00 2026 => 001);
-        assert_dwy!(Fri 00 2026 => 002);
-        assert_dwy!(Sat 00 2026 => 003);
-        assert_dwy!(Sun 00 2026 => 004);
-        assert_dwy!(Mon 01 2026 => 005);
-        assert_dwy!(Tue 01 2026 => 006);
-        assert_dwy!(Wed 01 2026 => 007);
+        let max = Date::from_yo(i32::max_value(), super::days_in_year(i32::max_value()));
+        assert_eq!(max.checked_add(0.days()), Some(max));
+        assert_eq!(max.checked_add(1.days()), None);
+        assert_eq!(max.checked_add((-1).days()), max.checked_previous_day());
 
-        // E
-        assert_dwy!(Wed 00 2025 => 001);
-        assert_dwy!(Thu 00 2025 => 002);
-        assert_dwy!(Fri 00 2025 => 003);
-        assert_dwy!(Sat 00 2025 => 004);
-        assert_dwy!(Sun 00 2025 => 005);
-        assert_dwy!(Mon 01 2025 => 006);
-        assert_dwy!(Tue 01 2025 => 007);
+        let min = Date::from_yo(i32::min_value(), 1);
+        assert_eq!(min.checked_add(0.days()), Some(min));
+        assert_eq!(min.checked_add((-1).days()), None);
+        assert_eq!(min.checked_add(1.days()), min.checked_next_day());
+        assert_eq!(
+            min.checked_add((max.julian_day() - min.julian_day()).days()),
+            Some(max)
+        );
+    }
 
-        // F
-        assert_dwy!(Tue 00 2019 => 001);
-        assert_dwy!(Wed 00 2019 => 002);
-        assert_dwy!(Thu 00 2019 => 003);
-        assert_dwy!(Fri 00 2019 => 004);
-        assert_dwy!(Sat 00 2019 => 005);
-        assert_dwy!(Sun 00 2019 => 006);
-        assert_dwy!(Mon 01 2019 => 007);
+    #[test]
+    fn checked_sub() {
+        assert_eq!(
+            ymd!(2019, 1, 6).checked_sub(5.days()),
+            Some(ymd!(2019, 1, 1))
+        );
+        assert_eq!(
+            ymd!(2020, 1, 1).checked_sub(1.days()),
+            Some(ymd!(2019, 12, 31))
+        );
+        assert_eq!(
+            ymd!(2019, 12, 31).checked_sub((-1).days()),
+            Some(ymd!(2020, 1, 1))
+        );
+        assert_eq!(
+            ymd!(2019, 1, 1).checked_sub(Duration::seconds(i64::max_value())),
+            None
+        );
+        assert_eq!(
+            Date::from_yo(i32::min_value(), 1).checked_sub(1.days()),
+            None
+        );
+    }
 
-        // G
-        assert_dwy!(Mon 01 2018 => 001);
-        assert_dwy!(Tue 01 2018 => 002);
-        assert_dwy!(Wed 01 2018 => 003);
-        assert_dwy!(Thu 01 2018 => 004);
-        assert_dwy!(Fri 01 2018 => 005);
-        assert_dwy!(Sat 01 2018 => 006);
-        assert_dwy!(Sun 01 2018 => 007);
+    #[test]
+    fn julian_day() {
+        assert_eq!(ymd!(-4713, 11, 24).julian_day(), 0);
+        assert_eq!(ymd!(2000, 1, 1).julian_day(), 2_451_545);
+        assert_eq!(ymd!(2019, 1, 1).julian_day(), 2_458_485);
+        assert_eq!(ymd!(2019, 12, 31).julian_day(), 2_458_849);
+        assert_eq!(ymd!(-5000, 1, 1).julian_day(), -105_152);
+        assert_eq!(ymd!(-5000, 3, 1).julian_day(), -105_093);
+    }
 
-        // AG
-        assert_dwy!(Sun 00 2012 => 001);
-        assert_dwy!(Mon 01 2012 => 002);
-        assert_dwy!(Tue 01 2012 => 003);
-        assert_dwy!(Wed 01 2012 => 004);
-        assert_dwy!(Thu 01 2012 => 005);
-        assert_dwy!(Fri 01 2012 => 006);
-        assert_dwy!(Sat 01 2012 => 007);
-        assert_dwy!(Tue 09 2012 => 059);
-        assert_dwy!(Wed 09 2012 => 060);
-        assert_dwy!(Thu 09 2012 => 061);
-        assert_dwy!(Fri 09 2012 => 062);
-        assert_dwy!(Sat 09 2012 => 063);
-        assert_dwy!(Sun 09 2012 => 064);
-        assert_dwy!(Mon 10 2012 => 065);
-        assert_dwy!(Tue 10 2012 => 066);
-        assert_dwy!(Wed 10 2012 => 067);
+    #[test]
+    fn from_julian_day() {
+        assert_eq!(julian!(0), ymd!(-4713, 11, 24));
+        assert_eq!(julian!(2_451_545), ymd!(2000, 1, 1));
+        assert_eq!(julian!(2_458_485), ymd!(2019, 1, 1));
+        assert_eq!(julian!(2_458_849), ymd!(2019, 12, 31));
+        assert_eq!(julian!(-105_152), ymd!(-5000, 1, 1));
+        assert_eq!(julian!(-105_093), ymd!(-5000, 3, 1));
+        assert_eq!(julian!(-1), ymd!(-4713, 11, 23));
+    }
 
-        // BA
-        assert_dwy!(Sat 00 2028 => 001);
-        assert_dwy!(Sun 00 2028 => 002);
-        assert_dwy!(Mon 01 2028 => 003);
-        assert_dwy!(Tue 01 2028 => 004);
-        assert_dwy!(Wed 01 2028 => 005);
-        assert_dwy!(Thu 01 2028 => 006);
-        assert_dwy!(Fri 01 2028 => 007);
-        assert_dwy!(Mon 09 2028 => 059);
-        assert_dwy!(Tue 09 2028 => 060);
-        assert_dwy!(Wed 09 2028 => 061);
-        assert_dwy!(Thu 09 2028 => 062);
-        assert_dwy!(Fri 09 2028 => 063);
-        assert_dwy!(Sat 09 2028 => 064);
-        assert_dwy!(Sun 09 2028 => 065);
-        assert_dwy!(Mon 10 2028 => 066);
-        assert_dwy!(Tue 10 2028 => 067);
+    #[test]
+    fn constructor_round_trip() {
+        for &year in &[-4713, -1, 0, 1, 1900, 1970, 2000, 2019, 2020, 2100] {
+            let first = Date::from_yo(year, 1);
+            let mut julian_day = first.julian_day();
+
+            for date in first.iter_days().take_while(|date| date.year() == year) {
+                assert_eq!(Date::from_yo(year, date.ordinal()), date);
+                let (month, day) = date.month_day();
+                assert_eq!(Date::from_ymd(year, month, day), date);
+                let (iso_year, week) = date.iso_year_week();
+                assert_eq!(Date::from_iso_ywd(iso_year, week, date.weekday()), date);
+
+                assert_eq!(date.julian_day(), julian_day);
+                assert_eq!(Date::from_julian_day(julian_day), date);
+                julian_day += 1;
+            }
 
-        // CB
-        assert_dwy!(Fri 00 2016 => 001);
-        assert_dwy!(Sat 00 2016 => 002);
-        assert_dwy!(Sun 00 2016 => 003);
-        assert_dwy!(Mon 01 2016 => 004);
-        assert_dwy!(Tue 01 2016 => 005);
-        assert_dwy!(Wed 01 2016 => 006);
-        assert_dwy!(Thu 01 2016 => 007);
-        assert_dwy!(Sun 08 2016 => 059);
-        assert_dwy!(Mon 09 2016 => 060);
-        assert_dwy!(Tue 09 2016 => 061);
-        assert_dwy!(Wed 09 2016 => 062);
-        assert_dwy!(Thu 09 2016 => 063);
-        assert_dwy!(Fri 09 2016 => 064);
-        assert_dwy!(Sat 09 2016 => 065);
-        assert_dwy!(Sun 09 2016 => 066);
-        assert_dwy!(Mon 10 2016 => 067);
+            assert_eq!(
+                Date::from_julian_day(julian_day - 1).ordinal(),
+                super::days_in_year(year)
+            );
+        }
+    }
 
-        // DC
-        assert_dwy!(Thu 00 2032 => 001);
-        assert_dwy!(Fri 00 2032 => 002);
-        assert_dwy!(Sat 00 2032 => 003);
-        assert_dwy!(Sun 00 2032 => 004);
-        assert_dwy!(Mon 01 2032 => 005);
-        assert_dwy!(Tue 01 2032 => 006);
-        assert_dwy!(Wed 01 2032 => 007);
-        assert_dwy!(Sat 08 2032 => 059);
-        assert_dwy!(Sun 08 2032 => 060);
-        assert_dwy!(Mon 09 2032 => 061);
-        assert_dwy!(Tue 09 2032 => 062);
-        assert_dwy!(Wed 09 2032 => 063);
-        assert_dwy!(Thu 09 2032 => 064);
-        assert_dwy!(Fri 09 2032 => 065);
-        assert_dwy!(Sat 09 2032 => 066);
-        assert_dwy!(Sun 09 2032 => 067);
+    #[test]
+    fn midnight() {
+        assert_eq!(ymd!(1970, 1, 1).midnight(), DateTime::unix_epoch());
+    }
 
-        // ED
-        assert_dwy!(Wed 00 2020 => 001);
-        assert_dwy!(Thu 00 2020 => 002);
-        assert_dwy!(Fri 00 2020 => 003);
-        assert_dwy!(Sat 00 2020 => 004);
-        assert_dwy!(Sun 00 2020 => 005);
-        assert_dwy!(Mon 01 2020 => 006);
-        assert_dwy!(Tue 01 2020 => 007);
-        assert_dwy!(Fri 08 2020 => 059);
-        assert_dwy!(Sat 08 2020 => 060);
-        assert_dwy!(Sun 08 2020 => 061);
-        assert_dwy!(Mon 09 2020 => 062);
-        assert_dwy!(Tue 09 2020 => 063);
-        assert_dwy!(Wed 09 2020 => 064);
-        assert_dwy!(Thu 09 2020 => 065);
-        assert_dwy!(Fri 09 2020 => 066);
-        assert_dwy!(Sat 09 2020 => 067);
+    #[test]
+    fn with_time() {
+        assert_eq!(
+            ymd!(1970, 1, 1).with_time(Time::from_hms(0, 0, 0)),
+            ymd!(1970, 1, 1).with_hms(0, 0, 0),
+        );
+    }
+
+    #[test]
+    fn with_hms() {
+        assert_eq!(
+            ymd!(1970, 1, 1).with_hms(0, 0, 0),
+            ymd!(1970, 1, 1).with_time(Time::from_hms(0, 0, 0)),
+        );
+    }
+
+    #[test]
+    fn with_hms_milli() {
+        assert_eq!(
+            ymd!(1970, 1, 1).with_hms_milli(0, 0, 0, 0),
+            ymd!(1970, 1, 1).with_time(Time::from_hms_milli(0, 0, 0, 0)),
+        );
+    }
+
+    #[test]
+    fn with_hms_micro() {
+        assert_eq!(
+            ymd!(1970, 1, 1).with_hms_micro(0, 0, 0, 0),
+            ymd!(1970, 1, 1).with_time(Time::from_hms_micro(0, 0, 0, 0)),
+        );
+    }
 
-        // FE
-        assert_dwy!(Tue 00 2036 => 001);
-        assert_dwy!(Wed 00 2036 => 002);
-        assert_dwy!(Thu 00 2036 => 003);
-        assert_dwy!(Fri 00 2036 => 004);
-        assert_dwy!(Sat 00 2036 => 005);
-        assert_dwy!(Sun 00 2036 => 006);
-        assert_dwy!(Mon 01 2036 => 007);
-        assert_dwy!(Thu 08 2036 => 059);
-        assert_dwy!(Fri 08 2036 => 060);
-        assert_dwy!(Sat 08 2036 => 061);
-        assert_dwy!(Sun 08 2036 => 062);
-        assert_dwy!(Mon 09 2036 => 063);
-        assert_dwy!(Tue 09 2036 => 064);
-        assert_dwy!(Wed 09 2036 => 065);
-        assert_dwy!(Thu 09 2036 => 066);
-        assert_dwy!(Fri 09 2036 => 067);
+    #[test]
+    fn with_hms_nano() {
+        assert_eq!(
+            ymd!(1970, 1, 1).with_hms_nano(0, 0, 0, 0),
+            ymd!(1970, 1, 1).with_time(Time::from_hms_nano(0, 0, 0, 0)),
+        );
+    }
 
-        // GF
-        assert_dwy!(Mon 01 2024 => 001);
-        assert_dwy!(Tue 01 2024 => 002);
-        assert_dwy!(Wed 01 2024 => 003);
-        assert_dwy!(Thu 01 2024 => 004);
-        assert_dwy!(Fri 01 2024 => 005);
-        assert_dwy!(Sat 01 2024 => 006);
-        assert_dwy!(Sun 01 2024 => 007);
-        assert_dwy!(Wed 09 2024 => 059);
-        assert_dwy!(Thu 09 2024 => 060);
-        assert_dwy!(Fri 09 2024 => 061);
-        assert_dwy!(Sat 09 2024 => 062);
-        assert_dwy!(Sun 09 2024 => 063);
-        assert_dwy!(Mon 10 2024 => 064);
-        assert_dwy!(Tue 10 2024 => 065);
-        assert_dwy!(Wed 10 2024 => 066);
-        assert_dwy!(Thu 10 2024 => 067);
+    #[test]
+    fn format() {
+        assert_eq!(ymd!(2019, 1, 2).format("%Y-%m-%d"), "2019-01-02");
     }
 
     #[test]
-    #[allow(clippy::zero_prefixed_literal)]
-    fn test_parse_sunday_based_week() {
-        macro_rules! assert_dwy {
-            ($weekday:ident $week:literal $year:literal => $ordinal:literal) => {
-                assert_eq!(
-                    Date::parse(
-                        concat!(
-                            stringify!($weekday),
-                            " ",
-                            stringify!($week),
-                            " ",
-                            stringify!($year)
-                        ),
-                        "%a %U %Y"
-                    ),
-                    Ok(Date::from_yo($year, $ordinal))
-                );
-            };
+    fn format_language() {
+        assert_eq!(
+            ymd!(2019, 1, 2).format_language("%B, %A", Language::en),
+            "January, Wednesday",
+        );
+        assert_eq!(
+            ymd!(2019, 1, 2).format_language("%B, %A", Language::es),
+            "enero, miércoles",
+        );
+        assert_eq!(
+            ymd!(2019, 1, 2).format_language("%B, %A", Language::de),
+            "Januar, Mittwoch",
+        );
+        assert_eq!(
+            ymd!(2019, 3, 3).format_language("%a %-d. %b %Y", Language::de),
+            "So 3. März 2019",
+        );
+        assert_eq!(
+            ymd!(2019, 3, 4).format_language("%A, %-d de %B de %Y", Language::pt),
+            "segunda-feira, 4 de março de 2019",
+        );
+        assert_eq!(
+            ymd!(2019, 3, 9).format_language("%a %-d %b", Language::pt),
+            "sáb 9 mar",
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Date::parse("2019-01-02", "%F"), Ok(ymd!(2019, 1, 2)));
+        assert_eq!(Date::parse("2019-002", "%Y-%j"), Ok(yo!(2019, 2)));
+        assert_eq!(
+            Date::parse("2019-W01-3", "%G-W%V-%u"),
+            Ok(ywd!(2019, 1, Wednesday))
+        );
+        assert_eq!(
+            Date::parse("2019-13-01", "%F"),
+            Err(ParseError::InvalidMonth)
+        );
+        assert_eq!(
+            Date::parse("2019-02-29", "%F"),
+            Err(ParseError::InvalidDayOfMonth)
+        );
+        assert_eq!(Date::parse("2020-02-29", "%F"), Ok(ymd!(2020, 2, 29)));
+        assert_eq!(
+            Date::parse("Wednesday 2019-01-02", "%A %F"),
+            Ok(ymd!(2019, 1, 2))
+        );
+        assert_eq!(Date::parse("Wed 2019-002", "%a %Y-%j"), Ok(yo!(2019, 2)));
+        assert_eq!(
+            Date::parse("Thursday 2019-01-02", "%A %F"),
+            Err(ParseError::ComponentConflict)
+        );
+        assert_eq!(
+            Date::parse("Thu 2019-002", "%a %Y-%j"),
+            Err(ParseError::ComponentConflict)
+        );
+        assert_eq!(Date::parse("2019-01- 2", "%Y-%m-%e"), Ok(ymd!(2019, 1, 2)));
+        assert_eq!(Date::parse(" 2 2019-01", "%e %Y-%m"), Ok(ymd!(2019, 1, 2)));
+        assert_eq!(
+            Date::parse("2019-000", "%Y-%j"),
+            Err(ParseError::InvalidDayOfYear)
+        );
+        assert_eq!(Date::parse("2019-365", "%Y-%j"), Ok(yo!(2019, 365)));
+        assert_eq!(
+            Date::parse("2019-366", "%Y-%j"),
+            Err(ParseError::InvalidDayOfYear)
+        );
+        assert_eq!(Date::parse("2020-366", "%Y-%j"), Ok(yo!(2020, 366)));
+        assert_eq!(
+            Date::parse("2020-367", "%Y-%j"),
+            Err(ParseError::InvalidDayOfYear)
+        );
+    }
+
+    #[test]
+    fn composite_specifiers() {
+        let date = ymd!(2001, 8, 3);
+        assert_eq!(date.format("%F"), date.format("%-Y-%m-%d"));
+        assert_eq!(date.format("%F"), "2001-08-03");
+        assert_eq!(date.format("%D"), date.format("%-m/%d/%y"));
+        assert_eq!(date.format("%D"), "8/03/01");
+
+        assert_eq!(Date::parse("8/03/01", "%D"), Ok(date));
+        assert_eq!(Date::parse("08/03/01", "%D"), Ok(date));
+        assert_eq!(
+            Date::parse("8/03/01", "%D"),
+            Date::parse("8/03/01", "%-m/%d/%y")
+        );
+
+        for &date in &[
+            ymd!(1969, 1, 1),
+            ymd!(1999, 12, 31),
+            ymd!(2000, 2, 29),
+            ymd!(2068, 7, 4),
+        ] {
+            assert_eq!(Date::parse(&date.format("%F"), "%F"), Ok(date));
+            assert_eq!(Date::parse(&date.format("%D"), "%D"), Ok(date));
         }
+    }
 
-        // A
-        assert_dwy!(Sun 01 2018 => 001);
-        assert_dwy!(Mon 01 2018 => 002);
-        assert_dwy!(Tue 01 2018 => 003);
-        assert_dwy!(Wed 01 2018 => 004);
-        assert_dwy!(Thu 01 2018 => 005);
-        assert_dwy!(Fri 01 2018 => 006);
-        assert_dwy!(Sat 01 2018 => 007);
+    #[test]
+    fn parse_short_input() {
+        assert_eq!(
+            Date::parse("2019-01-0", "%Y-%m-%d"),
+            Err(ParseError::InvalidDayOfMonth)
+        );
+        assert_eq!(
+            Date::parse("2019-0", "%Y-%m"),
+            Err(ParseError::InvalidMonth)
+        );
+        assert_eq!(Date::parse("2019-01- 2", "%Y-%m-%_d"), Ok(ymd!(2019, 1, 2)));
+        assert_eq!(Date::parse("2019- 1-02", "%Y-%_m-%d"), Ok(ymd!(2019, 1, 2)));
+    }
 
-        // B
-        assert_dwy!(Sat 00 2023 => 001);
-        assert_dwy!(Sun 01 2023 => 002);
-        assert_dwy!(Mon 01 2023 => 003);
-        assert_dwy!(Tue 01 2023 => 004);
-        assert_dwy!(Wed 01 2023 => 005);
-        assert_dwy!(Thu 01 2023 => 006);
-        assert_dwy!(Fri 01 2023 => 007);
+    #[test]
+    fn parse_two_digit_year() {
+        assert_eq!(Date::parse("69-01-01", "%y-%m-%d"), Ok(ymd!(1969, 1, 1)));
+        assert_eq!(Date::parse("99-01-01", "%y-%m-%d"), Ok(ymd!(1999, 1, 1)));
+        assert_eq!(Date::parse("00-01-01", "%y-%m-%d"), Ok(ymd!(2000, 1, 1)));
+        assert_eq!(Date::parse("68-01-01", "%y-%m-%d"), Ok(ymd!(2068, 1, 1)));
 
-        // C
-        assert_dwy!(Fri 00 2022 => 001);
-        assert_dwy!(Sat 00 2022 => 002);
-        assert_dwy!(Sun 01 2022 => 003);
-        assert_dwy!(Mon 01 2022 => 004);
-        assert_dwy!(Tue 01 2022 => 005);
-        assert_dwy!(Wed 01 2022 => 006);
-        assert_dwy!(Thu 01 2022 => 007);
+        // An explicit century takes precedence, in either order.
+        assert_eq!(
+            Date::parse("18 01-01-01", "%C %y-%m-%d"),
+            Ok(ymd!(1801, 1, 1))
+        );
+        assert_eq!(
+            Date::parse("01 18-01-01", "%y %C-%m-%d"),
+            Ok(ymd!(1801, 1, 1))
+        );
+        assert_eq!(
+            Date::parse("21 70-01-01", "%C %y-%m-%d"),
+            Ok(ymd!(2170, 1, 1))
+        );
+    }
 
-        // D
-        assert_dwy!(Thu 00 2021 => 001);
-        assert_dwy!(Fri 00 2021 => 002);
-        assert_dwy!(Sat 00 2021 => 003);
-        assert_dwy!(Sun 01 2021 => 004);
-        assert_dwy!(Mon 01 2021 => 005);
-        assert_dwy!(Tue 01 2021 => 006);
-        assert_dwy!(Wed 01 2021 => 007);
+    #[test]
+    fn parse_with_position() {
+        assert_eq!(
+            Date::parse_with_position("2019-01-02", "%Y-%m-%d"),
+            Ok(ymd!(2019, 1, 2))
+        );
 
-        // E
-        assert_dwy!(Wed 00 2026 => 001);
-        assert_dwy!(Thu 00 2026 => 002);
-        assert_dwy!(Fri 00 2026 => 003);
-        assert_dwy!(Sat 00 2026 => 004);
-        assert_dwy!(Sun 01 2026 => 005);
-        assert_dwy!(Mon 01 2026 => 006);
-        assert_dwy!(Tue 01 2026 => 007);
+        assert_eq!(
+            Date::parse_with_position("2019-1x-02", "%Y-%m-%d")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((ParseError::InvalidMonth, 5))
+        );
 
-        // F
-        assert_dwy!(Tue 00 2025 => 001);
-        assert_dwy!(Wed 00 2025 => 002);
-        assert_dwy!(Thu 00 2025 => 003);
-        assert_dwy!(Fri 00 2025 => 004);
-        assert_dwy!(Sat 00 2025 => 005);
-        assert_dwy!(Sun 01 2025 => 006);
-        assert_dwy!(Mon 01 2025 => 007);
+        assert_eq!(
+            Date::parse_with_position("2019/01/02", "%Y-%m-%d")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((
+                ParseError::UnexpectedCharacter {
+                    expected: '-',
+                    actual: '/'
+                },
+                4
+            ))
+        );
 
-        // G
-        assert_dwy!(Mon 00 2019 => 001);
-        assert_dwy!(Tue 00 2019 => 002);
-        assert_dwy!(Wed 00 2019 => 003);
-        assert_dwy!(Thu 00 2019 => 004);
-        assert_dwy!(Fri 00 2019 => 005);
-        assert_dwy!(Sat 00 2019 => 006);
-        assert_dwy!(Sun 01 2019 => 007);
+        assert_eq!(
+            Date::parse_with_position("Smarch 2 2019", "%B %-d %Y")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((ParseError::InvalidMonth, 0))
+        );
 
-        // AG
-        assert_dwy!(Sun 01 2024 => 001);
-        assert_dwy!(Mon 01 2024 => 002);
-        assert_dwy!(Tue 01 2024 => 003);
-        assert_dwy!(Wed 01 2024 => 004);
-        assert_dwy!(Thu 01 2024 => 005);
-        assert_dwy!(Fri 01 2024 => 006);
-        assert_dwy!(Sat 01 2024 => 007);
-        assert_dwy!(Tue 09 2024 => 059);
-        assert_dwy!(Wed 09 2024 => 060);
-        assert_dwy!(Thu 09 2024 => 061);
-        assert_dwy!(Fri 09 2024 => 062);
-        assert_dwy!(Sat 09 2024 => 063);
-        assert_dwy!(Sun 10 2024 => 064);
-        assert_dwy!(Mon 10 2024 => 065);
-        assert_dwy!(Tue 10 2024 => 066);
-        assert_dwy!(Wed 10 2024 => 067);
+        assert_eq!(
+            Date::parse_with_position("2019-01", "%Y-%m-%d")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((ParseError::UnexpectedEndOfString, 7))
+        );
 
-        // BA
-        assert_dwy!(Sat 00 2012 => 001);
-        assert_dwy!(Sun 01 2012 => 002);
-        assert_dwy!(Mon 01 2012 => 003);
-        assert_dwy!(Tue 01 2012 => 004);
-        assert_dwy!(Wed 01 2012 => 005);
-        assert_dwy!(Thu 01 2012 => 006);
-        assert_dwy!(Fri 01 2012 => 007);
-        assert_dwy!(Mon 09 2012 => 059);
-        assert_dwy!(Tue 09 2012 => 060);
-        assert_dwy!(Wed 09 2012 => 061);
-        assert_dwy!(Thu 09 2012 => 062);
-        assert_dwy!(Fri 09 2012 => 063);
-        assert_dwy!(Sat 09 2012 => 064);
-        assert_dwy!(Sun 10 2012 => 065);
-        assert_dwy!(Mon 10 2012 => 066);
-        assert_dwy!(Tue 10 2012 => 067);
+        // Errors found after parsing are at the end of the consumed input.
+        assert_eq!(
+            Date::parse_with_position("2019-02-30 and more", "%Y-%m-%d")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((ParseError::InvalidDayOfMonth, 10))
+        );
 
-        // CB
-        assert_dwy!(Fri 00 2028 => 001);
-        assert_dwy!(Sat 00 2028 => 002);
-        assert_dwy!(Sun 01 2028 => 003);
-        assert_dwy!(Mon 01 2028 => 004);
-        assert_dwy!(Tue 01 2028 => 005);
-        assert_dwy!(Wed 01 2028 => 006);
-        assert_dwy!(Thu 01 2028 => 007);
-        assert_dwy!(Sun 09 2028 => 059);
-        assert_dwy!(Mon 09 2028 => 060);
-        assert_dwy!(Tue 09 2028 => 061);
-        assert_dwy!(Wed 09 2028 => 062);
-        assert_dwy!(Thu 09 2028 => 063);
-        assert_dwy!(Fri 09 2028 => 064);
-        assert_dwy!(Sat 09 2028 => 065);
-        assert_dwy!(Sun 10 2028 => 066);
-        assert_dwy!(Mon 10 2028 => 067);
+        assert_eq!(
+            Date::parse_with_position("2019-1x-02", "%Y-%m-%d").map_err(ParseError::from),
+            Date::parse("2019-1x-02", "%Y-%m-%d")
+        );
+    }
 
-        // DC
-        assert_dwy!(Thu 00 2016 => 001);
-        assert_dwy!(Fri 00 2016 => 002);
-        assert_dwy!(Sat 00 2016 => 003);
-        assert_dwy!(Sun 01 2016 => 004);
-        assert_dwy!(Mon 01 2016 => 005);
-        assert_dwy!(Tue 01 2016 => 006);
-        assert_dwy!(Wed 01 2016 => 007);
-        assert_dwy!(Sat 08 2016 => 059);
-        assert_dwy!(Sun 09 2016 => 060);
-        assert_dwy!(Mon 09 2016 => 061);
-        assert_dwy!(Tue 09 2016 => 062);
-        assert_dwy!(Wed 09 2016 => 063);
-        assert_dwy!(Thu 09 2016 => 064);
-        assert_dwy!(Fri 09 2016 => 065);
-        assert_dwy!(Sat 09 2016 => 066);
-        assert_dwy!(Sun 10 2016 => 067);
+    #[test]
+    fn parse_strict() {
+        assert_eq!(Date::parse_strict("2019-01-02", "%F"), Ok(ymd!(2019, 1, 2)));
+        assert_eq!(
+            Date::parse_strict("2019-01-02T", "%F"),
+            Err(ParseError::UnexpectedTrailingCharacters)
+        );
+        assert_eq!(
+            Date::parse_strict("Jan 2 2019!", "%b %-d %Y"),
+            Err(ParseError::UnexpectedTrailingCharacters)
+        );
+        assert_eq!(
+            Date::parse_strict("2019-01", "%F"),
+            Err(ParseError::UnexpectedEndOfString)
+        );
+        assert_eq!(Date::parse("2019-01-02T", "%F"), Ok(ymd!(2019, 1, 2)));
+    }
 
-        // ED
-        assert_dwy!(Wed 00 2032 => 001);
-        assert_dwy!(Thu 00 2032 => 002);
-        assert_dwy!(Fri 00 2032 => 003);
-        assert_dwy!(Sat 00 2032 => 004);
-        assert_dwy!(Sun 01 2032 => 005);
-        assert_dwy!(Mon 01 2032 => 006);
-        assert_dwy!(Tue 01 2032 => 007);
-        assert_dwy!(Fri 08 2032 => 059);
-        assert_dwy!(Sat 08 2032 => 060);
-        assert_dwy!(Sun 09 2032 => 061);
-        assert_dwy!(Mon 09 2032 => 062);
-        assert_dwy!(Tue 09 2032 => 063);
-        assert_dwy!(Wed 09 2032 => 064);
-        assert_dwy!(Thu 09 2032 => 065);
-        assert_dwy!(Fri 09 2032 => 066);
-        assert_dwy!(Sat 09 2032 => 067);
+    #[test]
+    fn parse_any() {
+        let formats = ["%F", "%m/%d/%Y", "%04Y%m%d"];
+        assert_eq!(
+            Date::parse_any("2019-01-02", &formats),
+            Ok(ymd!(2019, 1, 2))
+        );
+        // The first format fails, but the second succeeds.
+        assert_eq!(
+            Date::parse_any("01/02/2019", &formats),
+            Ok(ymd!(2019, 1, 2))
+        );
+        assert_eq!(Date::parse_any("20190102", &formats), Ok(ymd!(2019, 1, 2)));
 
-        // FE
-        assert_dwy!(Tue 00 2020 => 001);
-        assert_dwy!(Wed 00 2020 => 002);
-        assert_dwy!(Thu 00 2020 => 003);
-        assert_dwy!(Fri 00 2020 => 004);
-        assert_dwy!(Sat 00 2020 => 005);
-        assert_dwy!(Sun 01 2020 => 006);
-        assert_dwy!(Mon 01 2020 => 007);
-        assert_dwy!(Thu 08 2020 => 059);
-        assert_dwy!(Fri 08 2020 => 060);
-        assert_dwy!(Sat 08 2020 => 061);
-        assert_dwy!(Sun 09 2020 => 062);
-        assert_dwy!(Mon 09 2020 => 063);
-        assert_dwy!(Tue 09 2020 => 064);
-        assert_dwy!(Wed 09 2020 => 065);
-        assert_dwy!(Thu 09 2020 => 066);
-        assert_dwy!(Fri 09 2020 => 067);
+        // The first success is returned, even if a later format would also
+        // succeed.
+        assert_eq!(
+            Date::parse_any("01/02/2019", &["%d/%m/%Y", "%m/%d/%Y"]),
+            Ok(ymd!(2019, 2, 1))
+        );
 
-        // GF
-        assert_dwy!(Mon 00 2036 => 001);
-        assert_dwy!(Tue 00 2036 => 002);
-        assert_dwy!(Wed 00 2036 => 003);
-        assert_dwy!(Thu 00 2036 => 004);
-        assert_dwy!(Fri 00 2036 => 005);
-        assert_dwy!(Sat 00 2036 => 006);
-        assert_dwy!(Sun 01 2036 => 007);
-        assert_dwy!(Wed 08 2036 => 059);
-        assert_dwy!(Thu 08 2036 => 060);
-        assert_dwy!(Fri 08 2036 => 061);
-        assert_dwy!(Sat 08 2036 => 062);
-        assert_dwy!(Sun 09 2036 => 063);
-        assert_dwy!(Mon 09 2036 => 064);
-        assert_dwy!(Tue 09 2036 => 065);
-        assert_dwy!(Wed 09 2036 => 066);
-        assert_dwy!(Thu 09 2036 => 067);
+        // The error from the last format is returned.
+        assert_eq!(
+            Date::parse_any("2019-13-02", &["%m/%d/%Y", "%F"]),
+            Err(ParseError::InvalidMonth)
+        );
+        assert_eq!(
+            Date::parse_any("2019-13-02", &["%F", "%m/%d/%Y"]),
+            Date::parse("2019-13-02", "%m/%d/%Y")
+        );
+        assert_eq!(
+            Date::parse_any("2019-01-02", &[]),
+            Err(ParseError::InsufficientInformation {
+                missing: Component::Date
+            })
+        );
     }
 
     #[test]
-    fn is_leap_year() {
-        use super::is_leap_year;
-        assert!(!is_leap_year(1900));
-        assert!(is_leap_year(2000));
-        assert!(is_leap_year(2004));
-        assert!(!is_leap_year(2005));
-        assert!(!is_leap_year(2100));
+    fn from_iso_week_string() {
+        assert_eq!(
+            Date::from_iso_week_string("2020-W01-3"),
+            Ok(ywd!(2020, 1, Wednesday))
+        );
+        assert_eq!(
+            Date::from_iso_week_string("2020W013"),
+            Ok(ywd!(2020, 1, Wednesday))
+        );
+        assert_eq!(
+            Date::from_iso_week_string("2019-W01-1"),
+            Ok(ymd!(2018, 12, 31))
+        );
+        assert_eq!(
+            Date::from_iso_week_string("2020-W53-5"),
+            Ok(ymd!(2021, 1, 1))
+        );
+        assert_eq!(Date::from_iso_week_string("2020W535"), Ok(ymd!(2021, 1, 1)));
+        assert_eq!(
+            Date::from_iso_week_string("-0001-W01-1"),
+            Ok(ywd!(-1, 1, Monday))
+        );
+
+        assert_eq!(
+            Date::from_iso_week_string("2019-W53-1"),
+            Err(ParseError::InvalidWeek)
+        );
+        assert_eq!(
+            Date::from_iso_week_string("2019W531"),
+            Err(ParseError::InvalidWeek)
+        );
+        assert_eq!(
+            Date::from_iso_week_string("2020-W00-1"),
+            Err(ParseError::InvalidWeek)
+        );
+        assert_eq!(
+            Date::from_iso_week_string("2020-W54-1"),
+            Err(ParseError::InvalidWeek)
+        );
+        assert_eq!(
+            Date::from_iso_week_string("2020-W01-8"),
+            Err(ParseError::InvalidDayOfWeek)
+        );
+        assert_eq!(
+            Date::from_iso_week_string("2020W010"),
+            Err(ParseError::InvalidDayOfWeek)
+        );
     }
 
     #[test]
-    fn days_in_year() {
-        use super::days_in_year;
-        assert_eq!(days_in_year(1900), 365);
-        assert_eq!(days_in_year(2000), 366);
-        assert_eq!(days_in_year(2004), 366);
-        assert_eq!(days_in_year(2005), 365);
-        assert_eq!(days_in_year(2100), 365);
+    fn parse_language() {
+        assert_eq!(
+            Date::parse_language("January 2 2019", "%B %-d %Y", Language::en),
+            Ok(ymd!(2019, 1, 2))
+        );
+        assert_eq!(
+            Date::parse_language("2 de enero 2019", "%-d de %B %Y", Language::es),
+            Ok(ymd!(2019, 1, 2))
+        );
+        assert_eq!(
+            Date::parse_language("Mittwoch, 2. Januar 2019", "%A, %-d. %B %Y", Language::de),
+            Ok(ymd!(2019, 1, 2))
+        );
+        assert_eq!(
+            Date::parse_language("Di 1. Okt 2019", "%a %-d. %b %Y", Language::de),
+            Ok(ymd!(2019, 10, 1))
+        );
+
+        // Each weekday contains a hyphen except Saturday and Sunday.
+        for day in 4..=10 {
+            let date = Date::from_ymd(2019, 3, day);
+            assert_eq!(
+                Date::parse_language(
+                    &date.format_language("%A, %-d de %B de %Y", Language::pt),
+                    "%A, %-d de %B de %Y",
+                    Language::pt
+                ),
+                Ok(date)
+            );
+            assert_eq!(
+                Date::parse_language(
+                    &date.format_language("%a-%d-%b-%Y", Language::pt),
+                    "%a-%d-%b-%Y",
+                    Language::pt
+                ),
+                Ok(date)
+            );
+        }
     }
 
-    #[test]
-    fn weeks_in_year() {
-        use super::weeks_in_year;
-        assert_eq!(weeks_in_year(2019), 52);
-        assert_eq!(weeks_in_year(2020), 53);
+    #[test]
+    fn parse_case_insensitive() {
+        for &s in &["january 2 2019", "JANUARY 2 2019", "jAnUaRy 2 2019"] {
+            assert_eq!(Date::parse(s, "%B %-d %Y"), Ok(ymd!(2019, 1, 2)), "{}", s);
+        }
+        assert_eq!(Date::parse("2 jan 2019", "%-d %b %Y"), Ok(ymd!(2019, 1, 2)));
+        assert_eq!(Date::parse("2 JAN 2019", "%-d %b %Y"), Ok(ymd!(2019, 1, 2)));
+        assert_eq!(
+            Date::parse("wednesday 2019-01-02", "%A %F"),
+            Ok(ymd!(2019, 1, 2))
+        );
+        assert_eq!(Date::parse("WED 2019-01-02", "%a %F"), Ok(ymd!(2019, 1, 2)));
+        assert_eq!(
+            Date::parse("2 de Enero 2019", "%-d de %B %Y"),
+            Err(ParseError::InvalidMonth)
+        );
+        assert_eq!(
+            Date::parse_language("2 de Enero 2019", "%-d de %B %Y", Language::es),
+            Ok(ymd!(2019, 1, 2))
+        );
+        assert_eq!(
+            Date::parse_language("Mercredi 2 Janvier 2019", "%A %-d %B %Y", Language::fr),
+            Ok(ymd!(2019, 1, 2))
+        );
+
+        // Characters outside of ASCII are folded as well.
+        assert_eq!(
+            Date::parse_language("6 FÉVRIER 2019", "%-d %B %Y", Language::fr),
+            Ok(ymd!(2019, 2, 6))
+        );
+        assert_eq!(
+            Date::parse_language("MÄRZ 2019-03-06", "%B %F", Language::de),
+            Ok(ymd!(2019, 3, 6))
+        );
     }
 
     #[test]
-    fn year() {
-        assert_eq!(yo!(2019, 2).year(), 2019);
-        assert_eq!(yo!(2020, 2).year(), 2020);
-    }
+    fn ordinal_suffix() {
+        assert_eq!(ymd!(2019, 7, 4).format("%B %o"), "July 4th");
+        assert_eq!(ymd!(2019, 7, 1).format("%B %o"), "July 1st");
+        assert_eq!(ymd!(2019, 7, 2).format("%B %o"), "July 2nd");
+        assert_eq!(ymd!(2019, 7, 3).format("%B %o"), "July 3rd");
+        assert_eq!(ymd!(2019, 7, 11).format("%B %o"), "July 11th");
+        assert_eq!(ymd!(2019, 7, 21).format("%B %o"), "July 21st");
+        assert_eq!(ymd!(2019, 7, 23).format("%B %o"), "July 23rd");
+        assert_eq!(
+            ymd!(2019, 7, 1).format_language("%o %B", Language::fr),
+            "1er juillet"
+        );
+        assert_eq!(
+            ymd!(2019, 7, 2).format_language("%o %B", Language::fr),
+            "2 juillet"
+        );
+        assert_eq!(
+            ymd!(2019, 7, 4).format_language("%o %B", Language::de),
+            "4. Juli"
+        );
 
-    #[test]
-    fn month() {
-        assert_eq!(yo!(2019, 2).month(), 1);
-        assert_eq!(yo!(2020, 2).month(), 1);
-        assert_eq!(yo!(2019, 60).month(), 3);
-        assert_eq!(yo!(2020, 60).month(), 2);
-    }
+        for day in 1..=31 {
+            let date = Date::from_ymd(2019, 7, day);
+            assert_eq!(Date::parse(&date.format("%o %B %Y"), "%o %B %Y"), Ok(date));
+            assert_eq!(
+                Date::parse_language(
+                    &date.format_language("%o %B %Y", Language::fr),
+                    "%o %B %Y",
+                    Language::fr
+                ),
+                Ok(date)
+            );
+        }
 
-    #[test]
-    fn day() {
-        assert_eq!(yo!(2019, 2).day(), 2);
-        assert_eq!(yo!(2020, 2).day(), 2);
-        assert_eq!(yo!(2019, 60).day(), 1);
-        assert_eq!(yo!(2020, 60).day(), 29);
+        // The suffix is optional when parsing.
+        assert_eq!(Date::parse("4 July 2019", "%o %B %Y"), Ok(ymd!(2019, 7, 4)));
+        assert_eq!(
+            Date::parse("4TH July 2019", "%o %B %Y"),
+            Ok(ymd!(2019, 7, 4))
+        );
+        assert_eq!(
+            Date::parse("4x July 2019", "%o %B %Y"),
+            Err(ParseError::UnexpectedCharacter {
+                expected: ' ',
+                actual: 'x'
+            })
+        );
+        assert_eq!(
+            Date::parse("32nd July 2019", "%o %B %Y"),
+            Err(ParseError::InvalidDayOfMonth)
+        );
     }
 
     #[test]
-    fn iso_year_week() {
-        assert_eq!(ymd!(2019, 1, 1).iso_year_week(), (2019, 1));
-        assert_eq!(ymd!(2019, 10, 4).iso_year_week(), (2019, 40));
-        assert_eq!(ymd!(2020, 1, 1).iso_year_week(), (2020, 1));
-        assert_eq!(ymd!(2020, 12, 31).iso_year_week(), (2020, 53));
-        assert_eq!(ymd!(2021, 1, 1).iso_year_week(), (2020, 53));
-    }
+    fn ordinal_suffix_lenient() {
+        assert_eq!(
+            Date::parse("July 1st 2019", "%B %o %Y"),
+            Ok(ymd!(2019, 7, 1))
+        );
+        assert_eq!(
+            Date::parse("July 22nd 2019", "%B %o %Y"),
+            Ok(ymd!(2019, 7, 22))
+        );
+        assert_eq!(
+            Date::parse("July 31st 2019", "%B %o %Y"),
+            Ok(ymd!(2019, 7, 31))
+        );
 
-    #[test]
-    fn week() {
-        assert_eq!(ymd!(2019, 1, 1).week(), 1);
-        assert_eq!(ymd!(2019, 10, 4).week(), 40);
-        assert_eq!(ymd!(2020, 1, 1).week(), 1);
-        assert_eq!(ymd!(2020, 12, 31).week(), 53);
-        assert_eq!(ymd!(2021, 1, 1).week(), 53);
+        // Incorrect English suffixes are tolerated.
+        assert_eq!(
+            Date::parse("July 1th 2019", "%B %o %Y"),
+            Ok(ymd!(2019, 7, 1))
+        );
+        assert_eq!(
+            Date::parse("July 22th 2019", "%B %o %Y"),
+            Ok(ymd!(2019, 7, 22))
+        );
+        assert_eq!(
+            Date::parse("July 4st 2019", "%B %o %Y"),
+            Ok(ymd!(2019, 7, 4))
+        );
+
+        // English suffixes are accepted regardless of language.
+        assert_eq!(
+            Date::parse_language("1st juillet 2019", "%o %B %Y", Language::fr),
+            Ok(ymd!(2019, 7, 1))
+        );
+        assert_eq!(
+            Date::parse_language("22nd Juli 2019", "%o %B %Y", Language::de),
+            Ok(ymd!(2019, 7, 22))
+        );
+        assert_eq!(
+            Date::parse_language("31st de julio 2019", "%o de %B %Y", Language::es),
+            Ok(ymd!(2019, 7, 31))
+        );
     }
 
     #[test]
-    fn as_ymd() {
-        assert_eq!(ymd!(2019, 1, 2).as_ymd(), (2019, 1, 2));
+    fn fixed_width() {
+        assert_eq!(ymd!(5, 1, 1).format("%04Y"), "0005");
+        assert_eq!(ymd!(5, 1, 1).format("%4Y"), "0005");
+        assert_eq!(ymd!(5, 1, 1).format("%_4Y"), "   5");
+        assert_eq!(ymd!(5, 1, 1).format("%06Y"), "000005");
+        assert_eq!(ymd!(-5, 1, 1).format("%05Y"), "-0005");
+        assert_eq!(ymd!(2019, 1, 5).format("%02d"), "05");
+        assert_eq!(ymd!(2019, 1, 5).format("%3d|%_3d|%-3d"), "005|  5|5");
+        assert_eq!(ymd!(2019, 1, 5).format("%04j"), "0005");
+
+        assert_eq!(Date::parse("0005-01-01", "%04Y-%m-%d"), Ok(ymd!(5, 1, 1)));
+        assert_eq!(Date::parse("-0005-01-01", "%05Y-%m-%d"), Ok(ymd!(-5, 1, 1)));
+        assert_eq!(Date::parse("   5-01-01", "%_4Y-%m-%d"), Ok(ymd!(5, 1, 1)));
+
+        // Exactly four digits are consumed, which `%Y` alone cannot do.
+        assert_eq!(
+            Date::parse("20190105", "%04Y%02m%02d"),
+            Ok(ymd!(2019, 1, 5))
+        );
+        assert_eq!(Date::parse("00050105", "%04Y%02m%02d"), Ok(ymd!(5, 1, 5)));
+        assert!(Date::parse("20190105", "%Y%m%d").is_err());
+
+        for &date in &[ymd!(5, 1, 1), ymd!(-5, 12, 31), ymd!(2019, 7, 4)] {
+            assert_eq!(
+                Date::parse(&date.format("%06Y%03m%03d"), "%06Y%03m%03d"),
+                Ok(date)
+            );
+            assert_eq!(Date::parse(&date.format("%_6Y%_3j"), "%_6Y%_3j"), Ok(date));
+        }
     }
 
     #[test]
-    fn as_wo() {
-        assert_eq!(ymd!(2019, 1, 1).as_yo(), (2019, 1));
+    #[cfg(feature = "std")]
+    fn fixed_width_narrower_than_default() {
+        // A value wider than the field could not be parsed back.
+        assert_panics!(ymd!(2019, 1, 1).format("%02Y"), "%Y has four digits");
+        assert_panics!(Date::parse("20190102", "%02Y%m%d"), "%Y has four digits");
+        assert_panics!(Date::parse("201915", "%4Y%-1m%-1d"), "%m has two digits");
+        assert_panics!(ymd!(2019, 1, 1).format("%2j"), "%j has three digits");
     }
 
     #[test]
-    fn next_day() {
-        assert_eq!(ymd!(2019, 1, 1).next_day(), ymd!(2019, 1, 2));
-        assert_eq!(ymd!(2019, 1, 31).next_day(), ymd!(2019, 2, 1));
-        assert_eq!(ymd!(2019, 12, 31).next_day(), ymd!(2020, 1, 1));
+    fn genitive_month_specifier() {
+        let date = ymd!(2019, 1, 5);
+        for &language in Language::variants() {
+            assert_eq!(
+                date.format_language("%-d %OB", language),
+                date.format_language("%-d %B", language)
+            );
+            assert_eq!(
+                Date::parse_language(
+                    &date.format_language("%-d %OB %Y", language),
+                    "%-d %OB %Y",
+                    language
+                ),
+                Ok(date)
+            );
+        }
+        assert_eq!(date.format("%OB"), "January");
+        assert_eq!(
+            Date::parse("5 JANUARY 2019", "%-d %OB %Y"),
+            Ok(ymd!(2019, 1, 5))
+        );
+        assert_eq!(
+            Date::parse("5 Jan 2019", "%-d %OB %Y"),
+            Err(ParseError::InvalidMonth)
+        );
     }
 
     #[test]
-    fn previous_day() {
-        assert_eq!(ymd!(2019, 1, 2).previous_day(), ymd!(2019, 1, 1));
-        assert_eq!(ymd!(2019, 2, 1).previous_day(), ymd!(2019, 1, 31));
-        assert_eq!(ymd!(2020, 1, 1).previous_day(), ymd!(2019, 12, 31));
+    #[cfg(feature = "std")]
+    fn colons_only_supported_by_offset() {
+        assert_panics!(
+            Date::parse("2019-01-02", "%Y-%m-%:d"),
+            "%:d is not supported"
+        );
+        assert_panics!(ymd!(2019, 1, 2).format("%::Y"), "%::Y is not supported");
     }
 
     #[test]
-    fn julian_day() {
-        assert_eq!(ymd!(-4713, 11, 24).julian_day(), 0);
-        assert_eq!(ymd!(2000, 1, 1).julian_day(), 2_451_545);
-        assert_eq!(ymd!(2019, 1, 1).julian_day(), 2_458_485);
-        assert_eq!(ymd!(2019, 12, 31).julian_day(), 2_458_849);
+    #[cfg(feature = "std")]
+    fn alternative_modifier_only_supported_by_month() {
+        assert_panics!(ymd!(2019, 1, 5).format("%Od"), "%Od is not supported");
+        assert_panics!(
+            Date::parse("2019-01-05", "%Y-%Om-%d"),
+            "%Om is not supported"
+        );
+        assert_panics!(ymd!(2019, 1, 5).format("%Ob"), "%Ob is not supported");
     }
 
     #[test]
-    fn from_julian_day() {
-        assert_eq!(julian!(0), ymd!(-4713, 11, 24));
-        assert_eq!(julian!(2_451_545), ymd!(2000, 1, 1));
-        assert_eq!(julian!(2_458_485), ymd!(2019, 1, 1));
-        assert_eq!(julian!(2_458_849), ymd!(2019, 12, 31));
+    fn buddhist_era_specifier() {
+        assert_eq!(ymd!(2020, 1, 1).format("%K"), "2563");
+        assert_eq!(ymd!(2020, 3, 15).format("%d/%m/%K"), "15/03/2563");
+        assert_eq!(ymd!(-543, 1, 1).format("%K"), "0000");
+        assert_eq!(ymd!(-600, 1, 1).format("%K"), ymd!(-57, 1, 1).format("%Y"));
+
+        assert_eq!(Date::parse("2563-01-01", "%K-%m-%d"), Ok(ymd!(2020, 1, 1)));
+        assert_eq!(Date::parse("15/03/2563", "%d/%m/%K"), Ok(ymd!(2020, 3, 15)));
+
+        for &date in &[ymd!(2020, 2, 29), ymd!(1, 1, 1), ymd!(-1000, 12, 31)] {
+            assert_eq!(Date::parse(&date.format("%K-%j"), "%K-%j"), Ok(date));
+        }
     }
 
     #[test]
-    fn midnight() {
-        assert_eq!(ymd!(1970, 1, 1).midnight(), DateTime::unix_epoch());
+    fn era_specifiers() {
+        for &date in &[ymd!(2019, 1, 5), ymd!(5, 12, 31), ymd!(-1000, 6, 15)] {
+            assert_eq!(date.format("%EC"), date.format("%C"));
+            assert_eq!(date.format("%Ey"), date.format("%y"));
+            assert_eq!(date.format("%EY"), date.format("%Y"));
+            assert_eq!(date.format("%-EY"), date.format("%-Y"));
+            assert_eq!(Date::parse(&date.format("%EY-%j"), "%EY-%j"), Ok(date));
+        }
+        assert_eq!(ymd!(2019, 1, 5).format("%EC%Ey"), "2019");
+        assert_eq!(Date::parse("2019-01-05", "%EY-%m-%d"), Ok(ymd!(2019, 1, 5)));
     }
 
     #[test]
-    fn with_time() {
-        assert_eq!(
-            ymd!(1970, 1, 1).with_time(Time::from_hms(0, 0, 0)),
-            ymd!(1970, 1, 1).with_hms(0, 0, 0),
+    #[cfg(feature = "std")]
+    fn era_modifier_only_supported_by_year() {
+        assert_panics!(ymd!(2019, 1, 5).format("%Ed"), "%Ed is not supported");
+        assert_panics!(
+            Date::parse("2019-01-05", "%Y-%Em-%d"),
+            "%Em is not supported"
         );
+        assert_panics!(ymd!(2019, 1, 5).format("%EB"), "%EB is not supported");
     }
 
     #[test]
-    fn with_hms() {
-        assert_eq!(
-            ymd!(1970, 1, 1).with_hms(0, 0, 0),
-            ymd!(1970, 1, 1).with_time(Time::from_hms(0, 0, 0)),
+    #[cfg(feature = "std")]
+    fn rounding_only_supported_by_subsecond() {
+        assert_panics!(ymd!(2019, 1, 5).format("%~d"), "%~d is not supported");
+        assert_panics!(
+            Date::parse("2019-01-05", "%~Y-%m-%d"),
+            "%~Y is not supported"
         );
     }
 
     #[test]
-    fn with_hms_milli() {
+    fn unpadded_week_numbers() {
+        let date = ymd!(2019, 1, 7);
+        assert_eq!(date.format("%U %W %V"), "01 01 02");
+        assert_eq!(date.format("%-U %-W %-V"), "1 1 2");
+        assert_eq!(date.format("%_U %_W %_V"), " 1  1  2");
+        assert_eq!(ymd!(2019, 1, 1).format("%-U %-W"), "0 0");
+        assert_eq!(ymd!(2019, 12, 31).format("%-U %-W %-V"), "52 52 1");
+
+        for &date in &[ymd!(2019, 1, 7), ymd!(2019, 3, 1), ymd!(2019, 12, 31)] {
+            assert_eq!(
+                Date::parse(&date.format("%Y %-U %a"), "%Y %-U %a"),
+                Ok(date)
+            );
+            assert_eq!(
+                Date::parse(&date.format("%Y %-W %a"), "%Y %-W %a"),
+                Ok(date)
+            );
+            assert_eq!(
+                Date::parse(&date.format("%G %-V %a"), "%G %-V %a"),
+                Ok(date)
+            );
+            assert_eq!(Date::parse(&date.format("%Y %U %a"), "%Y %U %a"), Ok(date));
+        }
+        assert_eq!(Date::parse("2019 1 Mon", "%Y %-W %a"), Ok(date));
+        assert_eq!(Date::parse("2019 01 Mon", "%Y %-W %a"), Ok(date));
+        assert_eq!(Date::parse("2019 2 Mon", "%G %-V %a"), Ok(date));
         assert_eq!(
-            ymd!(1970, 1, 1).with_hms_milli(0, 0, 0, 0),
-            ymd!(1970, 1, 1).with_time(Time::from_hms_milli(0, 0, 0, 0)),
+            Date::parse("2019 1 Mon", "%Y %U %a"),
+            Err(ParseError::InvalidWeek)
         );
     }
 
     #[test]
-    fn with_hms_micro() {
+    fn quarter_specifier() {
+        assert_eq!(ymd!(2019, 1, 1).format("%q"), "1");
+        assert_eq!(ymd!(2019, 3, 31).format("%q"), "1");
+        assert_eq!(ymd!(2019, 4, 1).format("%q"), "2");
+        assert_eq!(ymd!(2019, 6, 30).format("%q"), "2");
+        assert_eq!(ymd!(2019, 7, 1).format("%q"), "3");
+        assert_eq!(ymd!(2019, 9, 30).format("%q"), "3");
+        assert_eq!(ymd!(2019, 10, 1).format("%q"), "4");
+        assert_eq!(ymd!(2019, 12, 31).format("%Y-Q%q"), "2019-Q4");
+
         assert_eq!(
-            ymd!(1970, 1, 1).with_hms_micro(0, 0, 0, 0),
-            ymd!(1970, 1, 1).with_time(Time::from_hms_micro(0, 0, 0, 0)),
+            Date::parse("2019-Q2-05-15", "%Y-Q%q-%m-%d"),
+            Ok(ymd!(2019, 5, 15))
         );
-    }
-
-    #[test]
-    fn with_hms_nano() {
         assert_eq!(
-            ymd!(1970, 1, 1).with_hms_nano(0, 0, 0, 0),
-            ymd!(1970, 1, 1).with_time(Time::from_hms_nano(0, 0, 0, 0)),
+            Date::parse("2019-Q3-05-15", "%Y-Q%q-%m-%d"),
+            Err(ParseError::ComponentConflict)
         );
-    }
-
-    #[test]
-    fn format() {
-        assert_eq!(ymd!(2019, 1, 2).format("%Y-%m-%d"), "2019-01-02");
-    }
-
-    #[test]
-    fn format_language() {
         assert_eq!(
-            ymd!(2019, 1, 2).format_language("%B, %A", Language::en),
-            "January, Wednesday",
+            Date::parse("Q4 2019-365", "Q%q %Y-%j"),
+            Ok(ymd!(2019, 12, 31))
         );
         assert_eq!(
-            ymd!(2019, 1, 2).format_language("%B, %A", Language::es),
-            "enero, miércoles",
+            Date::parse("Q1 2019-365", "Q%q %Y-%j"),
+            Err(ParseError::ComponentConflict)
         );
-    }
-
-    #[test]
-    fn parse() {
-        assert_eq!(Date::parse("2019-01-02", "%F"), Ok(ymd!(2019, 1, 2)));
-        assert_eq!(Date::parse("2019-002", "%Y-%j"), Ok(yo!(2019, 2)));
         assert_eq!(
-            Date::parse("2019-W01-3", "%G-W%V-%u"),
-            Ok(ywd!(2019, 1, Wednesday))
+            Date::parse("2019-Q0-01-01", "%Y-Q%q-%m-%d"),
+            Err(ParseError::InvalidQuarter)
         );
-    }
-
-    #[test]
-    fn parse_language() {
         assert_eq!(
-            Date::parse_language("January 2 2019", "%B %-d %Y", Language::en),
-            Ok(ymd!(2019, 1, 2))
+            Date::parse("2019-Q5-01-01", "%Y-Q%q-%m-%d"),
+            Err(ParseError::InvalidQuarter)
         );
         assert_eq!(
-            Date::parse_language("2 de enero 2019", "%-d de %B %Y", Language::es),
-            Ok(ymd!(2019, 1, 2))
+            Date::parse("2019-Q2", "%Y-Q%q"),
+            Err(ParseError::InsufficientInformation {
+                missing: Component::Date
+            })
         );
     }
 
@@ -2068,4 +4230,271 @@ mod test {
         assert_eq!(first.cmp(&second), Ordering::Less);
         assert_eq!(second.cmp(&first), Ordering::Greater);
     }
+
+    #[test]
+    fn is_weekend() {
+        // 2019-06-03 is a Monday.
+        for (date, weekend) in ymd!(2019, 6, 3)
+            .iter_days()
+            .zip(
+                [false, false, false, false, false, true, true]
+                    .iter()
+                    .cycle(),
+            )
+            .take(21)
+        {
+            assert_eq!(date.is_weekend(), *weekend);
+        }
+    }
+
+    #[test]
+    fn next_weekday() {
+        // 2019-06-05 is a Wednesday.
+        let date = ymd!(2019, 6, 5);
+        assert_eq!(date.next_weekday(Thursday), ymd!(2019, 6, 6));
+        assert_eq!(date.next_weekday(Sunday), ymd!(2019, 6, 9));
+        assert_eq!(date.next_weekday(Monday), ymd!(2019, 6, 10));
+        assert_eq!(date.next_weekday(Wednesday), ymd!(2019, 6, 12));
+        assert_eq!(ymd!(2019, 12, 30).next_weekday(Friday), ymd!(2020, 1, 3));
+
+        for weekday in Weekday::iter() {
+            let next = date.next_weekday(weekday);
+            assert_eq!(next.weekday(), weekday);
+            assert!(next > date && next <= ymd!(2019, 6, 12));
+        }
+    }
+
+    #[test]
+    fn next_or_same_weekday() {
+        let date = ymd!(2019, 6, 5);
+        assert_eq!(date.next_or_same_weekday(Wednesday), date);
+        assert_eq!(date.next_or_same_weekday(Thursday), ymd!(2019, 6, 6));
+        assert_eq!(date.next_or_same_weekday(Tuesday), ymd!(2019, 6, 11));
+
+        for weekday in Weekday::iter() {
+            let next = date.next_or_same_weekday(weekday);
+            assert_eq!(next.weekday(), weekday);
+            assert!(next >= date && next < ymd!(2019, 6, 12));
+        }
+    }
+
+    #[test]
+    fn previous_weekday() {
+        let date = ymd!(2019, 6, 5);
+        assert_eq!(date.previous_weekday(Tuesday), ymd!(2019, 6, 4));
+        assert_eq!(date.previous_weekday(Thursday), ymd!(2019, 5, 30));
+        assert_eq!(date.previous_weekday(Wednesday), ymd!(2019, 5, 29));
+        assert_eq!(
+            ymd!(2020, 1, 2).previous_weekday(Friday),
+            ymd!(2019, 12, 27)
+        );
+
+        for weekday in Weekday::iter() {
+            let previous = date.previous_weekday(weekday);
+            assert_eq!(previous.weekday(), weekday);
+            assert!(previous < date && previous >= ymd!(2019, 5, 29));
+        }
+    }
+
+    #[test]
+    fn add_business_days() {
+        // 2019-06-03 is a Monday.
+        let monday = ymd!(2019, 6, 3);
+        assert_eq!(monday.add_business_days(0), monday);
+        assert_eq!(monday.add_business_days(1), ymd!(2019, 6, 4));
+        assert_eq!(monday.add_business_days(4), ymd!(2019, 6, 7));
+        assert_eq!(monday.add_business_days(5), ymd!(2019, 6, 10));
+        assert_eq!(monday.add_business_days(-1), ymd!(2019, 5, 31));
+        assert_eq!(monday.add_business_days(-5), ymd!(2019, 5, 27));
+        assert_eq!(monday.add_business_days(-6), ymd!(2019, 5, 24));
+        assert_eq!(monday.add_business_days(261), ymd!(2020, 6, 2));
+
+        let saturday = ymd!(2019, 6, 8);
+        let sunday = ymd!(2019, 6, 9);
+        assert_eq!(saturday.add_business_days(0), saturday);
+        assert_eq!(saturday.add_business_days(1), ymd!(2019, 6, 10));
+        assert_eq!(sunday.add_business_days(1), ymd!(2019, 6, 10));
+        assert_eq!(saturday.add_business_days(5), ymd!(2019, 6, 14));
+        assert_eq!(saturday.add_business_days(-1), ymd!(2019, 6, 7));
+        assert_eq!(sunday.add_business_days(-1), ymd!(2019, 6, 7));
+        assert_eq!(sunday.add_business_days(-5), ymd!(2019, 6, 3));
+
+        // Compare against stepping one day at a time.
+        for start in ymd!(2019, 12, 20).range(ymd!(2020, 1, 10)) {
+            let mut forward = start;
+            let mut backward = start;
+            for n in 1..=15 {
+                forward = forward
+                    .iter_days()
+                    .skip(1)
+                    .find(|d| !d.is_weekend())
+                    .unwrap_or(forward);
+                backward = backward.previous_day();
+                while backward.is_weekend() {
+                    backward = backward.previous_day();
+                }
+                assert_eq!(start.add_business_days(n), forward);
+                assert_eq!(start.add_business_days(-n), backward);
+            }
+        }
+    }
+
+    #[test]
+    fn checked_add_business_days() {
+        let monday = ymd!(2019, 6, 3);
+        assert_eq!(monday.checked_add_business_days(0), Some(monday));
+        assert_eq!(monday.checked_add_business_days(5), Some(ymd!(2019, 6, 10)));
+        assert_eq!(
+            monday.checked_add_business_days(-6),
+            Some(ymd!(2019, 5, 24))
+        );
+
+        assert_eq!(monday.checked_add_business_days(i64::max_value()), None);
+        assert_eq!(monday.checked_add_business_days(i64::min_value()), None);
+        assert_eq!(monday.checked_add_business_days(1_000_000_000_000), None);
+        assert_eq!(monday.checked_add_business_days(-1_000_000_000_000), None);
+
+        // A large value that remains in range.
+        assert_eq!(
+            monday.checked_add_business_days(1_000_000_000),
+            Some(monday + Duration::weeks(200_000_000))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn add_business_days_overflow() {
+        let monday = ymd!(2019, 6, 3);
+        assert_panics!(monday.add_business_days(i64::max_value()));
+        assert_panics!(monday.add_business_days(i64::min_value()));
+        assert_panics!(monday.add_business_days(1_000_000_000_000));
+    }
+
+    #[test]
+    fn iter_days() {
+        let mut days = ymd!(2019, 12, 30).iter_days();
+        assert_eq!(days.next(), Some(ymd!(2019, 12, 30)));
+        assert_eq!(days.next(), Some(ymd!(2019, 12, 31)));
+        assert_eq!(days.next(), Some(ymd!(2020, 1, 1)));
+
+        assert_eq!(
+            ymd!(2020, 1, 1).iter_days().nth(59),
+            Some(ymd!(2020, 2, 29))
+        );
+        assert_eq!(ymd!(2019, 1, 1).iter_days().nth(59), Some(ymd!(2019, 3, 1)));
+
+        let last = Date::from_yo(i32::max_value(), super::days_in_year(i32::max_value()));
+        assert_eq!(last.previous_day().iter_days().count(), 2);
+    }
+
+    #[test]
+    fn iter_weeks() {
+        let weeks: Vec<_> = ymd!(2020, 2, 15).iter_weeks().take(4).collect();
+        assert_eq!(
+            weeks,
+            [
+                ymd!(2020, 2, 15),
+                ymd!(2020, 2, 22),
+                ymd!(2020, 2, 29),
+                ymd!(2020, 3, 7)
+            ]
+        );
+        assert!(ymd!(2019, 7, 4)
+            .iter_weeks()
+            .take(60)
+            .all(|date| date.weekday() == Thursday));
+
+        let last = Date::from_yo(i32::max_value(), super::days_in_year(i32::max_value()));
+        assert_eq!(last.previous_day().iter_weeks().count(), 1);
+    }
+
+    #[test]
+    fn range() {
+        let start = ymd!(2019, 1, 1);
+        assert_eq!(start.range(ymd!(2020, 1, 1)).count(), 365);
+        assert_eq!(start.range(ymd!(2021, 1, 1)).count(), 731);
+        assert_eq!(start.range(start).count(), 0);
+        assert_eq!(start.range(start.previous_day()).count(), 0);
+        assert_eq!(start.range(start.next_day()).collect::<Vec<_>>(), [start]);
+
+        let mut previous = None;
+        for day in ymd!(2019, 2, 25).range(ymd!(2020, 3, 5)) {
+            if let Some(previous) = previous {
+                assert_eq!(Date::next_day(previous), day);
+            }
+            previous = Some(day);
+        }
+        assert_eq!(previous, Some(ymd!(2020, 3, 4)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let mut dates = HashSet::new();
+        assert!(dates.insert(ymd!(2019, 3, 1)));
+        assert!(!dates.insert(Date::from_yo(2019, 60)));
+        assert!(!dates.insert(Date::from_iso_ywd(2019, 9, Friday)));
+        assert!(!dates.insert(ymd!(2019, 2, 28).next_day()));
+        assert_eq!(
+            Date::parse("2019-03-01", "%F").map(|date| dates.insert(date)),
+            Ok(false)
+        );
+        assert_eq!(dates.len(), 1);
+
+        assert!(dates.insert(ymd!(2020, 3, 1)));
+        assert!(dates.insert(Date::from_yo(2020, 60)));
+        assert!(dates.insert(ymd!(-2019, 3, 1)));
+        assert_eq!(dates.len(), 4);
+        assert!(dates.contains(&ymd!(2020, 2, 29)));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        use serde_test::{
+            assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token,
+        };
+
+        assert_tokens(&ymd!(2019, 1, 2).readable(), &[Token::Str("2019-01-02")]);
+        assert_tokens(&ymd!(99, 12, 31).readable(), &[Token::Str("0099-12-31")]);
+        assert_tokens(
+            &ymd!(2019, 1, 2).compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::I32(2019),
+                Token::U16(2),
+                Token::TupleEnd,
+            ],
+        );
+        assert_tokens(
+            &ymd!(2020, 12, 31).compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::I32(2020),
+                Token::U16(366),
+                Token::TupleEnd,
+            ],
+        );
+
+        assert_de_tokens_error::<Readable<Date>>(&[Token::Str("2019-13-01")], "invalid month");
+        assert_de_tokens_error::<Readable<Date>>(
+            &[Token::Str("2019-01-01junk")],
+            "unexpected trailing characters",
+        );
+        assert_de_tokens_error::<Readable<Date>>(
+            &[Token::Str("2019-02-29")],
+            "invalid day of month",
+        );
+        assert_de_tokens_error::<Compact<Date>>(
+            &[
+                Token::Tuple { len: 2 },
+                Token::I32(2019),
+                Token::U16(366),
+                Token::TupleEnd,
+            ],
+            "ordinal day is out of range",
+        );
+    }
 }
@@ -3,7 +3,13 @@ use crate::no_std_prelude::*;
 #[cfg(feature = "std")]
 use crate::Sign;
 use crate::{
-    format::parse::{parse, ParseResult, ParsedItems},
+    format::{
+        date_time::{format_rfc2822, parse_rfc2822, parse_rfc3339},
+        parse::{
+            parse, parse_strict, parse_with_position, AmPm, ParseError, ParseErrorAt, ParseResult,
+            ParsedItems,
+        },
+    },
     Date, DeferredFormat, Duration, Language, OffsetDateTime, Time, UtcOffset, Weekday,
 };
 #[cfg(feature = "std")]
@@ -108,7 +114,10 @@ impl DateTime {
     /// ```
     #[inline(always)]
     pub fn timestamp(self) -> i64 {
-        (self - Self::unix_epoch()).whole_seconds()
+        // Counting whole days and seconds ensures that any subsecond is
+        // truncated toward the past, rather than toward the epoch.
+        (self.date.julian_day() - Self::unix_epoch().date.julian_day()) * 86_400
+            + i64::from(self.time.as_seconds_since_midnight())
     }
 
     /// Get the `Date` component of the `DateTime`.
@@ -436,6 +445,36 @@ impl DateTime {
         self.time().nanosecond()
     }
 
+    /// Check if the `DateTime` falls on the provided `Date`, regardless of the
+    /// time of day.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// let date = Date::from_ymd(2019, 1, 2);
+    /// assert!(date.midnight().is_on_date(date));
+    /// assert!(date.with_hms(23, 59, 59).is_on_date(date));
+    /// assert!(!date.midnight().is_on_date(Date::from_ymd(2019, 1, 1)));
+    /// ```
+    #[inline(always)]
+    pub fn is_on_date(self, date: Date) -> bool {
+        self.date() == date
+    }
+
+    /// Check if the `DateTime` falls on a day after the provided `Date`. A
+    /// `DateTime` on the provided date is _not_ considered to be after it.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// let date = Date::from_ymd(2019, 1, 2);
+    /// assert!(date.midnight().is_after_date(Date::from_ymd(2019, 1, 1)));
+    /// assert!(!date.midnight().is_after_date(date));
+    /// assert!(!date.with_hms(23, 59, 59).is_after_date(date));
+    /// ```
+    #[inline(always)]
+    pub fn is_after_date(self, date: Date) -> bool {
+        self.date() > date
+    }
+
     /// Create an `OffsetDateTime` from the existing `DateTime` and provided
     /// `UtcOffset`.
     ///
@@ -495,7 +534,7 @@ impl DateTime {
     ///     Date::from_ymd(2019, 1, 2)
     ///         .midnight()
     ///         .format_language("%c", Language::es),
-    ///     "Mi enero 2 0:00:00 2019",
+    ///     "Mi 2 enero 2019 00:00:00",
     /// );
     /// ```
     #[inline(always)]
@@ -509,6 +548,55 @@ impl DateTime {
         .to_string()
     }
 
+    /// Format the `DateTime`, which is assumed to be in UTC, as an
+    /// [RFC 3339](https://tools.ietf.org/html/rfc3339) date and time. The
+    /// subsecond is only included if it is nonzero, and uses as few digits as
+    /// possible.
+    ///
+    /// RFC 3339 only supports years from 0 to 9999. Other years are formatted
+    /// as with `%Y`.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 1, 2).with_hms(3, 4, 5).format_rfc3339(),
+    ///     "2019-01-02T03:04:05Z"
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 1, 2)
+    ///         .with_hms_milli(3, 4, 5, 600)
+    ///         .format_rfc3339(),
+    ///     "2019-01-02T03:04:05.6Z"
+    /// );
+    /// ```
+    #[inline]
+    pub fn format_rfc3339(self) -> String {
+        let mut s = self.format("%Y-%m-%dT%H:%M:%S");
+
+        if self.nanosecond() != 0 {
+            s.push('.');
+            s.push_str(format!("{:09}", self.nanosecond()).trim_end_matches('0'));
+        }
+
+        s.push('Z');
+        s
+    }
+
+    /// Format the `DateTime`, which is assumed to be in UTC, as an
+    /// [RFC 2822](https://tools.ietf.org/html/rfc2822) date and time.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert_eq!(
+    ///     Date::from_ymd(2003, 7, 1).with_hms(8, 52, 37).format_rfc2822(),
+    ///     "Tue, 01 Jul 2003 08:52:37 +0000"
+    /// );
+    /// ```
+    #[inline]
+    pub fn format_rfc2822(self) -> String {
+        format_rfc2822(self, UtcOffset::UTC)
+    }
+
     /// Attempt to parse a `DateTime` using the provided string. As no language
     /// is specified, English is used.
     ///
@@ -532,6 +620,89 @@ impl DateTime {
         Self::parse_language(s, format, Language::en)
     }
 
+    /// Attempt to parse a `DateTime` using the provided string. Any error includes
+    /// the position in the input at which it occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, DateTime, ParseError};
+    /// assert_eq!(
+    ///     DateTime::parse_with_position("2019-01-02 00:00:00", "%F %T"),
+    ///     Ok(Date::from_ymd(2019, 1, 2).midnight())
+    /// );
+    /// let error = DateTime::parse_with_position("2019-01-02 24:00:00", "%F %H:%M:%S").unwrap_err();
+    /// assert_eq!(error.kind(), ParseError::InvalidHour);
+    /// assert_eq!(error.index(), 11);
+    /// ```
+    #[inline(always)]
+    pub fn parse_with_position(s: &str, format: &str) -> Result<Self, ParseErrorAt> {
+        parse_with_position(s, format, Language::en, Self::try_from_parsed_items)
+    }
+
+    /// Attempt to parse a `DateTime` using the provided string, returning an error if
+    /// any input remains once the format is exhausted.
+    ///
+    /// ```rust
+    /// # use time::{Date, DateTime, ParseError};
+    /// assert_eq!(
+    ///     DateTime::parse_strict("2019-01-02 00:00:00", "%F %T"),
+    ///     Ok(Date::from_ymd(2019, 1, 2).midnight())
+    /// );
+    /// assert_eq!(
+    ///     DateTime::parse_strict("2019-01-02 00:00:00 +0000", "%F %T"),
+    ///     Err(ParseError::UnexpectedTrailingCharacters)
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn parse_strict(s: &str, format: &str) -> ParseResult<Self> {
+        Self::try_from_parsed_items(&parse_strict(s, format, Language::en)?)
+    }
+
+    /// Attempt to parse an [RFC 3339](https://tools.ietf.org/html/rfc3339)
+    /// date and time, such as `2019-01-02T03:04:05.6+01:00`. The returned
+    /// value is in UTC.
+    ///
+    /// Any number of fractional digits is accepted, though only the first nine
+    /// are retained. The offset may be `Z` or `±HH:MM`.
+    ///
+    /// ```rust
+    /// # use time::{Date, DateTime};
+    /// assert_eq!(
+    ///     DateTime::parse_rfc3339("2019-01-02T03:04:05Z"),
+    ///     Ok(Date::from_ymd(2019, 1, 2).with_hms(3, 4, 5))
+    /// );
+    /// assert_eq!(
+    ///     DateTime::parse_rfc3339("2019-01-02T03:04:05.6+01:00"),
+    ///     Ok(Date::from_ymd(2019, 1, 2).with_hms_milli(2, 4, 5, 600))
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn parse_rfc3339(s: &str) -> ParseResult<Self> {
+        OffsetDateTime::parse_well_known(s, parse_rfc3339).map(|datetime| datetime.datetime)
+    }
+
+    /// Attempt to parse an [RFC 2822](https://tools.ietf.org/html/rfc2822)
+    /// date and time, such as `Tue, 1 Jul 2003 10:52:37 +0200`. The returned
+    /// value is in UTC.
+    ///
+    /// The weekday and seconds may be omitted, and the day may have one or two
+    /// digits. The offset may be `±HHMM`, `GMT`, `UT`, or `Z`.
+    ///
+    /// ```rust
+    /// # use time::{Date, DateTime};
+    /// assert_eq!(
+    ///     DateTime::parse_rfc2822("Tue, 1 Jul 2003 10:52:37 +0200"),
+    ///     Ok(Date::from_ymd(2003, 7, 1).with_hms(8, 52, 37))
+    /// );
+    /// assert_eq!(
+    ///     DateTime::parse_rfc2822("01 Jul 2003 10:52 GMT"),
+    ///     Ok(Date::from_ymd(2003, 7, 1).with_hms(10, 52, 0))
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn parse_rfc2822(s: &str) -> ParseResult<Self> {
+        OffsetDateTime::parse_well_known(s, parse_rfc2822).map(|datetime| datetime.datetime)
+    }
+
     /// Attempt to parse a `DateTime` using the provided string and language.
     ///
     /// ```rust
@@ -547,17 +718,72 @@ impl DateTime {
     /// ```
     #[inline(always)]
     pub fn parse_language(s: &str, format: &str, language: Language) -> ParseResult<Self> {
-        Self::try_from_parsed_items(parse(s, format, language)?)
+        Self::try_from_parsed_items(&parse(s, format, language)?)
     }
 
     /// Given the items already parsed, attempt to create a `DateTime`.
     #[inline(always)]
-    pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
+    pub(crate) fn try_from_parsed_items(items: &ParsedItems) -> ParseResult<Self> {
         Ok(Self {
             date: Date::try_from_parsed_items(items)?,
             time: Time::try_from_parsed_items(items)?,
         })
     }
+
+    /// Given the items already parsed, get the `DateTime` indicated by the Unix
+    /// timestamp, if one is present. The returned value is in the parsed
+    /// offset, or UTC if there is none. Any other date or time component that
+    /// was parsed must agree with the timestamp.
+    #[inline]
+    pub(crate) fn from_parsed_timestamp(items: &ParsedItems) -> ParseResult<Option<Self>> {
+        let offset = items.offset.unwrap_or(UtcOffset::UTC);
+        let timestamp = match items.timestamp {
+            Some(timestamp) => timestamp + i64::from(offset.as_seconds()),
+            None => return Ok(None),
+        };
+
+        // Avoid going through `Duration`, which can't represent timestamps
+        // more than a few hundred years from the epoch.
+        let date = Date::from_julian_day(2_440_588 + timestamp.div_euclid(86_400));
+        let seconds = timestamp.rem_euclid(86_400);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let time = Time::from_hms(
+            (seconds / 3_600) as u8,
+            (seconds / 60 % 60) as u8,
+            (seconds % 60) as u8,
+        );
+
+        /// Whether the component was parsed and differs from the expected
+        /// value.
+        macro_rules! differs {
+            ($item:ident $(.$get:ident())?, $expected:expr) => {
+                items.$item.map_or(false, |value| value$(.$get())? != $expected)
+            };
+        }
+
+        let (iso_year, iso_week) = date.iso_year_week();
+        let am_pm = if time.hour() < 12 { AmPm::AM } else { AmPm::PM };
+        if differs!(year, date.year())
+            || differs!(month.get(), date.month())
+            || differs!(day.get(), date.day())
+            || differs!(ordinal_day.get(), date.ordinal())
+            || differs!(weekday, date.weekday())
+            || differs!(quarter.get(), date.quarter())
+            || differs!(week_based_year, iso_year)
+            || differs!(iso_week.get(), iso_week)
+            || differs!(sunday_week, date.sunday_based_week())
+            || differs!(monday_week, date.monday_based_week())
+            || differs!(hour_24, time.hour())
+            || differs!(hour_12.get(), (time.hour() + 11) % 12 + 1)
+            || differs!(am_pm, am_pm)
+            || differs!(minute, time.minute())
+            || differs!(second, time.second())
+        {
+            return Err(ParseError::ComponentConflict);
+        }
+
+        Ok(Some(Self { date, time }))
+    }
 }
 
 impl Add<Duration> for DateTime {
@@ -790,7 +1016,7 @@ impl From<DateTime> for SystemTime {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::prelude::*;
+    use crate::{prelude::*, ParseError};
 
     macro_rules! ymd {
         ($year:literal, $month:literal, $day:literal) => {
@@ -830,6 +1056,13 @@ mod test {
     fn timestamp() {
         assert_eq!(DateTime::unix_epoch().timestamp(), 0);
         assert_eq!(ymd!(2019, 1, 1).midnight().timestamp(), 1_546_300_800);
+        assert_eq!(
+            ymd!(1969, 12, 31)
+                .with_hms_milli(23, 59, 59, 500)
+                .timestamp(),
+            -1
+        );
+        assert_eq!(ymd!(1970, 1, 1).with_hms_milli(0, 0, 0, 500).timestamp(), 0);
     }
 
     #[test]
@@ -966,6 +1199,35 @@ mod test {
         );
     }
 
+    #[test]
+    fn is_on_date() {
+        let date = ymd!(2019, 1, 2);
+        assert!(date.midnight().is_on_date(date));
+        assert!(date.with_hms_nano(23, 59, 59, 999_999_999).is_on_date(date));
+        assert!(!date.midnight().is_on_date(ymd!(2019, 1, 1)));
+        assert!(!date.midnight().is_on_date(ymd!(2019, 1, 3)));
+
+        // One nanosecond before midnight is still the previous day.
+        assert!((date.midnight() - 1.nanoseconds()).is_on_date(ymd!(2019, 1, 1)));
+        assert!(!(date.midnight() - 1.nanoseconds()).is_on_date(date));
+    }
+
+    #[test]
+    fn is_after_date() {
+        let date = ymd!(2019, 1, 2);
+        assert!(date.midnight().is_after_date(ymd!(2019, 1, 1)));
+        assert!(date.midnight().is_after_date(ymd!(2018, 12, 31)));
+        assert!(!date.midnight().is_after_date(date));
+        assert!(!date
+            .with_hms_nano(23, 59, 59, 999_999_999)
+            .is_after_date(date));
+        assert!(!date.midnight().is_after_date(ymd!(2019, 1, 3)));
+
+        // Crossing midnight moves the datetime onto the following date.
+        assert!(!(date.midnight() - 1.nanoseconds()).is_after_date(ymd!(2019, 1, 1)));
+        assert!((date.midnight() + 1.days()).is_after_date(date));
+    }
+
     #[test]
     fn using_offset() {
         assert_eq!(
@@ -985,6 +1247,41 @@ mod test {
         );
     }
 
+    #[test]
+    fn format_subsecond_rounded() {
+        assert_eq!(
+            ymd!(2019, 12, 31)
+                .with_hms_nano(23, 59, 59, 999_999_999)
+                .format("%F %T.%~3f"),
+            "2020-01-01 0:00:00.000"
+        );
+        assert_eq!(
+            ymd!(2019, 12, 31)
+                .with_hms_nano(23, 59, 59, 999_999_999)
+                .format("%F %T.%3f"),
+            "2019-12-31 23:59:59.999"
+        );
+        assert_eq!(
+            ymd!(2019, 12, 31)
+                .with_hms_nano(23, 59, 59, 999_999_999)
+                .format("%F %T.%~9f"),
+            "2019-12-31 23:59:59.999999999"
+        );
+
+        // There is no next day to carry into, so the value is truncated.
+        let last = Date::from_yo(i32::max_value(), crate::days_in_year(i32::max_value()));
+        assert_eq!(
+            last.with_hms_nano(23, 59, 59, 999_999_999)
+                .format("%T.%~3f"),
+            "23:59:59.999"
+        );
+        assert_eq!(
+            last.with_hms_nano(23, 59, 58, 999_999_999)
+                .format("%T.%~3f"),
+            "23:59:59.000"
+        );
+    }
+
     #[test]
     fn format_language() {
         assert_eq!(
@@ -997,10 +1294,48 @@ mod test {
             ymd!(2019, 1, 2)
                 .midnight()
                 .format_language("%c", Language::es),
-            "Mi enero 2 0:00:00 2019",
+            "Mi 2 enero 2019 00:00:00",
         );
     }
 
+    #[test]
+    fn preferred_representations() {
+        let datetime = ymd!(2019, 1, 2).with_hms(3, 4, 5);
+        assert_eq!(datetime.format_language("%x", Language::en), "01/02/19");
+        assert_eq!(datetime.format_language("%x", Language::fr), "02/01/2019");
+        assert_eq!(datetime.format_language("%x", Language::de), "02.01.2019");
+        assert_eq!(datetime.format_language("%X", Language::en), "3:04:05");
+        assert_eq!(datetime.format_language("%X", Language::fr), "03:04:05");
+        assert_eq!(
+            datetime.format_language("%c", Language::en),
+            "Wed Jan 2 3:04:05 2019"
+        );
+        assert_eq!(
+            datetime.format_language("%c", Language::de),
+            "Mi 2 Jan 2019 03:04:05"
+        );
+
+        for &language in Language::variants() {
+            assert_eq!(
+                datetime.format_language("%x %X", language),
+                datetime.format_language(
+                    &format!("{} {}", language.date_format(), language.time_format()),
+                    language
+                )
+            );
+            for &format in &["%c", "%x %X"] {
+                assert_eq!(
+                    DateTime::parse_language(
+                        &datetime.format_language(format, language),
+                        format,
+                        language
+                    ),
+                    Ok(datetime)
+                );
+            }
+        }
+    }
+
     #[test]
     fn parse() {
         assert_eq!(
@@ -1017,6 +1352,291 @@ mod test {
         );
     }
 
+    #[test]
+    fn format_rfc3339() {
+        assert_eq!(
+            ymd!(2019, 1, 2).with_hms(3, 4, 5).format_rfc3339(),
+            "2019-01-02T03:04:05Z"
+        );
+        assert_eq!(
+            ymd!(2019, 1, 2)
+                .with_hms_nano(3, 4, 5, 123_456_789)
+                .format_rfc3339(),
+            "2019-01-02T03:04:05.123456789Z"
+        );
+        assert_eq!(
+            ymd!(2019, 1, 2)
+                .with_hms_micro(3, 4, 5, 120)
+                .format_rfc3339(),
+            "2019-01-02T03:04:05.00012Z"
+        );
+        assert_eq!(
+            ymd!(1, 1, 1).midnight().format_rfc3339(),
+            "0001-01-01T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn parse_rfc3339() {
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02T03:04:05Z"),
+            Ok(ymd!(2019, 1, 2).with_hms(3, 4, 5))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02t03:04:05z"),
+            Ok(ymd!(2019, 1, 2).with_hms(3, 4, 5))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02 03:04:05+00:00"),
+            Ok(ymd!(2019, 1, 2).with_hms(3, 4, 5))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02T03:04:05.123456789Z"),
+            Ok(ymd!(2019, 1, 2).with_hms_nano(3, 4, 5, 123_456_789))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02T03:04:05.1234567891234Z"),
+            Ok(ymd!(2019, 1, 2).with_hms_nano(3, 4, 5, 123_456_789))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02T03:04:05.5-05:30"),
+            Ok(ymd!(2019, 1, 2).with_hms_milli(8, 34, 5, 500))
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-01T00:30:00+01:00"),
+            Ok(ymd!(2018, 12, 31).with_hms(23, 30, 0))
+        );
+
+        for datetime in &[
+            ymd!(2019, 1, 2).with_hms(3, 4, 5),
+            ymd!(2019, 12, 31).with_hms_nano(23, 59, 59, 999_999_999),
+            ymd!(2000, 2, 29).with_hms_micro(12, 0, 0, 1),
+        ] {
+            assert_eq!(
+                DateTime::parse_rfc3339(&datetime.format_rfc3339()),
+                Ok(*datetime)
+            );
+        }
+
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02T03:04:05"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02T03:04:05+0100"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02T03:04:05.Z"),
+            Err(ParseError::InvalidSubsecond)
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("19-01-02T03:04:05Z"),
+            Err(ParseError::InvalidYear)
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02_03:04:05Z"),
+            Err(ParseError::UnexpectedCharacter {
+                expected: 'T',
+                actual: '_'
+            })
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02"),
+            Err(ParseError::UnexpectedEndOfString)
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2019-01-02T03:04:05Z "),
+            Err(ParseError::UnexpectedTrailingCharacters)
+        );
+    }
+
+    #[test]
+    fn format_rfc2822() {
+        assert_eq!(
+            ymd!(2003, 7, 1).with_hms(8, 52, 37).format_rfc2822(),
+            "Tue, 01 Jul 2003 08:52:37 +0000"
+        );
+        assert_eq!(
+            ymd!(2019, 12, 25)
+                .with_hms_milli(0, 0, 0, 999)
+                .format_rfc2822(),
+            "Wed, 25 Dec 2019 00:00:00 +0000"
+        );
+    }
+
+    #[test]
+    fn parse_rfc2822() {
+        let expected = Ok(ymd!(2003, 7, 1).with_hms(8, 52, 37));
+        assert_eq!(
+            DateTime::parse_rfc2822("Tue, 1 Jul 2003 10:52:37 +0200"),
+            expected
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("Tue, 01 Jul 2003 10:52:37 +0200"),
+            expected
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("1 Jul 2003 10:52:37 +0200"),
+            expected
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("Tue,  1 Jul 2003  10:52:37 +0200"),
+            expected
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("tue, 1 jul 2003 10:52:37 +0200"),
+            expected
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("Tue, 1 Jul 2003 08:52:37 GMT"),
+            expected
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("Tue, 1 Jul 2003 08:52:37 UT"),
+            expected
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("Mon, 30 Jun 2003 23:52:37 -0900"),
+            expected
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("Tue, 1 Jul 2003 08:52 +0000"),
+            Ok(ymd!(2003, 7, 1).with_hms(8, 52, 0))
+        );
+
+        for datetime in &[
+            ymd!(2003, 7, 1).with_hms(8, 52, 37),
+            ymd!(2020, 2, 29).midnight(),
+            ymd!(1999, 12, 31).with_hms(23, 59, 59),
+        ] {
+            assert_eq!(
+                DateTime::parse_rfc2822(&datetime.format_rfc2822()),
+                Ok(*datetime)
+            );
+        }
+
+        assert_eq!(
+            DateTime::parse_rfc2822("Wed, 1 Jul 2003 10:52:37 +0200"),
+            Err(ParseError::ComponentConflict)
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("Tue 1 Jul 2003 10:52:37 +0200"),
+            Err(ParseError::UnexpectedCharacter {
+                expected: ',',
+                actual: ' '
+            })
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("Tue, 1 Jul 2003 10:52:37"),
+            Err(ParseError::UnexpectedEndOfString)
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("Tue, 1 Jul 2003 10:52:37 EST"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("Tue, 32 Jul 2003 10:52:37 +0200"),
+            Err(ParseError::InvalidDayOfMonth)
+        );
+        assert_eq!(
+            DateTime::parse_rfc2822("Tue, 1 Jul 2003 10:52:37 +0200 (CEST)"),
+            Err(ParseError::UnexpectedTrailingCharacters)
+        );
+    }
+
+    #[test]
+    fn unix_timestamp_specifier() {
+        assert_eq!(DateTime::unix_epoch().format("%s"), "0");
+        assert_eq!(ymd!(2019, 1, 1).midnight().format("%s"), "1546300800");
+        assert_eq!(ymd!(1969, 12, 31).with_hms(23, 59, 59).format("%s"), "-1");
+
+        assert_eq!(
+            DateTime::parse("1546300800", "%s"),
+            Ok(ymd!(2019, 1, 1).midnight())
+        );
+        assert_eq!(
+            DateTime::parse("-1", "%s"),
+            Ok(ymd!(1969, 12, 31).with_hms(23, 59, 59))
+        );
+        assert_eq!(DateTime::parse("+0", "%s"), Ok(DateTime::unix_epoch()));
+        assert_eq!(
+            DateTime::parse("@1546300800", "@%s"),
+            Ok(ymd!(2019, 1, 1).midnight())
+        );
+        assert_eq!(Date::parse("1546300800", "%s"), Ok(ymd!(2019, 1, 1)));
+        assert_eq!(Time::parse("-1", "%s"), Ok(Time::from_hms(23, 59, 59)));
+
+        assert_eq!(
+            DateTime::parse("-62135596800", "%s"),
+            Ok(ymd!(1, 1, 1).midnight())
+        );
+
+        for &timestamp in &[-86_401, -1, 0, 1, 1_546_300_800, 4_102_444_800] {
+            let datetime = DateTime::from_unix_timestamp(timestamp);
+            assert_eq!(DateTime::parse(&datetime.format("%s"), "%s"), Ok(datetime));
+        }
+
+        assert_eq!(
+            DateTime::parse("x", "%s"),
+            Err(ParseError::InvalidTimestamp)
+        );
+        assert_eq!(
+            DateTime::parse("-", "%s"),
+            Err(ParseError::InvalidTimestamp)
+        );
+        assert_eq!(
+            DateTime::parse("9999999999999", "%s"),
+            Err(ParseError::InvalidTimestamp)
+        );
+
+        // Other components must agree with the timestamp.
+        assert_eq!(
+            DateTime::parse("0 2019", "%s %Y"),
+            Err(ParseError::ComponentConflict)
+        );
+        assert_eq!(
+            Date::parse("0 Friday", "%s %A"),
+            Err(ParseError::ComponentConflict)
+        );
+        assert_eq!(
+            Time::parse("0 01", "%s %H"),
+            Err(ParseError::ComponentConflict)
+        );
+        assert_eq!(
+            DateTime::parse("0 pm", "%s %p"),
+            Err(ParseError::ComponentConflict)
+        );
+        assert_eq!(
+            DateTime::parse("1546300800 2019-01-01 Tue 12 am", "%s %F %a %I %p"),
+            Ok(ymd!(2019, 1, 1).midnight())
+        );
+        assert_eq!(
+            DateTime::parse("3600 +0100 02:00", "%s %z %R"),
+            Ok(ymd!(1970, 1, 1).with_hms(2, 0, 0))
+        );
+    }
+
+    #[test]
+    fn parse_with_position() {
+        assert_eq!(
+            DateTime::parse_with_position("2019-01-02 00:00:00", "%F %T"),
+            Ok(ymd!(2019, 1, 2).midnight())
+        );
+
+        assert_eq!(
+            DateTime::parse_with_position("2019-01-02 24:00:00", "%F %H:%M:%S")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((ParseError::InvalidHour, 11))
+        );
+
+        assert_eq!(
+            DateTime::parse_with_position("2019-01-02", "%F %T")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((ParseError::UnexpectedEndOfString, 10))
+        );
+    }
+
     #[test]
     fn parse_language() {
         assert_eq!(
@@ -1029,6 +1649,68 @@ mod test {
         );
     }
 
+    #[test]
+    fn am_pm_language() {
+        let morning = ymd!(2019, 1, 2).with_hms(9, 30, 0);
+        let evening = ymd!(2019, 1, 2).with_hms(21, 30, 0);
+
+        assert_eq!(
+            morning.format_language("%-I:%M %P", Language::en),
+            "9:30 AM"
+        );
+        assert_eq!(
+            evening.format_language("%-I:%M %p", Language::en),
+            "9:30 pm"
+        );
+        assert_eq!(
+            morning.format_language("%-I:%M %P", Language::es),
+            "9:30 a. m."
+        );
+        assert_eq!(
+            evening.format_language("%-I:%M %p", Language::es),
+            "9:30 p. m."
+        );
+        assert_eq!(evening.format_language("%r", Language::es), "9:30:00 p. m.");
+        assert_eq!(
+            evening.format_language("%-I:%M %P", Language::fr),
+            "9:30 PM"
+        );
+
+        for &datetime in &[morning, evening] {
+            for &language in Language::variants() {
+                for &format in &["%F %-I:%M %p", "%F %-I:%M %P", "%F %r"] {
+                    assert_eq!(
+                        DateTime::parse_language(
+                            &datetime.format_language(format, language),
+                            format,
+                            language
+                        ),
+                        Ok(datetime)
+                    );
+                }
+            }
+        }
+
+        // The English designators are accepted regardless of language, and
+        // case is ignored.
+        assert_eq!(
+            DateTime::parse_language("2019-01-02 9:30 PM", "%F %-I:%M %p", Language::es),
+            Ok(evening)
+        );
+        assert_eq!(
+            DateTime::parse_language("2019-01-02 9:30 P. M.", "%F %-I:%M %p", Language::es),
+            Ok(evening)
+        );
+        assert_eq!(
+            DateTime::parse("2019-01-02 9:30 am", "%F %-I:%M %P"),
+            Ok(morning)
+        );
+        assert_eq!(
+            DateTime::parse_language("2019-01-02 9:30 nm", "%F %-I:%M %p", Language::es),
+            Err(ParseError::InvalidAmPm)
+        );
+    }
+
     #[test]
     fn add_duration() {
         assert_eq!(
@@ -1051,6 +1733,26 @@ mod test {
             ymd!(1999, 12, 31).with_hms(23, 0, 0) + 1.hours(),
             ymd!(2000, 1, 1).midnight(),
         );
+        assert_eq!(
+            ymd!(2019, 1, 31).with_hms(12, 0, 0) + 1.days(),
+            ymd!(2019, 2, 1).with_hms(12, 0, 0),
+        );
+        assert_eq!(
+            ymd!(2019, 4, 30).with_hms(23, 30, 0) + 45.minutes(),
+            ymd!(2019, 5, 1).with_hms(0, 15, 0),
+        );
+        assert_eq!(
+            ymd!(2019, 2, 28).with_hms(12, 0, 0) + 1.days(),
+            ymd!(2019, 3, 1).with_hms(12, 0, 0),
+        );
+        assert_eq!(
+            ymd!(2020, 2, 28).with_hms(12, 0, 0) + 1.days(),
+            ymd!(2020, 2, 29).with_hms(12, 0, 0),
+        );
+        assert_eq!(
+            ymd!(2020, 2, 28).with_hms(23, 0, 0) + 25.hours(),
+            ymd!(2020, 3, 1).midnight(),
+        );
     }
 
     #[test]
@@ -1166,6 +1868,22 @@ mod test {
             ymd!(1999, 12, 31).with_hms(23, 0, 0) - (-1).hours(),
             ymd!(2000, 1, 1).midnight(),
         );
+        assert_eq!(
+            ymd!(2019, 2, 1).with_hms(12, 0, 0) - 1.days(),
+            ymd!(2019, 1, 31).with_hms(12, 0, 0),
+        );
+        assert_eq!(
+            ymd!(2019, 3, 1).with_hms(12, 0, 0) - 1.days(),
+            ymd!(2019, 2, 28).with_hms(12, 0, 0),
+        );
+        assert_eq!(
+            ymd!(2020, 3, 1).with_hms(12, 0, 0) - 1.days(),
+            ymd!(2020, 2, 29).with_hms(12, 0, 0),
+        );
+        assert_eq!(
+            ymd!(2020, 3, 1).midnight() - 25.hours(),
+            ymd!(2020, 2, 28).with_hms(23, 0, 0),
+        );
     }
 
     #[test]
@@ -1321,6 +2039,30 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let mut datetimes = HashSet::new();
+        assert!(datetimes.insert(ymd!(2019, 3, 1).with_hms(12, 0, 0)));
+        assert!(!datetimes.insert(DateTime::new(
+            Date::from_yo(2019, 60),
+            Time::from_hms(12, 0, 0)
+        )));
+        assert!(!datetimes.insert(ymd!(2019, 2, 28).with_hms(12, 0, 0) + 1.days()));
+        assert_eq!(
+            DateTime::parse("2019-03-01 12:00:00", "%F %T")
+                .map(|datetime| datetimes.insert(datetime)),
+            Ok(false)
+        );
+        assert_eq!(datetimes.len(), 1);
+
+        assert!(datetimes.insert(ymd!(2019, 3, 1).with_hms(12, 0, 1)));
+        assert!(datetimes.insert(ymd!(2019, 3, 2).with_hms(12, 0, 0)));
+        assert_eq!(datetimes.len(), 3);
+    }
+
     #[test]
     fn ord() {
         use Ordering::*;
@@ -1,12 +1,16 @@
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 #[cfg(feature = "std")]
 use crate::Instant;
 use crate::{
-    NumberExt, OutOfRangeError,
+    format::parse::{try_consume_char, ParseResult},
+    Language, NumberExt, OutOfRangeError, ParseError, ParseErrorAt,
     Sign::{self, Negative, Positive, Zero},
 };
 use core::{
     cmp::Ordering::{self, Equal, Greater, Less},
     convert::{From, TryFrom},
+    fmt::Write,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     time::Duration as StdDuration,
 };
@@ -237,13 +241,37 @@ impl Duration {
         self.sign
     }
 
+    /// Get the sign of the duration as a number: `1` if positive, `-1` if
+    /// negative, and `0` if zero.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::seconds(1).signum(), 1);
+    /// assert_eq!(Duration::seconds(-1).signum(), -1);
+    /// assert_eq!(Duration::zero().signum(), 0);
+    /// ```
+    #[inline(always)]
+    pub const fn signum(self) -> i8 {
+        self.sign as i8
+    }
+
     /// Get the absolute value of the duration.
     ///
+    /// As the magnitude is stored separately from the sign, this cannot
+    /// overflow. Unlike `i64::abs`, the absolute value of the most negative
+    /// duration is the largest positive one, such that
+    /// `Duration::seconds(i64::min_value()).abs()` is one second longer than
+    /// `Duration::seconds(i64::max_value())`.
+    ///
     /// ```rust
     /// # use time::Duration;
     /// assert_eq!(Duration::seconds(1).abs(), Duration::seconds(1));
     /// assert_eq!(Duration::zero().abs(), Duration::zero());
     /// assert_eq!(Duration::seconds(-1).abs(), Duration::seconds(1));
+    /// assert_eq!(
+    ///     Duration::seconds(i64::min_value()).abs().whole_nanoseconds(),
+    ///     Duration::seconds(i64::max_value()).whole_nanoseconds() + 1_000_000_000
+    /// );
     /// ```
     #[inline(always)]
     pub fn abs(self) -> Self {
@@ -257,18 +285,55 @@ impl Duration {
     /// nanoseconds is at least 10<sup>9</sup>, it will wrap to the number of
     /// seconds.
     ///
+    /// The nanoseconds are always added to the seconds, regardless of the sign
+    /// of the seconds. A negative number of seconds with a nonzero number of
+    /// nanoseconds is therefore closer to zero than the seconds alone.
+    ///
     /// ```rust
     /// # use time::Duration;
     /// assert_eq!(Duration::new(1, 0), Duration::seconds(1));
     /// assert_eq!(Duration::new(-1, 0), Duration::seconds(-1));
     /// assert_eq!(Duration::new(1, 2_000_000_000), Duration::seconds(3));
+    /// assert_eq!(Duration::new(-1, 500_000_000), Duration::milliseconds(-500));
     /// ```
     #[inline(always)]
     pub fn new(seconds: i64, nanoseconds: u32) -> Self {
-        Self {
-            sign: (seconds * 1_000_000_000 + nanoseconds as i64).sign(),
-            std: StdDuration::new(seconds.abs() as u64, nanoseconds),
-        }
+        Self::seconds(seconds) + Self::nanoseconds(i64::from(nanoseconds))
+    }
+
+    /// Create a new `Duration` from the sum of the provided hours, minutes, and
+    /// seconds. The components may have differing signs. Equivalent to
+    /// `Duration::seconds(hours * 3_600 + minutes * 60 + seconds)`.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::from_hms(1, 30, 15), Duration::seconds(5_415));
+    /// assert_eq!(Duration::from_hms(-1, 30, 0), Duration::minutes(-30));
+    /// ```
+    #[inline(always)]
+    pub fn from_hms(hours: i64, minutes: i64, seconds: i64) -> Self {
+        Self::seconds(hours * SECONDS_PER_HOUR + minutes * SECONDS_PER_MINUTE + seconds)
+    }
+
+    /// Create a new `Duration` from the sum of the provided hours, minutes, and
+    /// seconds, returning `None` if the total number of seconds overflows.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(
+    ///     Duration::try_from_hms(1, 30, 15),
+    ///     Some(Duration::seconds(5_415))
+    /// );
+    /// assert_eq!(Duration::try_from_hms(i64::max_value(), 0, 0), None);
+    /// ```
+    #[inline]
+    pub fn try_from_hms(hours: i64, minutes: i64, seconds: i64) -> Option<Self> {
+        let seconds = hours
+            .checked_mul(SECONDS_PER_HOUR)?
+            .checked_add(minutes.checked_mul(SECONDS_PER_MINUTE)?)?
+            .checked_add(seconds)?;
+
+        Some(Self::seconds(seconds))
     }
 
     /// Create a new positive `Duration` from a `std::time::Duration`.
@@ -289,6 +354,18 @@ impl Duration {
         }
     }
 
+    /// Create a `Duration` from its sign and magnitude, ensuring that a zero
+    /// magnitude always has a zero sign.
+    #[inline(always)]
+    pub(crate) fn from_sign_and_std(sign: Sign, std: StdDuration) -> Self {
+        let duration = Self::from(std);
+        if sign.is_negative() {
+            -duration
+        } else {
+            duration
+        }
+    }
+
     /// Create a new `Duration` with the given number of weeks. Equivalent to
     /// `Duration::seconds(weeks * 604_800)`.
     ///
@@ -403,7 +480,8 @@ impl Duration {
     pub fn seconds(seconds: i64) -> Self {
         Self {
             sign: seconds.sign(),
-            std: StdDuration::from_secs(seconds.abs() as u64),
+            // `wrapping_abs` is correct for `i64::min_value()` once cast to `u64`.
+            std: StdDuration::from_secs(seconds.wrapping_abs() as u64),
         }
     }
 
@@ -431,10 +509,7 @@ impl Duration {
     /// ```
     #[inline(always)]
     pub fn seconds_f64(seconds: f64) -> Self {
-        Self {
-            sign: seconds.sign(),
-            std: StdDuration::from_secs_f64(seconds.abs()),
-        }
+        Self::from_sign_and_std(seconds.sign(), StdDuration::from_secs_f64(seconds.abs()))
     }
 
     /// Get the number of fractional seconds in the duration.
@@ -459,10 +534,7 @@ impl Duration {
     /// ```
     #[inline(always)]
     pub fn seconds_f32(seconds: f32) -> Self {
-        Self {
-            sign: seconds.sign(),
-            std: StdDuration::from_secs_f32(seconds.abs()),
-        }
+        Self::from_sign_and_std(seconds.sign(), StdDuration::from_secs_f32(seconds.abs()))
     }
 
     /// Get the number of fractional seconds in the duration.
@@ -488,7 +560,7 @@ impl Duration {
     pub fn milliseconds(milliseconds: i64) -> Self {
         Self {
             sign: milliseconds.sign(),
-            std: StdDuration::from_millis(milliseconds.abs() as u64),
+            std: StdDuration::from_millis(milliseconds.wrapping_abs() as u64),
         }
     }
 
@@ -536,7 +608,7 @@ impl Duration {
     pub fn microseconds(microseconds: i64) -> Self {
         Self {
             sign: microseconds.sign(),
-            std: StdDuration::from_micros(microseconds.abs() as u64),
+            std: StdDuration::from_micros(microseconds.wrapping_abs() as u64),
         }
     }
 
@@ -588,7 +660,7 @@ impl Duration {
     pub fn nanoseconds(nanoseconds: i64) -> Self {
         Self {
             sign: nanoseconds.sign(),
-            std: StdDuration::from_nanos(nanoseconds.abs() as u64),
+            std: StdDuration::from_nanos(nanoseconds.wrapping_abs() as u64),
         }
     }
 
@@ -705,10 +777,10 @@ impl Duration {
     /// ```
     #[inline(always)]
     pub fn checked_mul(self, rhs: i32) -> Option<Self> {
-        Some(Self {
-            sign: self.sign * rhs.sign(),
-            std: self.std.checked_mul(rhs.abs() as u32)?,
-        })
+        // `wrapping_abs` is correct for `i32::min_value()` once cast to `u32`.
+        #[allow(clippy::cast_sign_loss)]
+        let std = self.std.checked_mul(rhs.wrapping_abs() as u32)?;
+        Some(Self::from_sign_and_std(self.sign * rhs.sign(), std))
     }
 
     /// Computes `self / rhs`, returning `None` if `rhs == 0`.
@@ -718,14 +790,480 @@ impl Duration {
     /// assert_eq!(Duration::seconds(10).checked_div(2), Some(Duration::seconds(5)));
     /// assert_eq!(Duration::seconds(10).checked_div(-2), Some(Duration::seconds(-5)));
     /// assert_eq!(Duration::seconds(1).checked_div(0), None);
+    /// assert_eq!(Duration::nanoseconds(1).checked_div(2), Some(Duration::zero()));
+    /// ```
     #[inline(always)]
     pub fn checked_div(self, rhs: i32) -> Option<Self> {
-        Some(Self {
-            sign: self.sign * rhs.sign(),
-            std: self.std.checked_div(rhs.abs() as u32)?,
+        // `wrapping_abs` is correct for `i32::min_value()` once cast to `u32`.
+        #[allow(clippy::cast_sign_loss)]
+        let std = self.std.checked_div(rhs.wrapping_abs() as u32)?;
+        Some(Self::from_sign_and_std(self.sign * rhs.sign(), std))
+    }
+
+    /// Computes `self * rhs`, saturating at the bounds of `Duration` if an
+    /// overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::seconds(5).saturating_mul(2), Duration::seconds(10));
+    /// assert_eq!(Duration::seconds(5).saturating_mul(-2), Duration::seconds(-10));
+    /// assert_eq!(
+    ///     Duration::seconds(i64::max_value()).saturating_mul(i32::max_value()),
+    ///     Duration::max_value()
+    /// );
+    /// assert_eq!(
+    ///     Duration::seconds(i64::max_value()).saturating_mul(i32::min_value()),
+    ///     Duration::min_value()
+    /// );
+    /// ```
+    #[inline(always)]
+    #[allow(deprecated)]
+    pub fn saturating_mul(self, rhs: i32) -> Self {
+        self.checked_mul(rhs).unwrap_or_else(|| {
+            if (self.sign * rhs.sign()).is_negative() {
+                Self::min_value()
+            } else {
+                Self::max_value()
+            }
         })
     }
 
+    /// Split the `Duration` into its whole seconds and remaining nanoseconds,
+    /// as used by `timespec` and similar FFI types.
+    ///
+    /// Both components always have the same sign as the `Duration`, and the
+    /// nanoseconds are always in the range `-999_999_999..=999_999_999`. Note
+    /// that this differs from POSIX `timespec`, where `tv_nsec` must be
+    /// non-negative; for a negative duration, subtract one from the seconds
+    /// and add `1_000_000_000` to the nanoseconds to obtain that form.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::milliseconds(1_500).to_timespec_parts(), (1, 500_000_000));
+    /// assert_eq!(Duration::milliseconds(-1_500).to_timespec_parts(), (-1, -500_000_000));
+    /// ```
+    // Allow the lint, as the value is guaranteed to be less than 1_000_000_000.
+    #[inline(always)]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn to_timespec_parts(self) -> (i64, i32) {
+        (
+            self.whole_seconds(),
+            self.sign * self.subsec_nanoseconds() as i32,
+        )
+    }
+
+    /// Get the smaller of two durations.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::seconds(1).min(Duration::seconds(2)), Duration::seconds(1));
+    /// assert_eq!(Duration::seconds(-1).min(Duration::seconds(-2)), Duration::seconds(-2));
+    /// ```
+    #[inline(always)]
+    pub fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Get the larger of two durations.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::seconds(1).max(Duration::seconds(2)), Duration::seconds(2));
+    /// assert_eq!(Duration::seconds(-1).max(Duration::seconds(-2)), Duration::seconds(-1));
+    /// ```
+    #[inline(always)]
+    pub fn max(self, other: Self) -> Self {
+        if other < self {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Format the `Duration` as a clock, `HH:MM:SS`. The number of hours is
+    /// not limited to 24, and negative durations are prefixed with a `-`. If
+    /// the duration has a subsecond component, milliseconds are appended as
+    /// `.fff`.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::seconds(90).format_clock(), "00:01:30");
+    /// assert_eq!(Duration::hours(100).format_clock(), "100:00:00");
+    /// assert_eq!(Duration::seconds(-3_661).format_clock(), "-01:01:01");
+    /// assert_eq!(Duration::milliseconds(1_500).format_clock(), "00:00:01.500");
+    /// ```
+    #[inline]
+    pub fn format_clock(self) -> String {
+        let seconds = self.std.as_secs();
+        let mut s = String::new();
+
+        if self.is_negative() {
+            s.push('-');
+        }
+
+        // Writing to a `String` cannot fail.
+        let _ = write!(
+            s,
+            "{:02}:{:02}:{:02}",
+            seconds / 3_600,
+            seconds / 60 % 60,
+            seconds % 60
+        );
+
+        if self.subsec_nanoseconds() != 0 {
+            let _ = write!(s, ".{:03}", self.subsec_milliseconds());
+        }
+
+        s
+    }
+
+    /// Format the `Duration` using the provided string. Only `%H` (hours), `%M`
+    /// (minutes), `%S` (seconds), and `%f` or `%N` (subseconds) are supported,
+    /// along with their modifiers.
+    ///
+    /// The largest unit present holds the total of that unit, and is not
+    /// limited to its usual range. The remaining units hold what is left over
+    /// after the next larger unit present. Negative durations are prefixed with
+    /// a `-`.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::minutes(90).format("%H:%M:%S"), "01:30:00");
+    /// assert_eq!(Duration::minutes(90).format("%M:%S"), "90:00");
+    /// assert_eq!(Duration::hours(100).format("%Hh %Mm"), "100h 00m");
+    /// assert_eq!(
+    ///     Duration::milliseconds(-1_500).format("%H:%M:%S.%3f"),
+    ///     "-00:00:01.500"
+    /// );
+    /// ```
+    ///
+    /// Panics if the format contains any other specifier.
+    #[inline(always)]
+    pub fn format(self, format: &str) -> String {
+        crate::format::duration::format(self, format)
+    }
+
+    /// Parse a `Duration` from the string using the provided format. The
+    /// specifiers are interpreted as in [`Duration::format`], and the string
+    /// may be prefixed with a `-` to indicate a negative duration.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ParseError};
+    /// assert_eq!(Duration::parse("01:30:00", "%H:%M:%S"), Ok(Duration::minutes(90)));
+    /// assert_eq!(Duration::parse("90:00", "%M:%S"), Ok(Duration::minutes(90)));
+    /// assert_eq!(
+    ///     Duration::parse("-00:00:01.5", "%H:%M:%S.%1f"),
+    ///     Ok(Duration::milliseconds(-1_500))
+    /// );
+    /// assert_eq!(
+    ///     Duration::parse("01:60:00", "%H:%M:%S"),
+    ///     Err(ParseError::InvalidMinute)
+    /// );
+    /// assert_eq!(
+    ///     Duration::parse("2019", "%Y"),
+    ///     Err(ParseError::InvalidFormatSpecifier('Y'))
+    /// );
+    /// ```
+    ///
+    /// Returns `ParseError::InvalidFormatSpecifier` if the format contains any
+    /// other specifier.
+    #[inline(always)]
+    pub fn parse(s: &str, format: &str) -> ParseResult<Self> {
+        crate::format::duration::parse(s, format)
+    }
+
+    /// Parse a `Duration` written as a sequence of whole numbers, each followed
+    /// by a unit, such as `1h 30m`. Whitespace is permitted between components,
+    /// and the entire value may be preceded by a `-`. The supported units are
+    /// `w`, `d`, `h`, `m`, `s`, `ms`, `us` (or `µs`), and `ns`.
+    ///
+    /// Any error includes the position in the input at which it occurred. An
+    /// unrecognized unit is reported at its start, while a component that
+    /// overflows is reported at the start of its number. The total must fit in
+    /// an `i64` number of nanoseconds.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ParseError};
+    /// assert_eq!(Duration::parse_humanized("1h 30m"), Ok(Duration::minutes(90)));
+    /// assert_eq!(Duration::parse_humanized("-1m30s"), Ok(Duration::seconds(-90)));
+    ///
+    /// let error = Duration::parse_humanized("1h 10x").unwrap_err();
+    /// assert_eq!(error.kind(), ParseError::InvalidUnit);
+    /// assert_eq!(error.index(), 5);
+    /// ```
+    #[inline]
+    pub fn parse_humanized(s: &str) -> Result<Self, ParseErrorAt> {
+        let error = |kind, remaining: &str| ParseErrorAt {
+            kind,
+            index: s.len() - remaining.len(),
+        };
+
+        let mut remaining = s;
+        let sign = if try_consume_char(&mut remaining, '-').is_ok() {
+            -1
+        } else {
+            1
+        };
+        let mut nanoseconds: i64 = 0;
+
+        loop {
+            let start = remaining;
+            let digits = remaining
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(remaining.len());
+            let value: i64 = match remaining[..digits].parse() {
+                Ok(value) => value,
+                Err(_) if remaining.is_empty() => {
+                    return Err(error(ParseError::UnexpectedEndOfString, remaining))
+                }
+                Err(_) => return Err(error(ParseError::InvalidDuration, remaining)),
+            };
+            remaining = remaining[digits..].trim_start();
+
+            let length = remaining
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(remaining.len());
+            let unit: i64 = match &remaining[..length] {
+                "w" => 604_800_000_000_000,
+                "d" => 86_400_000_000_000,
+                "h" => 3_600_000_000_000,
+                "m" => 60_000_000_000,
+                "s" => 1_000_000_000,
+                "ms" => 1_000_000,
+                "us" | "µs" => 1_000,
+                "ns" => 1,
+                "" if remaining.is_empty() => {
+                    return Err(error(ParseError::UnexpectedEndOfString, remaining))
+                }
+                _ => return Err(error(ParseError::InvalidUnit, remaining)),
+            };
+            remaining = remaining[length..].trim_start();
+
+            nanoseconds = value
+                .checked_mul(unit)
+                .and_then(|value| nanoseconds.checked_add(value))
+                .ok_or_else(|| error(ParseError::InvalidDuration, start))?;
+
+            if remaining.is_empty() {
+                return Ok(Self::nanoseconds(sign * nanoseconds));
+            }
+        }
+    }
+
+    /// Format the `Duration` as an ISO 8601 duration, such as `PT1H30M`.
+    ///
+    /// Whole days are written with the `D` designator, as years and months do
+    /// not have a fixed length. Any subsecond component is written as a
+    /// fraction of a second, without trailing zeros. Negative durations are
+    /// prefixed with a `-`, and a zero duration is written as `PT0S`.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::minutes(90).format_iso8601(), "PT1H30M");
+    /// assert_eq!(Duration::days(3).format_iso8601(), "P3D");
+    /// assert_eq!(Duration::milliseconds(-1_500).format_iso8601(), "-PT1.5S");
+    /// assert_eq!(Duration::zero().format_iso8601(), "PT0S");
+    /// ```
+    #[inline]
+    pub fn format_iso8601(self) -> String {
+        if self.is_zero() {
+            return String::from("PT0S");
+        }
+
+        let seconds = self.std.as_secs();
+        let (days, hours, minutes) = (seconds / 86_400, seconds / 3_600 % 24, seconds / 60 % 60);
+        let (seconds, nanoseconds) = (seconds % 60, self.std.subsec_nanos());
+
+        let mut s = String::new();
+        if self.is_negative() {
+            s.push('-');
+        }
+        s.push('P');
+
+        // Writing to a `String` cannot fail.
+        if days != 0 {
+            let _ = write!(s, "{}D", days);
+        }
+        if hours != 0 || minutes != 0 || seconds != 0 || nanoseconds != 0 {
+            s.push('T');
+        }
+        if hours != 0 {
+            let _ = write!(s, "{}H", hours);
+        }
+        if minutes != 0 {
+            let _ = write!(s, "{}M", minutes);
+        }
+        if nanoseconds != 0 {
+            let fraction = format!("{:09}", nanoseconds);
+            let _ = write!(s, "{}.{}S", seconds, fraction.trim_end_matches('0'));
+        } else if seconds != 0 {
+            let _ = write!(s, "{}S", seconds);
+        }
+
+        s
+    }
+
+    /// Parse an ISO 8601 duration, such as `PT1H30M` or `P3D`. The string may
+    /// be prefixed with a `-` to indicate a negative duration.
+    ///
+    /// The components `W`, `D`, `H`, `M` (following the `T`), and `S` are
+    /// supported, and must appear in that order. The number of seconds may
+    /// have a fractional part of up to nine digits, separated by either a `.`
+    /// or a `,`. Years and months are rejected with
+    /// [`ParseError::InvalidUnit`], as their length varies.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ParseError};
+    /// assert_eq!(Duration::parse_iso8601("PT1H30M"), Ok(Duration::minutes(90)));
+    /// assert_eq!(Duration::parse_iso8601("P3D"), Ok(Duration::days(3)));
+    /// assert_eq!(Duration::parse_iso8601("P2W"), Ok(Duration::weeks(2)));
+    /// assert_eq!(
+    ///     Duration::parse_iso8601("-PT1.5S"),
+    ///     Ok(Duration::milliseconds(-1_500))
+    /// );
+    /// assert_eq!(Duration::parse_iso8601("P1Y"), Err(ParseError::InvalidUnit));
+    /// ```
+    #[inline]
+    pub fn parse_iso8601(mut s: &str) -> ParseResult<Self> {
+        let s = &mut s;
+        let is_negative = try_consume_char(s, '-').is_ok();
+        try_consume_char(s, 'P')?;
+
+        let mut in_time = false;
+        let mut previous_unit = 0;
+        let mut seconds = 0_u64;
+        let mut nanoseconds = 0;
+
+        while !s.is_empty() {
+            if !in_time && try_consume_char(s, 'T').is_ok() {
+                in_time = true;
+                continue;
+            }
+
+            let digits = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+            let value: u64 = s[..digits]
+                .parse()
+                .map_err(|_| ParseError::InvalidDuration)?;
+            *s = &s[digits..];
+
+            let fraction = if in_time && (s.starts_with('.') || s.starts_with(',')) {
+                *s = &s[1..];
+                let digits = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+                if digits == 0 || digits > 9 {
+                    return Err(ParseError::InvalidSubsecond);
+                }
+                let fraction: u32 = s[..digits]
+                    .parse()
+                    .map_err(|_| ParseError::InvalidSubsecond)?;
+                *s = &s[digits..];
+                #[allow(clippy::cast_possible_truncation)]
+                Some(fraction * 10_u32.pow(9 - digits as u32))
+            } else {
+                None
+            };
+
+            let (unit, size) = match (in_time, s.chars().next()) {
+                (false, Some('W')) => (1, 604_800),
+                (false, Some('D')) => (2, 86_400),
+                (true, Some('H')) => (3, 3_600),
+                (true, Some('M')) => (4, 60),
+                (true, Some('S')) => (5, 1),
+                (_, None) => return Err(ParseError::UnexpectedEndOfString),
+                (_, Some(_)) => return Err(ParseError::InvalidUnit),
+            };
+            *s = &s[1..];
+
+            // Components must be in order, and only seconds may be fractional.
+            if unit <= previous_unit || (fraction.is_some() && unit != 5) {
+                return Err(ParseError::InvalidDuration);
+            }
+            previous_unit = unit;
+
+            seconds = value
+                .checked_mul(size)
+                .and_then(|value| seconds.checked_add(value))
+                .ok_or(ParseError::InvalidDuration)?;
+            nanoseconds = fraction.unwrap_or(0);
+        }
+
+        // At least one component must be present, including after a `T`.
+        if previous_unit == 0 || (in_time && previous_unit < 3) {
+            return Err(ParseError::UnexpectedEndOfString);
+        }
+
+        let duration = Self::from(StdDuration::new(seconds, nanoseconds));
+        if is_negative {
+            Ok(-duration)
+        } else {
+            Ok(duration)
+        }
+    }
+
+    /// Format the `Duration` for display to a person, such as `2 hours 30
+    /// minutes`. The largest nonzero unit is used, followed by the next
+    /// smaller unit if it is also nonzero. Negative durations are prefixed
+    /// with a `-`.
+    ///
+    /// A year is treated as 365 days and a month as 30 days. Any subsecond
+    /// component is truncated, such that a duration of less than one second
+    /// is written as zero seconds.
+    ///
+    /// ```rust
+    /// # use time::{Duration, Language};
+    /// assert_eq!(Duration::minutes(150).humanize(Language::en), "2 hours 30 minutes");
+    /// assert_eq!(Duration::days(-1).humanize(Language::en), "-1 day");
+    /// assert_eq!(Duration::zero().humanize(Language::en), "0 seconds");
+    /// assert_eq!(Duration::minutes(150).humanize(Language::de), "2 Stunden 30 Minuten");
+    /// ```
+    #[inline]
+    pub fn humanize(self, language: Language) -> String {
+        /// The size of each unit in seconds, matching the order of
+        /// `Language::unit_names`.
+        const UNITS: [u64; 7] = [31_536_000, 2_592_000, 604_800, 86_400, 3_600, 60, 1];
+
+        let names = language.unit_names();
+        let mut remaining = self.std.as_secs();
+        let mut parts = vec![];
+        for (&size, name) in UNITS.iter().zip(names.iter()) {
+            let value = remaining / size;
+            remaining %= size;
+
+            // Only the largest nonzero unit and the one following it are used.
+            if value != 0 {
+                parts.push((value, name));
+            }
+            if parts.len() == 2 || (value == 0 && !parts.is_empty()) {
+                break;
+            }
+        }
+
+        let mut s = String::new();
+        if parts.is_empty() {
+            parts.push((0, &names[6]));
+        } else if self.is_negative() {
+            s.push('-');
+        }
+
+        for (i, &(value, &[singular, plural])) in parts.iter().enumerate() {
+            if i != 0 {
+                s.push(' ');
+            }
+            let name = if language.is_singular(value) {
+                singular
+            } else {
+                plural
+            };
+            // Writing to a `String` cannot fail.
+            let _ = write!(s, "{} {}", value, name);
+        }
+
+        s
+    }
+
     /// Runs a closure, returning the duration of time it took to run. The
     /// return value of the closure is provided in the second part of the tuple.
     ///
@@ -894,16 +1432,10 @@ impl TryFrom<Duration> for StdDuration {
 impl Add for Duration {
     type Output = Self;
 
-    #[inline]
+    #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        let secs = self.whole_seconds() + rhs.whole_seconds();
-        let nanos = self.subsec_nanoseconds() + rhs.subsec_nanoseconds();
-
-        if nanos >= 1_000_000_000 {
-            Self::new(secs + 1, nanos - 1_000_000_000)
-        } else {
-            Self::new(secs, nanos)
-        }
+        self.checked_add(rhs)
+            .expect("overflow when adding durations")
     }
 }
 
@@ -939,31 +1471,29 @@ impl AddAssign<StdDuration> for Duration {
     }
 }
 
+/// Negation never overflows. As the sign is stored separately from the
+/// magnitude, the range of `Duration` is symmetric, and negating
+/// `Duration::min_value()` results in `Duration::max_value()` (and vice versa)
+/// rather than needing to saturate.
 impl Neg for Duration {
     type Output = Self;
 
     #[inline(always)]
     fn neg(self) -> Self::Output {
-        -1 * self
+        Self {
+            sign: self.sign.negate(),
+            std: self.std,
+        }
     }
 }
 
 impl Sub for Duration {
     type Output = Self;
 
-    #[inline]
+    #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        let secs = self.whole_seconds() - rhs.whole_seconds();
-        let nanos = self.subsec_nanoseconds() as i32 - rhs.subsec_nanoseconds() as i32;
-
-        #[allow(clippy::cast_sign_loss)]
-        {
-            if nanos < 0 {
-                Self::new(secs - 1, (nanos + 1_000_000_000) as u32)
-            } else {
-                Self::new(secs, nanos as u32)
-            }
-        }
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting durations")
     }
 }
 
@@ -1020,14 +1550,12 @@ macro_rules! duration_mul_div {
                 #[inline(always)]
                 #[allow(trivial_numeric_casts)]
                 fn mul(self, rhs: $type) -> Self::Output {
-                    Self {
-                        sign: match rhs.cmp(&0) {
-                            Equal => return Self::zero(),
-                            Greater => self.sign,
-                            Less => self.sign.negate(),
-                        },
-                        std: self.std * rhs.abs() as u32,
-                    }
+                    let sign = match rhs.cmp(&0) {
+                        Equal => return Self::zero(),
+                        Greater => self.sign,
+                        Less => self.sign.negate(),
+                    };
+                    Self::from_sign_and_std(sign, self.std * rhs.abs() as u32)
                 }
             }
 
@@ -1053,14 +1581,12 @@ macro_rules! duration_mul_div {
                 #[inline(always)]
                 #[allow(trivial_numeric_casts)]
                 fn div(self, rhs: $type) -> Self::Output {
-                    Self {
-                        sign: match rhs.cmp(&0) {
-                            Equal => return Self::zero(),
-                            Greater => self.sign,
-                            Less => self.sign.negate(),
-                        },
-                        std: self.std / rhs.abs() as u32,
-                    }
+                    let sign = match rhs.cmp(&0) {
+                        Equal => return Self::zero(),
+                        Greater => self.sign,
+                        Less => self.sign.negate(),
+                    };
+                    Self::from_sign_and_std(sign, self.std / rhs.abs() as u32)
                 }
             }
 
@@ -1080,10 +1606,7 @@ impl Mul<f32> for Duration {
 
     #[inline(always)]
     fn mul(self, rhs: f32) -> Self::Output {
-        Self {
-            sign: self.sign * rhs.sign(),
-            std: self.std.mul_f32(rhs.abs()),
-        }
+        Self::from_sign_and_std(self.sign * rhs.sign(), self.std.mul_f32(rhs.abs()))
     }
 }
 
@@ -1108,10 +1631,7 @@ impl Mul<f64> for Duration {
 
     #[inline(always)]
     fn mul(self, rhs: f64) -> Self::Output {
-        Self {
-            sign: self.sign * rhs.sign(),
-            std: self.std.mul_f64(rhs.abs()),
-        }
+        Self::from_sign_and_std(self.sign * rhs.sign(), self.std.mul_f64(rhs.abs()))
     }
 }
 
@@ -1136,10 +1656,7 @@ impl Div<f32> for Duration {
 
     #[inline(always)]
     fn div(self, rhs: f32) -> Self::Output {
-        Self {
-            sign: self.sign * rhs.sign(),
-            std: self.std.div_f32(rhs.abs()),
-        }
+        Self::from_sign_and_std(self.sign * rhs.sign(), self.std.div_f32(rhs.abs()))
     }
 }
 
@@ -1155,10 +1672,7 @@ impl Div<f64> for Duration {
 
     #[inline(always)]
     fn div(self, rhs: f64) -> Self::Output {
-        Self {
-            sign: self.sign * rhs.sign(),
-            std: self.std.div_f64(rhs.abs()),
-        }
+        Self::from_sign_and_std(self.sign * rhs.sign(), self.std.div_f64(rhs.abs()))
     }
 }
 
@@ -1261,6 +1775,203 @@ mod test {
     use super::*;
     use crate::prelude::*;
 
+    #[test]
+    fn format_iso8601() {
+        assert_eq!(Duration::zero().format_iso8601(), "PT0S");
+        assert_eq!(1.seconds().format_iso8601(), "PT1S");
+        assert_eq!(90.minutes().format_iso8601(), "PT1H30M");
+        assert_eq!(3.days().format_iso8601(), "P3D");
+        assert_eq!(2.weeks().format_iso8601(), "P14D");
+        assert_eq!(
+            (1.days() + 2.hours() + 3.minutes() + 4.seconds()).format_iso8601(),
+            "P1DT2H3M4S"
+        );
+        assert_eq!((1.days() + 5.seconds()).format_iso8601(), "P1DT5S");
+        assert_eq!(1_500.milliseconds().format_iso8601(), "PT1.5S");
+        assert_eq!(1.nanoseconds().format_iso8601(), "PT0.000000001S");
+        assert_eq!((-90).minutes().format_iso8601(), "-PT1H30M");
+    }
+
+    #[test]
+    fn parse_iso8601() {
+        assert_eq!(Duration::parse_iso8601("PT0S"), Ok(Duration::zero()));
+        assert_eq!(Duration::parse_iso8601("P0D"), Ok(Duration::zero()));
+        assert_eq!(Duration::parse_iso8601("PT1H30M"), Ok(90.minutes()));
+        assert_eq!(Duration::parse_iso8601("PT90M"), Ok(90.minutes()));
+        assert_eq!(Duration::parse_iso8601("P3D"), Ok(3.days()));
+        assert_eq!(Duration::parse_iso8601("P2W"), Ok(2.weeks()));
+        assert_eq!(Duration::parse_iso8601("P1W1D"), Ok(8.days()));
+        assert_eq!(
+            Duration::parse_iso8601("P1DT2H3M4S"),
+            Ok(1.days() + 2.hours() + 3.minutes() + 4.seconds())
+        );
+        assert_eq!(Duration::parse_iso8601("PT1.5S"), Ok(1_500.milliseconds()));
+        assert_eq!(Duration::parse_iso8601("PT1,5S"), Ok(1_500.milliseconds()));
+        assert_eq!(
+            Duration::parse_iso8601("PT0.000000001S"),
+            Ok(1.nanoseconds())
+        );
+        assert_eq!(Duration::parse_iso8601("-PT1M"), Ok((-1).minutes()));
+
+        assert_eq!(Duration::parse_iso8601("P1Y"), Err(ParseError::InvalidUnit));
+        assert_eq!(Duration::parse_iso8601("P1M"), Err(ParseError::InvalidUnit));
+        assert_eq!(
+            Duration::parse_iso8601("PT1D"),
+            Err(ParseError::InvalidUnit)
+        );
+        assert_eq!(Duration::parse_iso8601("P1H"), Err(ParseError::InvalidUnit));
+        assert_eq!(Duration::parse_iso8601("P1X"), Err(ParseError::InvalidUnit));
+        assert_eq!(
+            Duration::parse_iso8601("PT1M1H"),
+            Err(ParseError::InvalidDuration)
+        );
+        assert_eq!(
+            Duration::parse_iso8601("PT1.5M"),
+            Err(ParseError::InvalidDuration)
+        );
+        assert_eq!(
+            Duration::parse_iso8601("PT1.S"),
+            Err(ParseError::InvalidSubsecond)
+        );
+        assert_eq!(
+            Duration::parse_iso8601("PT0.0000000001S"),
+            Err(ParseError::InvalidSubsecond)
+        );
+        assert_eq!(
+            Duration::parse_iso8601("PTH"),
+            Err(ParseError::InvalidDuration)
+        );
+        assert_eq!(
+            Duration::parse_iso8601("P99999999999999999999D"),
+            Err(ParseError::InvalidDuration)
+        );
+        assert_eq!(
+            Duration::parse_iso8601("1H"),
+            Err(ParseError::UnexpectedCharacter {
+                expected: 'P',
+                actual: '1'
+            })
+        );
+        assert_eq!(
+            Duration::parse_iso8601(""),
+            Err(ParseError::UnexpectedEndOfString)
+        );
+        assert_eq!(
+            Duration::parse_iso8601("P"),
+            Err(ParseError::UnexpectedEndOfString)
+        );
+        assert_eq!(
+            Duration::parse_iso8601("PT"),
+            Err(ParseError::UnexpectedEndOfString)
+        );
+        assert_eq!(
+            Duration::parse_iso8601("P1DT"),
+            Err(ParseError::UnexpectedEndOfString)
+        );
+        assert_eq!(
+            Duration::parse_iso8601("PT1"),
+            Err(ParseError::UnexpectedEndOfString)
+        );
+    }
+
+    #[test]
+    fn iso8601_round_trip() {
+        for &duration in &[
+            Duration::zero(),
+            1.nanoseconds(),
+            (-1).nanoseconds(),
+            1_500.milliseconds(),
+            90.minutes(),
+            (-3).days(),
+            1.days() + 1.nanoseconds(),
+            Duration::seconds(i64::max_value()),
+        ] {
+            assert_eq!(
+                Duration::parse_iso8601(&duration.format_iso8601()),
+                Ok(duration)
+            );
+        }
+    }
+
+    #[test]
+    fn humanize() {
+        use Language::{de, en, es, fr, pt};
+
+        assert_eq!(Duration::zero().humanize(en), "0 seconds");
+        assert_eq!(999.milliseconds().humanize(en), "0 seconds");
+        assert_eq!((-999).milliseconds().humanize(en), "0 seconds");
+        assert_eq!(1.seconds().humanize(en), "1 second");
+        assert_eq!(59.seconds().humanize(en), "59 seconds");
+        assert_eq!(61.seconds().humanize(en), "1 minute 1 second");
+        assert_eq!(150.minutes().humanize(en), "2 hours 30 minutes");
+        assert_eq!((-150).minutes().humanize(en), "-2 hours 30 minutes");
+        assert_eq!((2.hours() + 5.seconds()).humanize(en), "2 hours");
+        assert_eq!(1.days().humanize(en), "1 day");
+        assert_eq!(
+            (1.days() + 1.hours() + 1.minutes()).humanize(en),
+            "1 day 1 hour"
+        );
+        assert_eq!(2.weeks().humanize(en), "2 weeks");
+        assert_eq!(45.days().humanize(en), "1 month 2 weeks");
+        assert_eq!(400.days().humanize(en), "1 year 1 month");
+        assert_eq!(730.days().humanize(en), "2 years");
+
+        assert_eq!(Duration::zero().humanize(de), "0 Sekunden");
+        assert_eq!(1.days().humanize(de), "1 Tag");
+        assert_eq!(150.minutes().humanize(de), "2 Stunden 30 Minuten");
+        assert_eq!(Duration::zero().humanize(es), "0 segundos");
+        assert_eq!(400.days().humanize(es), "1 año 1 mes");
+        assert_eq!(60.days().humanize(es), "2 meses");
+        assert_eq!(Duration::zero().humanize(fr), "0 seconde");
+        assert_eq!(60.days().humanize(fr), "2 mois");
+        assert_eq!(730.days().humanize(fr), "2 ans");
+        assert_eq!(Duration::zero().humanize(pt), "0 segundos");
+        assert_eq!(60.days().humanize(pt), "2 meses");
+    }
+
+    #[test]
+    fn parse_humanized() {
+        assert_eq!(Duration::parse_humanized("0s"), Ok(Duration::zero()));
+        assert_eq!(Duration::parse_humanized("90s"), Ok(90.seconds()));
+        assert_eq!(Duration::parse_humanized("1h 30m"), Ok(90.minutes()));
+        assert_eq!(Duration::parse_humanized("1h30m"), Ok(90.minutes()));
+        assert_eq!(Duration::parse_humanized("1 h 30 m"), Ok(90.minutes()));
+        assert_eq!(Duration::parse_humanized("2w 1d"), Ok(15.days()));
+        assert_eq!(
+            Duration::parse_humanized("1s 500ms 20us 3µs 7ns"),
+            Ok(1_500_023_007.nanoseconds())
+        );
+        assert_eq!(Duration::parse_humanized("-1m30s"), Ok((-90).seconds()));
+        assert_eq!(Duration::parse_humanized("1m 1m"), Ok(2.minutes()));
+
+        let error = |s| Duration::parse_humanized(s).map_err(|error| (error.kind(), error.index()));
+        assert_eq!(error("10x"), Err((ParseError::InvalidUnit, 2)));
+        assert_eq!(error("1h 10x"), Err((ParseError::InvalidUnit, 5)));
+        assert_eq!(error("1h 10 min"), Err((ParseError::InvalidUnit, 6)));
+        assert_eq!(error("1h 2"), Err((ParseError::UnexpectedEndOfString, 4)));
+        assert_eq!(error("1h 2 3s"), Err((ParseError::InvalidUnit, 5)));
+        assert_eq!(error("h"), Err((ParseError::InvalidDuration, 0)));
+        assert_eq!(error("1h,30m"), Err((ParseError::InvalidDuration, 2)));
+        assert_eq!(error(""), Err((ParseError::UnexpectedEndOfString, 0)));
+        assert_eq!(error("-"), Err((ParseError::UnexpectedEndOfString, 1)));
+        assert_eq!(error("1h -1m"), Err((ParseError::InvalidDuration, 3)));
+        assert_eq!(
+            error("1s 99999999999999999999s"),
+            Err((ParseError::InvalidDuration, 3))
+        );
+        assert_eq!(
+            error("1s 9999999999s"),
+            Err((ParseError::InvalidDuration, 3))
+        );
+
+        // The offending unit can be recovered from the index.
+        let input = "5m 3hrs";
+        let index = Duration::parse_humanized(input)
+            .map_err(ParseErrorAt::index)
+            .err();
+        assert_eq!(index.map(|index| &input[index..]), Some("hrs"));
+    }
+
     #[test]
     fn unit_values() {
         assert_eq!(Duration::zero(), 0.seconds());
@@ -1307,6 +2018,37 @@ mod test {
         assert_eq!(1.seconds().abs(), Duration::seconds(1));
         assert_eq!(0.seconds().abs(), Duration::zero());
         assert_eq!((-1).seconds().abs(), Duration::seconds(1));
+        assert_eq!(
+            Duration::seconds(i64::min_value())
+                .abs()
+                .whole_nanoseconds(),
+            -Duration::seconds(i64::min_value()).whole_nanoseconds()
+        );
+    }
+
+    #[test]
+    fn constructors_at_minimum() {
+        assert_eq!(
+            Duration::milliseconds(i64::min_value()).whole_milliseconds(),
+            i64::min_value().into()
+        );
+        assert_eq!(
+            Duration::microseconds(i64::min_value()).whole_microseconds(),
+            i64::min_value().into()
+        );
+        assert_eq!(
+            Duration::nanoseconds(i64::min_value()).whole_nanoseconds(),
+            i64::min_value().into()
+        );
+    }
+
+    #[test]
+    fn signum() {
+        assert_eq!(1.seconds().signum(), 1);
+        assert_eq!((-1).seconds().signum(), -1);
+        assert_eq!(0.seconds().signum(), 0);
+        assert_eq!(1.nanoseconds().signum(), 1);
+        assert_eq!((-1).nanoseconds().signum(), -1);
     }
 
     #[test]
@@ -1319,6 +2061,69 @@ mod test {
         assert_eq!(Duration::new(0, 1_000_000_000).sign(), Positive);
         assert_eq!(Duration::new(-1, 1_000_000_000).sign(), Zero);
         assert_eq!(Duration::new(-2, 1_000_000_000).sign(), Negative);
+
+        // The nanoseconds are added to the (possibly negative) seconds.
+        assert_eq!(Duration::new(-1, 1_000_000_000), Duration::zero());
+        assert_eq!(Duration::new(-1, 500_000_000), (-500).milliseconds());
+        assert_eq!(Duration::new(-2, 1_000_000_000), (-1).seconds());
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(Duration::new(1, 2_000_000_000), Duration::new(3, 0));
+        assert_eq!(Duration::new(-1, 2_000_000_000), Duration::new(1, 0));
+
+        // A zero magnitude always has a zero sign, however it was produced.
+        for &duration in &[
+            Duration::new(-1, 1_000_000_000),
+            1.nanoseconds() / 2,
+            (-1).nanoseconds() / 2,
+            1.nanoseconds() * 0.1,
+            (-1).nanoseconds() * 0.1_f32,
+            1.nanoseconds() / 3.0,
+            (-1).nanoseconds() / 3.0_f32,
+            Duration::seconds_f64(1e-12),
+            Duration::seconds_f32(-1e-12),
+            1.seconds() - 1.seconds(),
+            (-1).seconds() + 1.seconds(),
+        ] {
+            assert_eq!(duration, Duration::zero());
+            assert_eq!(duration.sign(), Zero);
+        }
+    }
+
+    #[test]
+    fn from_hms() {
+        assert_eq!(Duration::from_hms(0, 0, 0), Duration::zero());
+        assert_eq!(Duration::from_hms(1, 30, 15), 5_415.seconds());
+        assert_eq!(Duration::from_hms(-1, -30, -15), (-5_415).seconds());
+        assert_eq!(Duration::from_hms(1, -30, 0), 30.minutes());
+        assert_eq!(Duration::from_hms(0, 0, 90), 90.seconds());
+        assert_eq!(Duration::from_hms(48, 0, 0), 2.days());
+    }
+
+    #[test]
+    fn try_from_hms() {
+        assert_eq!(Duration::try_from_hms(1, 30, 15), Some(5_415.seconds()));
+        assert_eq!(
+            Duration::try_from_hms(-1, -30, -15),
+            Some((-5_415).seconds())
+        );
+        assert_eq!(
+            Duration::try_from_hms(0, 0, i64::max_value()),
+            Some(i64::max_value().seconds())
+        );
+        assert_eq!(Duration::try_from_hms(i64::max_value(), 0, 0), None);
+        assert_eq!(Duration::try_from_hms(0, i64::min_value(), 0), None);
+        assert_eq!(Duration::try_from_hms(0, 1, i64::max_value()), None);
+        assert_eq!(
+            Duration::try_from_hms(0, 0, i64::min_value()),
+            Some(Duration::seconds(i64::min_value()))
+        );
+        assert_eq!(
+            Duration::try_from_hms(0, -1, i64::min_value() + 60),
+            Some(Duration::seconds(i64::min_value()))
+        );
     }
 
     #[test]
@@ -1537,6 +2342,11 @@ mod test {
         assert_eq!(5.seconds().checked_mul(0), Some(Duration::zero()));
         assert_eq!(Duration::max_value().checked_mul(2), None);
         assert_eq!(Duration::min_value().checked_mul(2), None);
+        assert_eq!(
+            1.seconds().checked_mul(i32::min_value()),
+            Some(i64::from(i32::min_value()).seconds())
+        );
+        assert_eq!((-1).seconds().checked_mul(0), Some(Duration::zero()));
     }
 
     #[test]
@@ -1544,6 +2354,226 @@ mod test {
         assert_eq!(10.seconds().checked_div(2), Some(5.seconds()));
         assert_eq!(10.seconds().checked_div(-2), Some((-5).seconds()));
         assert_eq!(1.seconds().checked_div(0), None);
+        assert_eq!(1.nanoseconds().checked_div(2), Some(Duration::zero()));
+        assert_eq!((-1).nanoseconds().checked_div(2), Some(Duration::zero()));
+        assert_eq!(
+            i64::from(i32::min_value())
+                .seconds()
+                .checked_div(i32::min_value()),
+            Some(1.seconds())
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn saturating_mul() {
+        assert_eq!(5.seconds().saturating_mul(2), 10.seconds());
+        assert_eq!(5.seconds().saturating_mul(-2), (-10).seconds());
+        assert_eq!((-5).seconds().saturating_mul(-2), 10.seconds());
+        assert_eq!(5.seconds().saturating_mul(0), Duration::zero());
+        assert_eq!(
+            Duration::max_value().saturating_mul(2),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::max_value().saturating_mul(-2),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::min_value().saturating_mul(2),
+            Duration::min_value()
+        );
+        assert_eq!(
+            Duration::min_value().saturating_mul(-2),
+            Duration::max_value()
+        );
+    }
+
+    #[test]
+    fn to_timespec_parts() {
+        assert_eq!(0.seconds().to_timespec_parts(), (0, 0));
+        assert_eq!(1.seconds().to_timespec_parts(), (1, 0));
+        assert_eq!((-1).seconds().to_timespec_parts(), (-1, 0));
+        assert_eq!(1.nanoseconds().to_timespec_parts(), (0, 1));
+        assert_eq!((-1).nanoseconds().to_timespec_parts(), (0, -1));
+        assert_eq!(1_500.milliseconds().to_timespec_parts(), (1, 500_000_000));
+        assert_eq!(
+            (-1_500).milliseconds().to_timespec_parts(),
+            (-1, -500_000_000)
+        );
+        assert_eq!(
+            Duration::new(5, 999_999_999).to_timespec_parts(),
+            (5, 999_999_999)
+        );
+        assert_eq!(
+            (-Duration::new(5, 999_999_999)).to_timespec_parts(),
+            (-5, -999_999_999)
+        );
+    }
+
+    #[test]
+    fn min() {
+        assert_eq!(1.seconds().min(2.seconds()), 1.seconds());
+        assert_eq!(2.seconds().min(1.seconds()), 1.seconds());
+        assert_eq!((-1).seconds().min((-2).seconds()), (-2).seconds());
+        assert_eq!((-1).seconds().min(1.seconds()), (-1).seconds());
+        assert_eq!(0.seconds().min((-1).nanoseconds()), (-1).nanoseconds());
+        assert_eq!(5.seconds().min(5.seconds()), 5.seconds());
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(1.seconds().max(2.seconds()), 2.seconds());
+        assert_eq!(2.seconds().max(1.seconds()), 2.seconds());
+        assert_eq!((-1).seconds().max((-2).seconds()), (-1).seconds());
+        assert_eq!((-1).seconds().max(1.seconds()), 1.seconds());
+        assert_eq!(0.seconds().max((-1).nanoseconds()), 0.seconds());
+        assert_eq!(5.seconds().max(5.seconds()), 5.seconds());
+    }
+
+    #[test]
+    fn format_clock() {
+        assert_eq!(0.seconds().format_clock(), "00:00:00");
+        assert_eq!(90.seconds().format_clock(), "00:01:30");
+        assert_eq!(59.minutes().format_clock(), "00:59:00");
+        assert_eq!(3_661.seconds().format_clock(), "01:01:01");
+        assert_eq!(100.hours().format_clock(), "100:00:00");
+        assert_eq!((-90).seconds().format_clock(), "-00:01:30");
+        assert_eq!((-3_661).seconds().format_clock(), "-01:01:01");
+        assert_eq!(1_500.milliseconds().format_clock(), "00:00:01.500");
+        assert_eq!((-1_005).milliseconds().format_clock(), "-00:00:01.005");
+        assert_eq!(1.nanoseconds().format_clock(), "00:00:00.000");
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(0.seconds().format("%H:%M:%S"), "00:00:00");
+        assert_eq!(90.minutes().format("%H:%M:%S"), "01:30:00");
+        assert_eq!(90.minutes().format("%-H:%M"), "1:30");
+        assert_eq!(90.minutes().format("%M:%S"), "90:00");
+        assert_eq!(90.minutes().format("%S"), "5400");
+        assert_eq!(100.hours().format("%H:%M:%S"), "100:00:00");
+        assert_eq!((-3_661).seconds().format("%H:%M:%S"), "-01:01:01");
+        assert_eq!(1_234_567_891.nanoseconds().format("%S%f"), "01.234567891");
+        assert_eq!(1_234_567_891.nanoseconds().format("%S.%3f"), "01.234");
+        assert_eq!(1_500.milliseconds().format("%S%f"), "01.5");
+        assert_eq!(1_500.milliseconds().format("%S.%N"), "01.500000000");
+        assert_eq!(1.seconds().format("%S%f"), "01");
+        assert_eq!(5.milliseconds().format("%S.%3N"), "00.005");
+        assert_eq!(90.seconds().format("%_M min"), " 1 min");
+
+        // Units hold what remains after the next larger unit present.
+        assert_eq!(3_725.seconds().format("%H:%S"), "01:125");
+        assert_eq!(3_725.seconds().format("%S %H"), "125 01");
+        assert_eq!(3_725.seconds().format("%H:%M"), "01:02");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Duration::parse("01:30:00", "%H:%M:%S"), Ok(90.minutes()));
+        assert_eq!(Duration::parse("1:30", "%-H:%M"), Ok(90.minutes()));
+        assert_eq!(Duration::parse("100:00:00", "%H:%M:%S"), Ok(100.hours()));
+        assert_eq!(Duration::parse("90:00", "%M:%S"), Ok(90.minutes()));
+        assert_eq!(Duration::parse("5400", "%S"), Ok(90.minutes()));
+        assert_eq!(
+            Duration::parse("-01:01:01", "%H:%M:%S"),
+            Ok((-3_661).seconds())
+        );
+        assert_eq!(
+            Duration::parse("00:00:01.500", "%H:%M:%S.%3f"),
+            Ok(1_500.milliseconds())
+        );
+        assert_eq!(Duration::parse("-0", "%S"), Ok(0.seconds()));
+
+        assert_eq!(
+            Duration::parse("01:60:00", "%H:%M:%S"),
+            Err(ParseError::InvalidMinute)
+        );
+        assert_eq!(
+            Duration::parse("01:00:7", "%H:%M:%S"),
+            Err(ParseError::InvalidSecond)
+        );
+        assert_eq!(
+            Duration::parse("a:00", "%H:%M"),
+            Err(ParseError::InvalidHour)
+        );
+        assert_eq!(
+            Duration::parse("1.5", "%S.%3f"),
+            Err(ParseError::InvalidSubsecond)
+        );
+        assert_eq!(
+            Duration::parse("01-00", "%H:%M"),
+            Err(ParseError::UnexpectedCharacter {
+                expected: ':',
+                actual: '-'
+            })
+        );
+        assert_eq!(
+            Duration::parse("18446744073709551615", "%H"),
+            Err(ParseError::InvalidDuration)
+        );
+
+        assert_eq!(Duration::parse("01:125", "%H:%S"), Ok(3_725.seconds()));
+        assert_eq!(Duration::parse("01:5", "%H:%S"), Ok(3_605.seconds()));
+        assert_eq!(
+            Duration::parse("01:3600", "%H:%S"),
+            Err(ParseError::InvalidSecond)
+        );
+    }
+
+    #[test]
+    fn format_parse_round_trip() {
+        for &duration in &[
+            0.seconds(),
+            1.nanoseconds(),
+            59.seconds(),
+            90.minutes() + 5.seconds(),
+            (-(25.hours() + 123_456_789.nanoseconds())),
+            10_000.hours() + 59.minutes() + 59.seconds(),
+        ] {
+            for &format in &[
+                "%H:%M:%S%f",
+                "%M:%S%f",
+                "%-S%f",
+                "%Hh %Mm %Ss %Nns",
+                "%H:%S%f",
+            ] {
+                assert_eq!(
+                    Duration::parse(&duration.format(format), format),
+                    Ok(duration),
+                    "{}",
+                    format
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn format_unsupported() {
+        assert_panics!(1.seconds().format("%d"), "%d is not supported");
+        assert_panics!(1.seconds().format("%2S"), "%2S is not supported");
+        assert_panics!(1.seconds().format("%:z"), "%:z is not supported");
+        assert_panics!(1.seconds().format("%~1f"), "%~1f is not supported");
+        assert_panics!(1.seconds().format("%S%"), "a trailing % is not supported");
+        assert_eq!(1.seconds().format("%S%%"), "01%");
+    }
+
+    #[test]
+    fn parse_unsupported() {
+        assert_eq!(
+            Duration::parse("1", "%Y"),
+            Err(ParseError::InvalidFormatSpecifier('Y'))
+        );
+        assert_eq!(
+            Duration::parse("1.5", "%S.%~1f"),
+            Err(ParseError::InvalidFormatSpecifier('~'))
+        );
+        assert_eq!(
+            Duration::parse("1", "%S%"),
+            Err(ParseError::MissingFormatSpecifier)
+        );
+        assert_eq!(Duration::parse("01%", "%S%%"), Ok(1.seconds()));
     }
 
     #[test]
@@ -1576,6 +2606,12 @@ mod test {
         assert_eq!(1.seconds() + 1.seconds(), 2.seconds());
         assert_eq!(500.milliseconds() + 500.milliseconds(), 1.seconds());
         assert_eq!(1.seconds() + (-1).seconds(), 0.seconds());
+        assert_eq!((-1_500).milliseconds() + 1.seconds(), (-500).milliseconds());
+        assert_eq!(1_500.milliseconds() + (-2).seconds(), (-500).milliseconds());
+        assert_eq!(
+            (-1_500).milliseconds() + (-1_500).milliseconds(),
+            (-3).seconds()
+        );
     }
 
     #[test]
@@ -1627,6 +2663,19 @@ mod test {
         assert_eq!(-(1.seconds()), Duration::seconds(-1));
         assert_eq!(-(-1).seconds(), Duration::second());
         assert_eq!(-(0.seconds()), Duration::zero());
+        assert_eq!(-(1_500.milliseconds()), (-1_500).milliseconds());
+        assert_eq!(-(-(7.nanoseconds())), 7.nanoseconds());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn neg_extremes() {
+        assert_eq!(-Duration::min_value(), Duration::max_value());
+        assert_eq!(-Duration::max_value(), Duration::min_value());
+        assert_eq!(
+            (-Duration::min_value()).whole_nanoseconds(),
+            -Duration::min_value().whole_nanoseconds()
+        );
     }
 
     #[test]
@@ -1634,6 +2683,12 @@ mod test {
         assert_eq!(1.seconds() - 1.seconds(), 0.seconds());
         assert_eq!(1_500.milliseconds() - 500.milliseconds(), 1.seconds());
         assert_eq!(1.seconds() - (-1).seconds(), 2.seconds());
+        assert_eq!(
+            (-1_500).milliseconds() - (-1).seconds(),
+            (-500).milliseconds()
+        );
+        assert_eq!(500.milliseconds() - 1_500.milliseconds(), (-1).seconds());
+        assert_eq!((-500).milliseconds() - 1.seconds(), (-1_500).milliseconds());
     }
 
     #[test]
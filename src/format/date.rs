@@ -5,7 +5,7 @@
 use super::{
     parse::{
         consume_padding, try_consume_digits, try_consume_digits_in_range, try_consume_exact_digits,
-        try_consume_exact_digits_in_range, try_consume_first_match,
+        try_consume_exact_digits_in_range, try_consume_first_match, try_consume_first_match_ci,
     },
     Padding, ParseError, ParseResult, ParsedItems,
 };
@@ -17,18 +17,6 @@ use core::{
     num::{NonZeroU16, NonZeroU8},
 };
 
-/// Array of weekdays that corresponds to the localized values. This can be
-/// zipped via an iterator to perform parsing easily.
-const WEEKDAYS: [Weekday; 7] = [
-    Weekday::Monday,
-    Weekday::Tuesday,
-    Weekday::Wednesday,
-    Weekday::Thursday,
-    Weekday::Friday,
-    Weekday::Saturday,
-    Weekday::Sunday,
-];
-
 /// Short day of the week
 #[inline(always)]
 pub(crate) fn fmt_a(f: &mut Formatter<'_>, date: Date, language: Language) -> fmt::Result {
@@ -42,13 +30,13 @@ pub(crate) fn parse_a(
     s: &mut &str,
     language: Language,
 ) -> ParseResult<()> {
-    items.weekday = try_consume_first_match(
+    items.weekday = try_consume_first_match_ci(
         s,
         language
             .short_week_days()
             .iter()
             .cloned()
-            .zip(WEEKDAYS.iter().cloned()),
+            .zip(Weekday::iter()),
     )
     .ok_or(ParseError::InvalidDayOfWeek)?
     .into();
@@ -69,16 +57,10 @@ pub(crate) fn parse_A(
     s: &mut &str,
     language: Language,
 ) -> ParseResult<()> {
-    items.weekday = try_consume_first_match(
-        s,
-        language
-            .week_days()
-            .iter()
-            .cloned()
-            .zip(WEEKDAYS.iter().cloned()),
-    )
-    .ok_or(ParseError::InvalidDayOfWeek)?
-    .into();
+    items.weekday =
+        try_consume_first_match_ci(s, language.week_days().iter().cloned().zip(Weekday::iter()))
+            .ok_or(ParseError::InvalidDayOfWeek)?
+            .into();
 
     Ok(())
 }
@@ -100,9 +82,10 @@ pub(crate) fn parse_b(
     s: &mut &str,
     language: Language,
 ) -> ParseResult<()> {
-    items.month = try_consume_first_match(s, language.short_month_names().iter().cloned().zip(1..))
-        .map(NonZeroU8::new)
-        .ok_or(ParseError::InvalidMonth)?;
+    items.month =
+        try_consume_first_match_ci(s, language.short_month_names().iter().cloned().zip(1..))
+            .map(NonZeroU8::new)
+            .ok_or(ParseError::InvalidMonth)?;
 
     Ok(())
 }
@@ -120,13 +103,34 @@ pub(crate) fn parse_B(
     s: &mut &str,
     language: Language,
 ) -> ParseResult<()> {
-    items.month = try_consume_first_match(s, language.month_names().iter().cloned().zip(1..))
+    items.month = try_consume_first_match_ci(s, language.month_names().iter().cloned().zip(1..))
         .map(NonZeroU8::new)
         .ok_or(ParseError::InvalidMonth)?;
 
     Ok(())
 }
 
+/// Month name in the genitive case
+#[inline(always)]
+pub(crate) fn fmt_OB(f: &mut Formatter<'_>, date: Date, language: Language) -> fmt::Result {
+    f.write_str(language.month_names_genitive()[date.month() as usize - 1])
+}
+
+/// Month name in the genitive case
+#[inline(always)]
+pub(crate) fn parse_OB(
+    items: &mut ParsedItems,
+    s: &mut &str,
+    language: Language,
+) -> ParseResult<()> {
+    items.month =
+        try_consume_first_match_ci(s, language.month_names_genitive().iter().cloned().zip(1..))
+            .map(NonZeroU8::new)
+            .ok_or(ParseError::InvalidMonth)?;
+
+    Ok(())
+}
+
 /// Year divided by 100 and truncated to integer (`00`-`999`)
 #[inline(always)]
 pub(crate) fn fmt_C(f: &mut Formatter<'_>, date: Date, padding: Padding) -> fmt::Result {
@@ -251,6 +255,52 @@ pub(crate) fn parse_m(items: &mut ParsedItems, s: &mut &str, padding: Padding) -
     Ok(())
 }
 
+/// Day of the month with its ordinal suffix (`1st`-`31st`)
+#[inline(always)]
+pub(crate) fn fmt_o(f: &mut Formatter<'_>, date: Date, language: Language) -> fmt::Result {
+    let day = date.day();
+    write!(f, "{}{}", day, language.ordinal_suffix(day.into()))
+}
+
+/// Day of the month with its ordinal suffix (`1st`-`31st`). The suffix is
+/// optional. To tolerate text that is not strictly formatted, any English
+/// suffix is accepted in addition to the localized one.
+#[inline(always)]
+pub(crate) fn parse_o(
+    items: &mut ParsedItems,
+    s: &mut &str,
+    language: Language,
+) -> ParseResult<()> {
+    let day =
+        try_consume_digits_in_range::<u8>(s, 1..=2, 1..=31).ok_or(ParseError::InvalidDayOfMonth)?;
+    let _ = try_consume_first_match_ci(
+        s,
+        [language.ordinal_suffix(day.into()), "st", "nd", "rd", "th"]
+            .iter()
+            .filter(|suffix| !suffix.is_empty())
+            .map(|&suffix| (suffix, ())),
+    );
+    items.day = NonZeroU8::new(day);
+
+    Ok(())
+}
+
+/// Quarter of the year (`1`-`4`)
+#[inline(always)]
+pub(crate) fn fmt_q(f: &mut Formatter<'_>, date: Date) -> fmt::Result {
+    write!(f, "{}", date.quarter())
+}
+
+/// Quarter of the year (`1`-`4`)
+#[inline(always)]
+pub(crate) fn parse_q(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    items.quarter = try_consume_digits_in_range(s, 1..=1, 1..=4)
+        .map(NonZeroU8::new)
+        .ok_or(ParseError::InvalidQuarter)?;
+
+    Ok(())
+}
+
 /// ISO weekday (Monday = `1`, Sunday = `7`)
 #[inline(always)]
 pub(crate) fn fmt_u(f: &mut Formatter<'_>, date: Date) -> fmt::Result {
@@ -260,12 +310,9 @@ pub(crate) fn fmt_u(f: &mut Formatter<'_>, date: Date) -> fmt::Result {
 /// ISO weekday (Monday = `1`, Sunday = `7`)
 #[inline(always)]
 pub(crate) fn parse_u(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
-    items.weekday = try_consume_first_match(
-        s,
-        (1..).map(|d| d.to_string()).zip(WEEKDAYS.iter().cloned()),
-    )
-    .ok_or(ParseError::InvalidDayOfWeek)?
-    .into();
+    items.weekday = try_consume_first_match(s, (1..).map(|d| d.to_string()).zip(Weekday::iter()))
+        .ok_or(ParseError::InvalidDayOfWeek)?
+        .into();
 
     Ok(())
 }
@@ -313,14 +360,9 @@ pub(crate) fn fmt_w(f: &mut Formatter<'_>, date: Date) -> fmt::Result {
 /// Weekday number (Sunday = `0`, Saturday = `6`)
 #[inline(always)]
 pub(crate) fn parse_w(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
-    let mut weekdays = WEEKDAYS;
-    weekdays.rotate_left(1);
-
     items.weekday = try_consume_first_match(
         s,
-        (0..)
-            .map(|d: u8| d.to_string())
-            .zip(weekdays.iter().cloned()),
+        (0..7).map(|d: u8| (d.to_string(), Weekday::nth_from_sunday(d))),
     )
     .ok_or(ParseError::InvalidDayOfWeek)?
     .into();
@@ -352,13 +394,44 @@ pub(crate) fn fmt_y(f: &mut Formatter<'_>, date: Date, padding: Padding) -> fmt:
 }
 
 /// Last two digits of year (`00`-`99`)
+///
+/// If the century is not already known, it is inferred as in POSIX: `69`-`99`
+/// are in the 1900s, and `00`-`68` are in the 2000s. A subsequent `%C`
+/// replaces the century.
 #[inline(always)]
 pub(crate) fn parse_y(items: &mut ParsedItems, s: &mut &str, padding: Padding) -> ParseResult<()> {
-    items.year = (items.year.unwrap_or(0) / 100 * 100
-        + try_consume_exact_digits::<i32>(s, 2, padding.default_to(Padding::Zero))
-            .ok_or(ParseError::InvalidYear)?)
-    .into();
+    let year = try_consume_exact_digits::<i32>(s, 2, padding.default_to(Padding::Zero))
+        .ok_or(ParseError::InvalidYear)?;
+    let century = match items.year {
+        Some(existing) => existing / 100 * 100,
+        None if year >= 69 => 1900,
+        None => 2000,
+    };
+    items.year = Some(century + year);
+
+    Ok(())
+}
+
+/// The number of years the Buddhist era is ahead of the Gregorian calendar.
+const BUDDHIST_ERA_OFFSET: i32 = 543;
+
+/// Year in the Buddhist era (Gregorian year + 543)
+#[inline(always)]
+pub(crate) fn fmt_K(f: &mut Formatter<'_>, date: Date, padding: Padding) -> fmt::Result {
+    let year = date.year() + BUDDHIST_ERA_OFFSET;
+
+    if year >= 10_000 {
+        f.write_str("+")?;
+    }
 
+    pad!(f, padding(Zero), 4, year)
+}
+
+/// Year in the Buddhist era (Gregorian year + 543)
+#[inline(always)]
+pub(crate) fn parse_K(items: &mut ParsedItems, s: &mut &str, padding: Padding) -> ParseResult<()> {
+    parse_Y(items, s, padding)?;
+    items.year = items.year.map(|year| year - BUDDHIST_ERA_OFFSET);
     Ok(())
 }
 
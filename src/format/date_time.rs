@@ -0,0 +1,191 @@
+//! Formatting helpers for a `DateTime`.
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{
+    format::{
+        date, offset,
+        parse::{
+            try_consume_char, try_consume_digits, try_consume_digits_in_range,
+            try_consume_exact_digits, try_consume_exact_digits_in_range, try_consume_first_match,
+            try_consume_first_match_ci,
+        },
+        time, Padding, ParseError, ParseResult, ParsedItems,
+    },
+    DateTime, Language, Sign, UtcOffset,
+};
+use core::fmt::{self, Formatter};
+use core::num::NonZeroU8;
+
+/// Abbreviated month names used by RFC 2822. Unlike the English abbreviations,
+/// these are always three letters.
+const RFC2822_MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Unix timestamp. The `DateTime` is in the provided offset, if any.
+#[inline(always)]
+pub(crate) fn fmt_s(
+    f: &mut Formatter<'_>,
+    datetime: DateTime,
+    offset: Option<UtcOffset>,
+) -> fmt::Result {
+    let offset = offset.map_or(0, |offset| i64::from(offset.as_seconds()));
+    write!(f, "{}", datetime.timestamp() - offset)
+}
+
+/// Unix timestamp
+#[inline(always)]
+pub(crate) fn parse_s(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    let sign = try_consume_first_match(
+        s,
+        [("+", Sign::Positive), ("-", Sign::Negative)]
+            .iter()
+            .cloned(),
+    )
+    .unwrap_or(Sign::Positive);
+
+    // Roughly ±95,000 years from the epoch, which is well within the range of
+    // years guaranteed to be representable.
+    items.timestamp = try_consume_digits_in_range(s, 1..=13, 0..=3_000_000_000_000)
+        .map(|v: i64| sign * v)
+        .ok_or(ParseError::InvalidTimestamp)?
+        .into();
+
+    Ok(())
+}
+
+/// RFC 3339 date and time, such as `2019-01-02T03:04:05.678+01:00`. Any number
+/// of fractional digits is accepted, though only the first nine are retained.
+#[inline]
+pub(crate) fn parse_rfc3339(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    items.year = try_consume_exact_digits::<i32>(s, 4, Padding::Zero)
+        .ok_or(ParseError::InvalidYear)?
+        .into();
+    try_consume_char(s, '-')?;
+    date::parse_m(items, s, Padding::Zero)?;
+    try_consume_char(s, '-')?;
+    date::parse_d(items, s, Padding::Zero)?;
+
+    // RFC 3339 permits a space in place of the `T`, and both `T` and `Z` may
+    // be lowercase.
+    try_consume_first_match(s, ["T", "t", " "].iter().map(|&v| (v, ()))).ok_or(
+        ParseError::UnexpectedCharacter {
+            expected: 'T',
+            actual: s.chars().next().ok_or(ParseError::UnexpectedEndOfString)?,
+        },
+    )?;
+
+    time::parse_H(items, s, Padding::Zero)?;
+    try_consume_char(s, ':')?;
+    time::parse_M(items, s, Padding::Zero)?;
+    try_consume_char(s, ':')?;
+    time::parse_S(items, s, Padding::Zero)?;
+
+    if try_consume_char(s, '.').is_ok() {
+        let length = s.len();
+        let nanosecond: u32 = try_consume_digits(s, 1..=9).ok_or(ParseError::InvalidSubsecond)?;
+        #[allow(clippy::cast_possible_truncation)]
+        let digits = (length - s.len()) as u32;
+        items.nanosecond = Some(nanosecond * 10_u32.pow(9 - digits));
+
+        // Digits beyond nanosecond precision are discarded.
+        *s = s.trim_start_matches(|c: char| c.is_ascii_digit());
+    }
+
+    items.offset = if try_consume_first_match(s, ["Z", "z"].iter().map(|&v| (v, ()))).is_some() {
+        UtcOffset::UTC
+    } else {
+        let sign = try_consume_first_match(
+            s,
+            [("+", Sign::Positive), ("-", Sign::Negative)]
+                .iter()
+                .cloned(),
+        )
+        .ok_or(ParseError::InvalidOffset)?;
+        let hours: i16 = try_consume_exact_digits_in_range(s, 2, 0..24, Padding::Zero)
+            .ok_or(ParseError::InvalidOffset)?;
+        try_consume_char(s, ':').map_err(|_| ParseError::InvalidOffset)?;
+        let minutes: i16 = try_consume_exact_digits_in_range(s, 2, 0..60, Padding::Zero)
+            .ok_or(ParseError::InvalidOffset)?;
+
+        UtcOffset::minutes(sign * (hours * 60 + minutes))
+    }
+    .into();
+
+    Ok(())
+}
+
+/// RFC 2822 date and time, such as `Tue, 01 Jul 2003 10:52:37 +0200`. The
+/// `DateTime` is the wall-clock time in the provided offset.
+#[inline]
+pub(crate) fn format_rfc2822(datetime: DateTime, offset: UtcOffset) -> String {
+    format!(
+        "{}, {:02} {} {} {}",
+        datetime.format("%a"),
+        datetime.day(),
+        RFC2822_MONTH_NAMES[datetime.month() as usize - 1],
+        datetime.format("%Y %H:%M:%S"),
+        offset.format("%z"),
+    )
+}
+
+/// RFC 2822 date and time, such as `Tue, 1 Jul 2003 10:52:37 +0200`. The
+/// weekday and seconds are optional, and the day may have one or two digits.
+/// In addition to numeric offsets, `GMT`, `UT`, and `Z` are accepted as UTC.
+#[inline]
+pub(crate) fn parse_rfc2822(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    /// Consume any amount of whitespace, requiring at least one character.
+    #[inline(always)]
+    fn consume_whitespace(s: &mut &str) -> ParseResult<()> {
+        let length = s.len();
+        *s = s.trim_start_matches(&[' ', '\t'][..]);
+
+        if s.len() == length {
+            Err(s
+                .chars()
+                .next()
+                .map_or(ParseError::UnexpectedEndOfString, |actual| {
+                    ParseError::UnexpectedCharacter {
+                        expected: ' ',
+                        actual,
+                    }
+                }))
+        } else {
+            Ok(())
+        }
+    }
+
+    if date::parse_a(items, s, Language::en).is_ok() {
+        try_consume_char(s, ',')?;
+        consume_whitespace(s)?;
+    }
+
+    items.day = try_consume_digits_in_range(s, 1..=2, 1..=31)
+        .map(NonZeroU8::new)
+        .ok_or(ParseError::InvalidDayOfMonth)?;
+    consume_whitespace(s)?;
+    items.month = try_consume_first_match_ci(s, RFC2822_MONTH_NAMES.iter().cloned().zip(1..))
+        .map(NonZeroU8::new)
+        .ok_or(ParseError::InvalidMonth)?;
+    consume_whitespace(s)?;
+    items.year = try_consume_exact_digits::<i32>(s, 4, Padding::Zero)
+        .ok_or(ParseError::InvalidYear)?
+        .into();
+    consume_whitespace(s)?;
+
+    time::parse_H(items, s, Padding::Zero)?;
+    try_consume_char(s, ':')?;
+    time::parse_M(items, s, Padding::Zero)?;
+    if try_consume_char(s, ':').is_ok() {
+        time::parse_S(items, s, Padding::Zero)?;
+    }
+    consume_whitespace(s)?;
+
+    match try_consume_first_match(s, ["GMT", "UT", "Z"].iter().map(|&v| (v, ()))) {
+        Some(()) => items.offset = UtcOffset::UTC.into(),
+        None => offset::parse_z(items, s, 0)?,
+    }
+
+    Ok(())
+}
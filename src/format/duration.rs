@@ -0,0 +1,216 @@
+//! Formatting and parsing of a `Duration` with a format string.
+//!
+//! Only `%H`, `%M`, `%S`, `%f`, and `%N` are supported. The largest of the
+//! hours, minutes, and seconds present in the format holds the total of that
+//! unit, while the others hold what remains after the next larger unit
+//! present, such that `%M:%S` formats 90 minutes as `90:00`, `%H:%M:%S`
+//! formats it as `01:30:00`, and `%H:%S` formats it as `01:1800`.
+
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use crate::{
+    format::{
+        parse::{
+            consume_padding, try_consume_char, try_consume_digits,
+            try_consume_exact_digits_in_range, try_consume_str,
+        },
+        parse_with_language,
+        time::{parse_subsecond, write_subsecond},
+        FormatItem, Padding, ParseError, ParseResult, Specifier,
+    },
+    Duration, Language,
+};
+use core::{fmt::Write, time::Duration as StdDuration};
+
+/// A unit of a `Duration` that can be present in a format string, ordered by
+/// size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Unit {
+    /// Seconds, formatted by `%S`.
+    Second,
+    /// Minutes, formatted by `%M`.
+    Minute,
+    /// Hours, formatted by `%H`.
+    Hour,
+}
+
+impl Unit {
+    /// The number of seconds in the unit.
+    #[inline(always)]
+    fn seconds(self) -> u64 {
+        match self {
+            Self::Second => 1,
+            Self::Minute => 60,
+            Self::Hour => 3_600,
+        }
+    }
+}
+
+/// Ensure the format only contains specifiers supported by a `Duration`,
+/// returning the first unsupported specifier otherwise. A width is only
+/// supported by the subsecond specifiers, and rounding is not supported.
+#[inline]
+fn validate(format: &str) -> ParseResult<()> {
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+
+        let mut has_width = false;
+        while let Some(&c) = chars.peek() {
+            match c {
+                '-' | '_' | '0' => {}
+                '1'..='9' => has_width = true,
+                _ => break,
+            }
+            let _ = chars.next();
+        }
+
+        match chars.next() {
+            Some('f') | Some('N') => {}
+            Some('H') | Some('M') | Some('S') | Some('%') if !has_width => {}
+            Some(c) => return Err(ParseError::InvalidFormatSpecifier(c)),
+            None => return Err(ParseError::MissingFormatSpecifier),
+        }
+    }
+
+    Ok(())
+}
+
+/// Get the number of seconds in the smallest unit present in the format that is
+/// larger than the provided one. A unit holds what remains after that unit, or
+/// the total if there is none.
+#[inline]
+fn next_larger_unit(items: &[FormatItem<'_>], unit: Unit) -> Option<u64> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            FormatItem::Specifier(Specifier::H { .. }) => Some(Unit::Hour),
+            FormatItem::Specifier(Specifier::M { .. }) => Some(Unit::Minute),
+            FormatItem::Specifier(Specifier::S { .. }) => Some(Unit::Second),
+            _ => None,
+        })
+        .filter(|&present| present > unit)
+        .min()
+        .map(Unit::seconds)
+}
+
+/// Format the `Duration` using the provided string. Negative durations are
+/// prefixed with a `-`.
+#[inline]
+pub(crate) fn format(duration: Duration, format: &str) -> String {
+    // A trailing `%` is rejected when parsing the format.
+    if let Err(ParseError::InvalidFormatSpecifier(c)) = validate(format) {
+        panic!("Invalid specifier `{}`", c);
+    }
+    let items = parse_with_language(format, Language::en);
+    let seconds = duration.std.as_secs();
+
+    /// Write the value of a unit, holding the total if it is the largest
+    /// present.
+    macro_rules! unit {
+        ($s:ident, $padding:ident, $unit:ident) => {{
+            let value = match next_larger_unit(&items, Unit::$unit) {
+                Some(larger) => seconds % larger / Unit::$unit.seconds(),
+                None => seconds / Unit::$unit.seconds(),
+            };
+            pad!($s, $padding(Zero), 2, value)
+        }};
+    }
+
+    let mut s = String::new();
+    if duration.is_negative() {
+        s.push('-');
+    }
+
+    for item in &items {
+        // Writing to a `String` cannot fail.
+        let _ = match *item {
+            FormatItem::Literal(value) => s.write_str(value),
+            FormatItem::Specifier(Specifier::H { padding }) => unit!(s, padding, Hour),
+            FormatItem::Specifier(Specifier::M { padding }) => unit!(s, padding, Minute),
+            FormatItem::Specifier(Specifier::S { padding }) => unit!(s, padding, Second),
+            FormatItem::Specifier(Specifier::f { digits, trim, .. }) => {
+                write_subsecond(&mut s, duration.std.subsec_nanos(), digits, trim)
+            }
+            // Any other item was rejected when validating the format.
+            _ => Ok(()),
+        };
+    }
+
+    s
+}
+
+/// Parse a `Duration` from the string using the provided format. The string
+/// may be prefixed with a `-` to indicate a negative duration.
+#[inline]
+pub(crate) fn parse(mut input: &str, format: &str) -> ParseResult<Duration> {
+    validate(format)?;
+    let items = parse_with_language(format, Language::en);
+    let s = &mut input;
+
+    let is_negative = try_consume_char(s, '-').is_ok();
+    let mut seconds = 0_u64;
+    let mut nanoseconds = 0;
+
+    /// Parse the value of a unit, which is unbounded if it is the largest
+    /// present. Otherwise, it must be less than the next larger unit present,
+    /// and has exactly two digits if that is the next larger unit.
+    macro_rules! unit {
+        ($padding:ident, $unit:ident, $error:ident) => {{
+            let value: u64 = match next_larger_unit(&items, Unit::$unit) {
+                Some(larger) if larger / Unit::$unit.seconds() == 60 => {
+                    try_consume_exact_digits_in_range(
+                        s,
+                        2,
+                        0..60,
+                        $padding.default_to(Padding::Zero),
+                    )
+                }
+                larger => {
+                    if $padding == Padding::Space {
+                        consume_padding(s, Padding::Space, 1);
+                    }
+                    try_consume_digits(s, 1..=20).filter(|&value| {
+                        larger.map_or(true, |larger| value < larger / Unit::$unit.seconds())
+                    })
+                }
+            }
+            .ok_or(ParseError::$error)?;
+
+            seconds = value
+                .checked_mul(Unit::$unit.seconds())
+                .and_then(|value| seconds.checked_add(value))
+                .ok_or(ParseError::InvalidDuration)?;
+        }};
+    }
+
+    for item in &items {
+        match *item {
+            FormatItem::Literal(expected) => try_consume_str(s, expected)?,
+            FormatItem::Specifier(Specifier::H { padding }) => {
+                unit!(padding, Hour, InvalidHour);
+            }
+            FormatItem::Specifier(Specifier::M { padding }) => {
+                unit!(padding, Minute, InvalidMinute);
+            }
+            FormatItem::Specifier(Specifier::S { padding }) => {
+                unit!(padding, Second, InvalidSecond);
+            }
+            FormatItem::Specifier(Specifier::f { digits, trim, .. }) => {
+                nanoseconds = parse_subsecond(s, digits, trim)?;
+            }
+            // Any other item was rejected when validating the format.
+            _ => {}
+        }
+    }
+
+    let duration = Duration::from(StdDuration::new(seconds, nanoseconds));
+    if is_negative {
+        Ok(-duration)
+    } else {
+        Ok(duration)
+    }
+}
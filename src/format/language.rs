@@ -1,49 +1,427 @@
 //! The `Language` struct and its various methods.
 
+use crate::ParseError;
+use core::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
 /// Languages used in formatting. Follows [ISO 639-1](https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes).
 ///
 /// Additional languages may be added at any time. Contributions will be
 /// accepted by native and highly fluent speakers of any living language.
 ///
-/// All languages must have the following:
+/// Languages should have the following. Any that are missing fall back to
+/// English, allowing a language to be added incrementally.
 /// - Month names
+/// - Month names in the genitive case, for languages that distinguish it
 /// - Short month names
 /// - Weekday names
 /// - Short weekday names
+/// - AM/PM designators
+/// - Singular and plural names of units of time
 #[cfg_attr(feature = "unstable", non_exhaustive)]
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
+    /// German
+    de,
     /// English
     en,
     /// Spanish
     es,
     /// French
     fr,
+    /// Portuguese
+    pt,
+}
+
+/// English month names.
+const EN_MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// English abbreviated month names. As with the abbreviated weekday names,
+/// these are always three letters.
+const EN_SHORT_MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// English weekday names, starting with Monday.
+const EN_WEEK_DAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// English abbreviated weekday names, starting with Monday.
+const EN_SHORT_WEEK_DAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// English AM/PM designators.
+const EN_AM_PM: [&str; 2] = ["AM", "PM"];
+
+/// English names of units of time, from years to seconds, each in the
+/// singular and plural.
+const EN_UNIT_NAMES: [[&str; 2]; 7] = [
+    ["year", "years"],
+    ["month", "months"],
+    ["week", "weeks"],
+    ["day", "days"],
+    ["hour", "hours"],
+    ["minute", "minutes"],
+    ["second", "seconds"],
+];
+
+/// Tables of localized names. Any table may be missing, in which case the
+/// English table is used instead. This allows languages to be added
+/// incrementally.
+trait Names: Copy {
+    /// Get the month names, if present.
+    fn try_month_names(self) -> Option<[&'static str; 12]>;
+
+    /// Get the month names in the genitive case, if the language
+    /// distinguishes them from the nominative.
+    fn try_month_names_genitive(self) -> Option<[&'static str; 12]>;
+
+    /// Get the abbreviated month names, if present.
+    fn try_short_month_names(self) -> Option<[&'static str; 12]>;
+
+    /// Get the names of days of the week, starting with Monday, if present.
+    fn try_week_days(self) -> Option<[&'static str; 7]>;
+
+    /// Get the abbreviated names of days of the week, starting with Monday, if
+    /// present.
+    fn try_short_week_days(self) -> Option<[&'static str; 7]>;
+
+    /// Get the AM and PM designators, if present.
+    fn try_am_pm(self) -> Option<[&'static str; 2]>;
+
+    /// Get the singular and plural names of units of time, from years to
+    /// seconds, if present.
+    fn try_unit_names(self) -> Option<[[&'static str; 2]; 7]>;
+
+    /// Get the month names, falling back to English.
+    #[inline(always)]
+    fn month_names_or_en(self) -> [&'static str; 12] {
+        self.try_month_names().unwrap_or(EN_MONTH_NAMES)
+    }
+
+    /// Get the month names in the genitive case, falling back to the
+    /// nominative.
+    #[inline(always)]
+    fn month_names_genitive_or_nominative(self) -> [&'static str; 12] {
+        self.try_month_names_genitive()
+            .unwrap_or_else(|| self.month_names_or_en())
+    }
+
+    /// Get the abbreviated month names, falling back to English.
+    #[inline(always)]
+    fn short_month_names_or_en(self) -> [&'static str; 12] {
+        self.try_short_month_names().unwrap_or(EN_SHORT_MONTH_NAMES)
+    }
+
+    /// Get the names of days of the week, falling back to English.
+    #[inline(always)]
+    fn week_days_or_en(self) -> [&'static str; 7] {
+        self.try_week_days().unwrap_or(EN_WEEK_DAYS)
+    }
+
+    /// Get the abbreviated names of days of the week, falling back to English.
+    #[inline(always)]
+    fn short_week_days_or_en(self) -> [&'static str; 7] {
+        self.try_short_week_days().unwrap_or(EN_SHORT_WEEK_DAYS)
+    }
+
+    /// Get the AM and PM designators, falling back to English.
+    #[inline(always)]
+    fn am_pm_or_en(self) -> [&'static str; 2] {
+        self.try_am_pm().unwrap_or(EN_AM_PM)
+    }
+
+    /// Get the names of units of time, falling back to English.
+    #[inline(always)]
+    fn unit_names_or_en(self) -> [[&'static str; 2]; 7] {
+        self.try_unit_names().unwrap_or(EN_UNIT_NAMES)
+    }
 }
 
-#[allow(clippy::non_ascii_literal)]
 impl Language {
+    /// Get all supported languages.
+    ///
+    /// ```rust
+    /// # use time::Language;
+    /// assert!(Language::variants().contains(&Language::en));
+    /// ```
+    #[inline(always)]
+    pub fn variants() -> &'static [Self] {
+        use Language::*;
+        &[de, en, es, fr, pt]
+    }
+
+    /// Get the language with the provided ISO 639-1 code, without regard to
+    /// case. Returns `None` if the language is not supported.
+    ///
+    /// ```rust
+    /// # use time::Language;
+    /// assert_eq!(Language::from_iso_639_1("fr"), Some(Language::fr));
+    /// assert_eq!(Language::from_iso_639_1("DE"), Some(Language::de));
+    /// assert_eq!(Language::from_iso_639_1("xx"), None);
+    /// ```
+    #[inline]
+    pub fn from_iso_639_1(code: &str) -> Option<Self> {
+        Self::variants()
+            .iter()
+            .copied()
+            .find(|language| language.as_str().eq_ignore_ascii_case(code))
+    }
+
+    /// Get the ISO 639-1 code of the language. This is the inverse of
+    /// [`Language::from_iso_639_1`], and is also used by the `Display`
+    /// implementation.
+    ///
+    /// ```rust
+    /// # use time::Language;
+    /// assert_eq!(Language::en.as_str(), "en");
+    /// assert_eq!(Language::pt.to_string(), "pt");
+    /// ```
+    #[inline(always)]
+    pub fn as_str(self) -> &'static str {
+        use Language::*;
+        match self {
+            de => "de",
+            en => "en",
+            es => "es",
+            fr => "fr",
+            pt => "pt",
+        }
+    }
+
     /// Get the month names for the given language.
     #[inline(always)]
     pub fn month_names(self) -> [&'static str; 12] {
+        self.month_names_or_en()
+    }
+
+    /// Get the month names for the given language, as used in a date
+    /// context (e.g. "5 января" in Russian). Languages that do not distinguish
+    /// the genitive case return the same names as
+    /// [`Language::month_names`].
+    ///
+    /// ```rust
+    /// # use time::Language;
+    /// assert_eq!(Language::en.month_names_genitive(), Language::en.month_names());
+    /// ```
+    #[inline(always)]
+    pub fn month_names_genitive(self) -> [&'static str; 12] {
+        self.month_names_genitive_or_nominative()
+    }
+
+    /// Get the abbreviated month names for the given language.
+    ///
+    /// References on localization:
+    /// [\[1\]](https://web.library.yale.edu/cataloging/months)
+    /// [\[2\]](https://library.princeton.edu/departments/tsd/katmandu/reference/months.html)
+    #[inline(always)]
+    pub fn short_month_names(self) -> [&'static str; 12] {
+        self.short_month_names_or_en()
+    }
+
+    /// Get the names of days of the week for the given language. Starts with
+    /// Monday.
+    #[inline(always)]
+    pub fn week_days(self) -> [&'static str; 7] {
+        self.week_days_or_en()
+    }
+
+    /// Get the abbreviated names of days of the week for the given language.
+    /// Starts with Monday.
+    #[inline(always)]
+    pub fn short_week_days(self) -> [&'static str; 7] {
+        self.short_week_days_or_en()
+    }
+
+    /// Get the designators used for times before and after noon in the
+    /// 12-hour clock for the given language, in that order.
+    ///
+    /// ```rust
+    /// # use time::Language;
+    /// assert_eq!(Language::en.am_pm(), ["AM", "PM"]);
+    /// assert_eq!(Language::es.am_pm(), ["a. m.", "p. m."]);
+    /// ```
+    #[inline(always)]
+    pub fn am_pm(self) -> [&'static str; 2] {
+        self.am_pm_or_en()
+    }
+
+    /// Get the names of units of time for the given language, from years to
+    /// seconds. Each is a pair of the singular and plural forms, in that order.
+    ///
+    /// ```rust
+    /// # use time::Language;
+    /// assert_eq!(Language::en.unit_names()[4], ["hour", "hours"]);
+    /// assert_eq!(Language::de.unit_names()[6], ["Sekunde", "Sekunden"]);
+    /// ```
+    #[inline(always)]
+    pub fn unit_names(self) -> [[&'static str; 2]; 7] {
+        self.unit_names_or_en()
+    }
+
+    /// Check if the singular form is used for the given quantity. French uses
+    /// the singular for zero as well as one.
+    #[inline]
+    pub(crate) fn is_singular(self, n: u64) -> bool {
+        use Language::*;
+        match self {
+            de | en | es | pt => n == 1,
+            fr => n <= 1,
+        }
+    }
+
+    /// Get the preferred representation of a date and time in the given
+    /// language, as used by `%c`.
+    ///
+    /// ```rust
+    /// # use time::Language;
+    /// assert_eq!(Language::en.date_time_format(), "%a %b %-d %-H:%M:%S %-Y");
+    /// assert_eq!(Language::fr.date_time_format(), "%a %-d %b %Y %H:%M:%S");
+    /// ```
+    #[inline]
+    pub fn date_time_format(self) -> &'static str {
         use Language::*;
         match self {
-            en => [
-                "January",
-                "February",
-                "March",
+            en => "%a %b %-d %-H:%M:%S %-Y",
+            de | es | fr | pt => "%a %-d %b %Y %H:%M:%S",
+        }
+    }
+
+    /// Get the preferred representation of a date in the given language, as
+    /// used by `%x`.
+    ///
+    /// ```rust
+    /// # use time::Language;
+    /// assert_eq!(Language::en.date_format(), "%m/%d/%y");
+    /// assert_eq!(Language::fr.date_format(), "%d/%m/%Y");
+    /// ```
+    #[inline]
+    pub fn date_format(self) -> &'static str {
+        use Language::*;
+        match self {
+            de => "%d.%m.%Y",
+            en => "%m/%d/%y",
+            es => "%d/%m/%y",
+            fr | pt => "%d/%m/%Y",
+        }
+    }
+
+    /// Get the preferred representation of a time in the given language, as
+    /// used by `%X`.
+    ///
+    /// ```rust
+    /// # use time::Language;
+    /// assert_eq!(Language::en.time_format(), "%-H:%M:%S");
+    /// assert_eq!(Language::de.time_format(), "%H:%M:%S");
+    /// ```
+    #[inline]
+    pub fn time_format(self) -> &'static str {
+        use Language::*;
+        match self {
+            en => "%-H:%M:%S",
+            de | es | fr | pt => "%H:%M:%S",
+        }
+    }
+
+    /// Get the suffix used to write the number as an ordinal in the given
+    /// language. Languages that do not use a suffix for a given number return
+    /// an empty string.
+    ///
+    /// ```rust
+    /// # use time::Language;
+    /// assert_eq!(Language::en.ordinal_suffix(1), "st");
+    /// assert_eq!(Language::en.ordinal_suffix(12), "th");
+    /// assert_eq!(Language::en.ordinal_suffix(23), "rd");
+    /// assert_eq!(Language::fr.ordinal_suffix(1), "er");
+    /// assert_eq!(Language::fr.ordinal_suffix(2), "");
+    /// ```
+    #[inline]
+    pub fn ordinal_suffix(self, n: u16) -> &'static str {
+        use Language::*;
+        match self {
+            de => ".",
+            en => match (n % 10, n % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            },
+            es | pt if n == 1 => "º",
+            fr if n == 1 => "er",
+            es | fr | pt => "",
+        }
+    }
+}
+
+impl Display for Language {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Language {
+    type Err = ParseError;
+
+    /// Parse a language from its ISO 639-1 code, without regard to case.
+    ///
+    /// ```rust
+    /// # use time::{Language, ParseError};
+    /// assert_eq!("fr".parse::<Language>(), Ok(Language::fr));
+    /// assert_eq!("PT".parse::<Language>(), Ok(Language::pt));
+    /// assert_eq!("xx".parse::<Language>(), Err(ParseError::InvalidLanguage));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_iso_639_1(s).ok_or(ParseError::InvalidLanguage)
+    }
+}
+
+#[allow(clippy::non_ascii_literal)]
+impl Names for Language {
+    #[inline(always)]
+    fn try_month_names(self) -> Option<[&'static str; 12]> {
+        use Language::*;
+        match self {
+            de => Some([
+                "Januar",
+                "Februar",
+                "März",
                 "April",
-                "May",
-                "June",
-                "July",
+                "Mai",
+                "Juni",
+                "Juli",
                 "August",
                 "September",
-                "October",
+                "Oktober",
                 "November",
-                "December",
-            ],
-            es => [
+                "Dezember",
+            ]),
+            en => Some(EN_MONTH_NAMES),
+            es => Some([
                 "enero",
                 "febrero",
                 "marzo",
@@ -56,8 +434,8 @@ impl Language {
                 "octubre",
                 "noviembre",
                 "diciembre",
-            ],
-            fr => [
+            ]),
+            fr => Some([
                 "janvier",
                 "février",
                 "mars",
@@ -70,50 +448,70 @@ impl Language {
                 "octobre",
                 "novembre",
                 "décembre",
-            ],
+            ]),
+            pt => Some([
+                "janeiro",
+                "fevereiro",
+                "março",
+                "abril",
+                "maio",
+                "junho",
+                "julho",
+                "agosto",
+                "setembro",
+                "outubro",
+                "novembro",
+                "dezembro",
+            ]),
         }
     }
 
-    /// Get the abbreviated month names for the given language.
-    ///
-    /// References on localization:
-    /// [\[1\]](https://web.library.yale.edu/cataloging/months)
-    /// [\[2\]](https://library.princeton.edu/departments/tsd/katmandu/reference/months.html)
+    // None of the supported languages inflect month names in a date context.
     #[inline(always)]
-    pub fn short_month_names(self) -> [&'static str; 12] {
+    fn try_month_names_genitive(self) -> Option<[&'static str; 12]> {
+        use Language::*;
+        match self {
+            de | en | es | fr | pt => None,
+        }
+    }
+
+    #[inline(always)]
+    fn try_short_month_names(self) -> Option<[&'static str; 12]> {
         use Language::*;
         match self {
-            en => [
-                "Jan", "Feb", "Mar", "Apr", "May", "June", "July", "Aug", "Sept", "Oct", "Nov",
-                "Dec",
-            ],
-            es => [
+            de => Some([
+                "Jan", "Feb", "März", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+            ]),
+            en => Some(EN_SHORT_MONTH_NAMES),
+            es => Some([
                 "enero", "feb", "marzo", "abr", "mayo", "jun", "jul", "agosto", "set", "oct",
                 "nov", "dic",
-            ],
-            fr => [
+            ]),
+            fr => Some([
                 "janv", "févr", "mars", "avril", "mai", "juin", "juil", "août", "sept", "oct",
                 "nov", "déc",
-            ],
+            ]),
+            pt => Some([
+                "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+            ]),
         }
     }
 
-    /// Get the names of days of the week for the given language. Starts with
-    /// Monday.
     #[inline(always)]
-    pub fn week_days(self) -> [&'static str; 7] {
+    fn try_week_days(self) -> Option<[&'static str; 7]> {
         use Language::*;
         match self {
-            en => [
-                "Monday",
-                "Tuesday",
-                "Wednesday",
-                "Thursday",
-                "Friday",
-                "Saturday",
-                "Sunday",
-            ],
-            es => [
+            de => Some([
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+                "Sonntag",
+            ]),
+            en => Some(EN_WEEK_DAYS),
+            es => Some([
                 "lunes",
                 "martes",
                 "miércoles",
@@ -121,22 +519,323 @@ impl Language {
                 "viernes",
                 "sábado",
                 "domingo",
-            ],
-            fr => [
+            ]),
+            fr => Some([
                 "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
-            ],
+            ]),
+            pt => Some([
+                "segunda-feira",
+                "terça-feira",
+                "quarta-feira",
+                "quinta-feira",
+                "sexta-feira",
+                "sábado",
+                "domingo",
+            ]),
         }
     }
 
-    /// Get the abbreviated names of days of the week for the given language.
-    /// Starts with Monday.
     #[inline(always)]
-    pub fn short_week_days(self) -> [&'static str; 7] {
+    fn try_short_week_days(self) -> Option<[&'static str; 7]> {
+        use Language::*;
+        match self {
+            de => Some(["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"]),
+            en => Some(EN_SHORT_WEEK_DAYS),
+            es => Some(["Lu", "Ma", "Mi", "Ju", "Vi", "Sa", "Do"]),
+            fr => Some(["lun", "mar", "mer", "jeu", "ven", "sam", "dim"]),
+            pt => Some(["seg", "ter", "qua", "qui", "sex", "sáb", "dom"]),
+        }
+    }
+
+    // The remaining languages use the same designators as English, when the
+    // 12-hour clock is used at all.
+    #[inline(always)]
+    fn try_am_pm(self) -> Option<[&'static str; 2]> {
+        use Language::*;
+        match self {
+            en => Some(EN_AM_PM),
+            es => Some(["a. m.", "p. m."]),
+            de | fr | pt => None,
+        }
+    }
+
+    #[inline(always)]
+    fn try_unit_names(self) -> Option<[[&'static str; 2]; 7]> {
         use Language::*;
         match self {
-            en => ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
-            es => ["Lu", "Ma", "Mi", "Ju", "Vi", "Sa", "Do"],
-            fr => ["lun", "mar", "mer", "jeu", "ven", "sam", "dim"],
+            de => Some([
+                ["Jahr", "Jahre"],
+                ["Monat", "Monate"],
+                ["Woche", "Wochen"],
+                ["Tag", "Tage"],
+                ["Stunde", "Stunden"],
+                ["Minute", "Minuten"],
+                ["Sekunde", "Sekunden"],
+            ]),
+            en => Some(EN_UNIT_NAMES),
+            es => Some([
+                ["año", "años"],
+                ["mes", "meses"],
+                ["semana", "semanas"],
+                ["día", "días"],
+                ["hora", "horas"],
+                ["minuto", "minutos"],
+                ["segundo", "segundos"],
+            ]),
+            fr => Some([
+                ["an", "ans"],
+                ["mois", "mois"],
+                ["semaine", "semaines"],
+                ["jour", "jours"],
+                ["heure", "heures"],
+                ["minute", "minutes"],
+                ["seconde", "secondes"],
+            ]),
+            pt => Some([
+                ["ano", "anos"],
+                ["mês", "meses"],
+                ["semana", "semanas"],
+                ["dia", "dias"],
+                ["hora", "horas"],
+                ["minuto", "minutos"],
+                ["segundo", "segundos"],
+            ]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(not(feature = "std"))]
+    use crate::no_std_prelude::*;
+
+    #[test]
+    fn variants() {
+        use Language::*;
+
+        // This match must remain exhaustive, ensuring that any new language is
+        // also added to `Language::variants`.
+        let index = |language| match language {
+            de => 0,
+            en => 1,
+            es => 2,
+            fr => 3,
+            pt => 4,
+        };
+
+        let variants = Language::variants();
+        assert_eq!(variants.len(), 5);
+        for (i, &language) in variants.iter().enumerate() {
+            assert_eq!(index(language), i);
+        }
+    }
+
+    #[test]
+    fn from_iso_639_1() {
+        for &language in Language::variants() {
+            let code = language.as_str();
+            assert_eq!(Language::from_iso_639_1(code), Some(language));
+            assert_eq!(
+                Language::from_iso_639_1(&code.to_uppercase()),
+                Some(language)
+            );
+            assert_eq!(code.parse::<Language>(), Ok(language));
+        }
+
+        assert_eq!(Language::from_iso_639_1("En"), Some(Language::en));
+        assert_eq!(Language::from_iso_639_1(""), None);
+        assert_eq!(Language::from_iso_639_1("eng"), None);
+        assert_eq!(Language::from_iso_639_1("en-US"), None);
+        assert_eq!("ru".parse::<Language>(), Err(ParseError::InvalidLanguage));
+    }
+
+    #[test]
+    fn as_str() {
+        use Language::*;
+
+        assert_eq!(de.as_str(), "de");
+        assert_eq!(en.as_str(), "en");
+        assert_eq!(es.as_str(), "es");
+        assert_eq!(fr.as_str(), "fr");
+        assert_eq!(pt.as_str(), "pt");
+
+        for &language in Language::variants() {
+            assert_eq!(language.to_string(), language.as_str());
+            assert_eq!(language.to_string().parse(), Ok(language));
+        }
+    }
+
+    #[test]
+    fn fallback() {
+        /// A language with only some tables present.
+        #[derive(Clone, Copy)]
+        struct Partial;
+
+        impl Names for Partial {
+            fn try_month_names(self) -> Option<[&'static str; 12]> {
+                Language::de.try_month_names()
+            }
+
+            fn try_month_names_genitive(self) -> Option<[&'static str; 12]> {
+                Some(["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l"])
+            }
+
+            fn try_short_month_names(self) -> Option<[&'static str; 12]> {
+                None
+            }
+
+            fn try_week_days(self) -> Option<[&'static str; 7]> {
+                None
+            }
+
+            fn try_short_week_days(self) -> Option<[&'static str; 7]> {
+                Language::fr.try_short_week_days()
+            }
+
+            fn try_am_pm(self) -> Option<[&'static str; 2]> {
+                None
+            }
+
+            fn try_unit_names(self) -> Option<[[&'static str; 2]; 7]> {
+                None
+            }
+        }
+
+        assert_eq!(Partial.month_names_or_en(), Language::de.month_names());
+        assert_eq!(Partial.month_names_genitive_or_nominative()[0], "a");
+        assert_eq!(
+            Partial.short_month_names_or_en(),
+            Language::en.short_month_names()
+        );
+        assert_eq!(Partial.week_days_or_en(), Language::en.week_days());
+        assert_eq!(
+            Partial.short_week_days_or_en(),
+            Language::fr.short_week_days()
+        );
+        assert_eq!(Partial.am_pm_or_en(), Language::en.am_pm());
+        assert_eq!(Partial.unit_names_or_en(), Language::en.unit_names());
+
+        // Languages without a distinct genitive use the nominative names.
+        for &language in Language::variants() {
+            assert!(language.try_month_names_genitive().is_none());
+            assert_eq!(language.month_names_genitive(), language.month_names());
+        }
+
+        // Languages without their own designators use the English ones.
+        for &language in &[Language::de, Language::fr, Language::pt] {
+            assert!(language.try_am_pm().is_none());
+            assert_eq!(language.am_pm(), Language::en.am_pm());
+        }
+
+        // Every table of names is currently present for every language.
+        for &language in Language::variants() {
+            assert!(language.try_month_names().is_some());
+            assert!(language.try_short_month_names().is_some());
+            assert!(language.try_week_days().is_some());
+            assert!(language.try_short_week_days().is_some());
+            assert!(language.try_unit_names().is_some());
+        }
+    }
+
+    #[test]
+    fn is_singular() {
+        for &language in Language::variants() {
+            assert!(language.is_singular(1));
+            assert!(!language.is_singular(2));
+            assert!(!language.is_singular(21));
+        }
+        assert!(!Language::en.is_singular(0));
+        assert!(!Language::de.is_singular(0));
+        assert!(!Language::es.is_singular(0));
+        assert!(Language::fr.is_singular(0));
+        assert!(!Language::pt.is_singular(0));
+    }
+
+    #[test]
+    fn ordinal_suffix() {
+        let en = [
+            "st", "nd", "rd", "th", "th", "th", "th", "th", "th", "th", "th", "th", "th", "th",
+            "th", "th", "th", "th", "th", "th", "st", "nd", "rd", "th", "th", "th", "th", "th",
+            "th", "th", "st",
+        ];
+        for (n, &suffix) in (1..).zip(en.iter()) {
+            assert_eq!(Language::en.ordinal_suffix(n), suffix, "{}", n);
+        }
+        assert_eq!(Language::en.ordinal_suffix(0), "th");
+        assert_eq!(Language::en.ordinal_suffix(101), "st");
+        assert_eq!(Language::en.ordinal_suffix(111), "th");
+        assert_eq!(Language::en.ordinal_suffix(112), "th");
+        assert_eq!(Language::en.ordinal_suffix(122), "nd");
+
+        assert_eq!(Language::de.ordinal_suffix(4), ".");
+        assert_eq!(Language::es.ordinal_suffix(1), "º");
+        assert_eq!(Language::es.ordinal_suffix(2), "");
+        assert_eq!(Language::fr.ordinal_suffix(1), "er");
+        assert_eq!(Language::fr.ordinal_suffix(21), "");
+        assert_eq!(Language::pt.ordinal_suffix(1), "º");
+        assert_eq!(Language::pt.ordinal_suffix(31), "");
+    }
+
+    #[test]
+    fn short_names_are_not_longer() {
+        // Every name must be present, and an abbreviation may not be longer
+        // than the name it abbreviates. It may be identical, for names that
+        // are already short.
+        fn assert_short_names(full: &[&str], short: &[&str]) {
+            assert_eq!(full.len(), short.len());
+            for (full, short) in full.iter().zip(short) {
+                assert!(!full.is_empty() && !short.is_empty());
+                assert!(
+                    short.chars().count() <= full.chars().count(),
+                    "{} is longer than {}",
+                    short,
+                    full
+                );
+            }
+        }
+
+        for &language in Language::variants() {
+            assert_short_names(&language.month_names(), &language.short_month_names());
+            assert_short_names(
+                &language.month_names_genitive(),
+                &language.short_month_names(),
+            );
+            assert_short_names(&language.week_days(), &language.short_week_days());
+            assert!(language.am_pm().iter().all(|value| !value.is_empty()));
+        }
+
+        // English abbreviations are always exactly three letters.
+        assert!(Language::en
+            .short_month_names()
+            .iter()
+            .chain(Language::en.short_week_days().iter())
+            .all(|name| name.len() == 3));
+    }
+
+    #[test]
+    fn names_are_not_prefixes() {
+        // Parsing takes the first name that matches without regard to case,
+        // so no name may be a prefix of another name in the same list.
+        fn assert_no_prefixes(names: &[&str]) {
+            for (i, a) in names.iter().enumerate() {
+                for (j, b) in names.iter().enumerate() {
+                    assert!(
+                        i == j || !b.to_lowercase().starts_with(&a.to_lowercase()),
+                        "{} is a prefix of {}",
+                        a,
+                        b
+                    );
+                }
+            }
+        }
+
+        for &language in Language::variants() {
+            assert_no_prefixes(&language.month_names());
+            assert_no_prefixes(&language.month_names_genitive());
+            assert_no_prefixes(&language.short_month_names());
+            assert_no_prefixes(&language.week_days());
+            assert_no_prefixes(&language.short_week_days());
         }
     }
 }
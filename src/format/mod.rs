@@ -32,6 +32,8 @@ macro_rules! pad {
 }
 
 pub(crate) mod date;
+pub(crate) mod date_time;
+pub(crate) mod duration;
 pub(crate) mod language;
 pub(crate) mod offset;
 pub(crate) mod parse;
@@ -40,13 +42,13 @@ pub(crate) mod time;
 
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-use crate::{Date, Time, UtcOffset};
+use crate::{Date, DateTime, Duration, Time, UtcOffset};
 use core::fmt::{self, Display, Formatter};
 #[allow(unreachable_pub)] // rust-lang/rust#64762
 pub use language::Language;
+pub(crate) use parse::{parse, parse_strict, parse_with_position, ParseResult, ParsedItems};
 #[allow(unreachable_pub)] // rust-lang/rust#64762
-pub use parse::ParseError;
-pub(crate) use parse::{parse, ParseResult, ParsedItems};
+pub use parse::{Component, ParseError, ParseErrorAt};
 pub(crate) use parse_items::parse_with_language;
 
 /// The type of padding to use when formatting.
@@ -71,6 +73,16 @@ impl Padding {
             _ => self,
         }
     }
+
+    /// The character used to pad values, if any.
+    #[inline(always)]
+    pub(crate) fn pad_char(self) -> Option<char> {
+        match self {
+            Self::Space => Some(' '),
+            Self::Zero => Some('0'),
+            Self::None | Self::Default => None,
+        }
+    }
 }
 
 /// Specifiers are similar to C's `strftime`, with some omissions and changes.
@@ -89,17 +101,19 @@ pub(crate) enum Specifier {
     b { language: Language },
     /// Full month name
     B { language: Language },
-    /// Date and time representation
-    c { language: Language },
     /// Year divided by 100 and truncated to integer (`00`-`99`)
     C { padding: Padding },
     /// Day of the month, zero-padded (`01`-`31`)
     d { padding: Padding },
-    /// Short MM/DD/YY date, equivalent to `%m/%d/%y`
+    /// Short MM/DD/YY date, equivalent to `%-m/%d/%y`
     D,
     /// Day of the month, space-padded (` 1`-`31`)
     e { padding: Padding },
-    /// Short YYYY-MM-DD date, equivalent to `%Y-%m-%d`
+    /// Subsecond to the provided number of digits (`1`-`9`), either truncated
+    /// or rounded. If trimmed, trailing zeros are omitted, and nothing is
+    /// formatted for a zero subsecond.
+    f { digits: u8, round: bool, trim: bool },
+    /// Short YYYY-MM-DD date, equivalent to `%-Y-%m-%d`
     F,
     /// Week-based year, last two digits (`00`-`99`)
     g { padding: Padding },
@@ -111,21 +125,33 @@ pub(crate) enum Specifier {
     I { padding: Padding },
     /// Day of the year (`001`-`366`)
     j { padding: Padding },
+    /// Year in the Buddhist era (Gregorian year + 543)
+    K { padding: Padding },
+    /// Milliseconds, zero-padded (`000`-`999`)
+    L,
     /// Month as a decimal number (`01`-`12`)
     m { padding: Padding },
     /// Minute (`00`-`59`)
     M { padding: Padding },
+    /// Full month name in the genitive case, as used in a date context
+    OB { language: Language },
+    /// Day of the month with its ordinal suffix (`1st`-`31st`)
+    o { language: Language },
     /// `am` or `pm` designation
-    p,
+    p { language: Language },
     /// `AM` or `PM` designation
-    P,
+    P { language: Language },
+    /// Quarter of the year (`1`-`4`)
+    q,
     /// 12-hour clock time
-    r,
-    /// 24-hour HH:MM time, equivalent to `%H:%M`
+    r { language: Language },
+    /// 24-hour HH:MM time, equivalent to `%-H:%M`
     R,
+    /// Number of seconds since the Unix epoch, which may be negative
+    s,
     /// Second (`00`-`59`)
     S { padding: Padding },
-    /// ISO 8601 time format (HH:MM:SS), equivalent to `%H:%M:%S`
+    /// ISO 8601 time format (HH:MM:SS), equivalent to `%-H:%M:%S`
     T,
     /// ISO 8601 weekday as number with Monday as 1 (`1`-`7`)
     u,
@@ -141,13 +167,50 @@ pub(crate) enum Specifier {
     y { padding: Padding },
     /// Year
     Y { padding: Padding },
-    /// UTC offset
-    z,
+    /// UTC offset, with the number of colons being `0` (`+HHMM`), `1`
+    /// (`+HH:MM`), or `2` (`+HH:MM:SS`)
+    z { colons: u8 },
+    /// Time zone abbreviation. As only the offset is known, this is `UTC` for
+    /// a zero offset and `+HH:MM` otherwise.
+    Z,
+}
+
+impl Specifier {
+    /// Split a numerical specifier into its unpadded form, the padding it uses
+    /// by default, and the width it is padded to by default. Returns `None` if
+    /// the specifier does not support padding.
+    #[inline]
+    pub(crate) fn split_padding(self) -> Option<(Self, Padding, u8)> {
+        use Specifier::*;
+        let padding = Padding::None;
+
+        Some(match self {
+            C { .. } => (C { padding }, Padding::Zero, 2),
+            d { .. } => (d { padding }, Padding::Zero, 2),
+            e { .. } => (e { padding }, Padding::Space, 2),
+            g { .. } => (g { padding }, Padding::Zero, 2),
+            G { .. } => (G { padding }, Padding::Zero, 4),
+            H { .. } => (H { padding }, Padding::Zero, 2),
+            I { .. } => (I { padding }, Padding::Zero, 2),
+            j { .. } => (j { padding }, Padding::Zero, 3),
+            K { .. } => (K { padding }, Padding::Zero, 4),
+            m { .. } => (m { padding }, Padding::Zero, 2),
+            M { .. } => (M { padding }, Padding::Zero, 2),
+            S { .. } => (S { padding }, Padding::Zero, 2),
+            U { .. } => (U { padding }, Padding::Zero, 2),
+            V { .. } => (V { padding }, Padding::Zero, 2),
+            W { .. } => (W { padding }, Padding::Zero, 2),
+            y { .. } => (y { padding }, Padding::Zero, 2),
+            Y { .. } => (Y { padding }, Padding::Zero, 4),
+            _ => return None,
+        })
+    }
 }
 
 /// Given all the information necessary, write the provided specifier to the
 /// formatter.
 #[inline]
+#[allow(clippy::too_many_lines)]
 fn format_specifier(
     f: &mut Formatter<'_>,
     date: Option<Date>,
@@ -161,7 +224,7 @@ fn format_specifier(
     // TODO (future) Some way to concatenate identifiers/paths without hacks
     // would be super!
     macro_rules! specifier {
-        ($type:ident :: $specifier_fn:ident ( $specifier:ident $(, $param:expr)? )) => {
+        ($type:ident :: $specifier_fn:ident ( $specifier:ident $(, $param:expr)* )) => {
             $type::$specifier_fn(
                 f,
                 $type.expect(concat!(
@@ -171,7 +234,7 @@ fn format_specifier(
                     stringify!($type),
                     " to be present."
                 )),
-                $($param)?
+                $($param),*
             )?
         };
     }
@@ -188,21 +251,6 @@ fn format_specifier(
         A { language } => specifier!(date::fmt_A(A, language)),
         b { language } => specifier!(date::fmt_b(b, language)),
         B { language } => specifier!(date::fmt_B(B, language)),
-        c { language } => {
-            specifier!(date::fmt_a(a, language));
-            literal!(" ");
-            specifier!(date::fmt_b(b, language));
-            literal!(" ");
-            specifier!(date::fmt_d(d, Padding::None));
-            literal!(" ");
-            specifier!(time::fmt_H(H, Padding::None));
-            literal!(":");
-            specifier!(time::fmt_M(M, Padding::Default));
-            literal!(":");
-            specifier!(time::fmt_S(S, Padding::Default));
-            literal!(" ");
-            specifier!(date::fmt_Y(Y, Padding::None));
-        }
         C { padding } => specifier!(date::fmt_C(C, padding)),
         d { padding } => specifier!(date::fmt_d(d, padding)),
         D => {
@@ -213,6 +261,8 @@ fn format_specifier(
             specifier!(date::fmt_y(y, Padding::Default));
         }
         e { padding } => specifier!(date::fmt_e(e, padding)),
+        // Any rounding has already been performed on the `Time`.
+        f { digits, trim, .. } => specifier!(time::fmt_f(f, digits, trim)),
         F => {
             specifier!(date::fmt_Y(Y, Padding::None));
             literal!("-");
@@ -225,24 +275,37 @@ fn format_specifier(
         H { padding } => specifier!(time::fmt_H(H, padding)),
         I { padding } => specifier!(time::fmt_I(I, padding)),
         j { padding } => specifier!(date::fmt_j(j, padding)),
+        K { padding } => specifier!(date::fmt_K(K, padding)),
+        L => specifier!(time::fmt_L(L)),
         m { padding } => specifier!(date::fmt_m(m, padding)),
         M { padding } => specifier!(time::fmt_M(M, padding)),
-        p => specifier!(time::fmt_p(p)),
-        P => specifier!(time::fmt_P(P)),
-        r => {
+        OB { language } => specifier!(date::fmt_OB(OB, language)),
+        o { language } => specifier!(date::fmt_o(o, language)),
+        p { language } => specifier!(time::fmt_p(p, language)),
+        P { language } => specifier!(time::fmt_P(P, language)),
+        q => specifier!(date::fmt_q(q)),
+        r { language } => {
             specifier!(time::fmt_I(I, Padding::None));
             literal!(":");
             specifier!(time::fmt_M(M, Padding::Default));
             literal!(":");
             specifier!(time::fmt_S(S, Padding::Default));
             literal!(" ");
-            specifier!(time::fmt_p(p));
+            specifier!(time::fmt_p(p, language));
         }
         R => {
             specifier!(time::fmt_H(H, Padding::None));
             literal!(":");
             specifier!(time::fmt_M(M, Padding::Default));
         }
+        s => date_time::fmt_s(
+            f,
+            DateTime::new(
+                date.expect("Specifier `%s` requires a date to be present."),
+                time.expect("Specifier `%s` requires a time to be present."),
+            ),
+            offset,
+        )?,
         S { padding } => specifier!(time::fmt_S(S, padding)),
         T => {
             specifier!(time::fmt_H(H, Padding::None));
@@ -258,7 +321,8 @@ fn format_specifier(
         W { padding } => specifier!(date::fmt_W(W, padding)),
         y { padding } => specifier!(date::fmt_y(y, padding)),
         Y { padding } => specifier!(date::fmt_Y(Y, padding)),
-        z => specifier!(offset::fmt_z(z)),
+        z { colons } => specifier!(offset::fmt_z(z, colons)),
+        Z => specifier!(offset::fmt_Z(Z)),
     }
 
     Ok(())
@@ -272,6 +336,61 @@ pub(crate) enum FormatItem<'a> {
     Literal(&'a str),
     /// A value that needs to be interpreted when formatting.
     Specifier(Specifier),
+    /// A numerical value occupying a fixed width, such as `%04Y`. The
+    /// specifier itself is unpadded, and the padding is never `Default`.
+    FixedWidth {
+        /// The specifier, without any padding of its own.
+        specifier: Specifier,
+        /// The number of characters the value occupies.
+        width: u8,
+        /// The padding used to reach the width.
+        padding: Padding,
+    },
+}
+
+/// The output of a single specifier, used to measure it before padding.
+struct FormattedSpecifier {
+    /// The `Date` to use for formatting.
+    date: Option<Date>,
+    /// The `Time` to use for formatting.
+    time: Option<Time>,
+    /// The `UtcOffset` to use for formatting.
+    offset: Option<UtcOffset>,
+    /// The specifier to format.
+    specifier: Specifier,
+}
+
+impl Display for FormattedSpecifier {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        format_specifier(f, self.date, self.time, self.offset, self.specifier)
+    }
+}
+
+/// Write the value, padded to the provided width. When padding with zeros, the
+/// padding is placed after any sign.
+#[inline]
+fn write_fixed_width(
+    f: &mut Formatter<'_>,
+    value: &str,
+    width: u8,
+    padding: Padding,
+) -> fmt::Result {
+    let pad_char = match padding.pad_char() {
+        Some(pad_char) => pad_char,
+        None => return f.write_str(value),
+    };
+
+    let (sign, digits) = match value.chars().next() {
+        Some(c @ '+') | Some(c @ '-') if pad_char == '0' => value.split_at(c.len_utf8()),
+        _ => ("", value),
+    };
+
+    f.write_str(sign)?;
+    for _ in value.len()..width as usize {
+        write!(f, "{}", pad_char)?;
+    }
+    f.write_str(digits)
 }
 
 /// A struct containing all the necessary information to display the inner type.
@@ -288,14 +407,79 @@ pub(crate) struct DeferredFormat<'a> {
     pub(crate) format: Vec<FormatItem<'a>>,
 }
 
+impl DeferredFormat<'_> {
+    /// Round the subsecond component of the time, if requested by any
+    /// specifier. When multiple specifiers request rounding, the one with the
+    /// fewest digits is used. If rounding carries into the next day, the date
+    /// is advanced as well. As this must not panic, the value is truncated
+    /// instead if the date cannot be advanced.
+    #[inline]
+    fn rounded_date_time(&self) -> (Option<Date>, Option<Time>) {
+        let digits = self
+            .format
+            .iter()
+            .filter_map(|item| match item {
+                FormatItem::Specifier(Specifier::f {
+                    digits,
+                    round: true,
+                    ..
+                }) => Some(*digits),
+                _ => None,
+            })
+            .min();
+
+        let (time, digits) = match (self.time, digits) {
+            (Some(time), Some(digits)) => (time, digits),
+            _ => return (self.date, self.time),
+        };
+
+        let unit = 10_u32.pow(9 - digits as u32);
+        let remainder = time.nanosecond() % unit;
+
+        // Round half away from zero. As the time is never negative, this is
+        // the same as rounding half up.
+        if remainder * 2 < unit {
+            return (self.date, self.time);
+        }
+
+        let rounded = time + Duration::nanoseconds((unit - remainder) as i64);
+        if rounded.nanoseconds_since_midnight() > time.nanoseconds_since_midnight() {
+            return (self.date, Some(rounded));
+        }
+
+        // The carry reached the next day. If there is no next day to carry
+        // into, the value is truncated instead.
+        match self.date.map(Date::checked_next_day) {
+            Some(None) => (self.date, self.time),
+            date => (date.flatten(), Some(rounded)),
+        }
+    }
+}
+
 impl Display for DeferredFormat<'_> {
     #[inline(always)]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (date, time) = self.rounded_date_time();
+
         for item in &self.format {
             match item {
                 FormatItem::Literal(value) => f.write_str(value)?,
                 FormatItem::Specifier(specifier) => {
-                    format_specifier(f, self.date, self.time, self.offset, *specifier)?
+                    format_specifier(f, date, time, self.offset, *specifier)?
+                }
+                &FormatItem::FixedWidth {
+                    specifier,
+                    width,
+                    padding,
+                } => {
+                    let value = FormattedSpecifier {
+                        date,
+                        time,
+                        offset: self.offset,
+                        specifier,
+                    }
+                    .to_string();
+                    write_fixed_width(f, &value, width, padding)?;
                 }
             }
         }
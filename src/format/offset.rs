@@ -3,32 +3,42 @@
 #![allow(non_snake_case)]
 
 use super::{
-    parse::{try_consume_exact_digits_in_range, try_consume_first_match},
+    parse::{try_consume_char, try_consume_exact_digits_in_range, try_consume_first_match},
     Padding, ParseError, ParseResult, ParsedItems,
 };
 use crate::{Sign, UtcOffset};
 use core::fmt::{self, Formatter};
 
-/// UTC offset
+/// UTC offset, with the provided number of colons (`+HHMM`, `+HH:MM`, or
+/// `+HH:MM:SS`). Only the last includes the seconds.
 #[inline(always)]
-pub(crate) fn fmt_z(f: &mut Formatter<'_>, offset: UtcOffset) -> fmt::Result {
-    let offset = offset.as_duration();
-
-    write!(
-        f,
-        "{}{:02}{:02}",
-        match offset.sign() {
-            Sign::Positive | Sign::Zero => "+",
-            Sign::Negative => "-",
-        },
-        offset.whole_hours().abs(),
-        (offset.whole_minutes() - 60 * offset.whole_hours()).abs()
-    )
+pub(crate) fn fmt_z(f: &mut Formatter<'_>, offset: UtcOffset, colons: u8) -> fmt::Result {
+    let seconds = offset.as_seconds();
+    let sign = if seconds < 0 { "-" } else { "+" };
+    let seconds = seconds.abs();
+    let (hours, minutes, seconds) = (seconds / 3_600, seconds / 60 % 60, seconds % 60);
+
+    match colons {
+        0 => write!(f, "{}{:02}{:02}", sign, hours, minutes),
+        1 => write!(f, "{}{:02}:{:02}", sign, hours, minutes),
+        _ => write!(f, "{}{:02}:{:02}:{:02}", sign, hours, minutes, seconds),
+    }
 }
 
-/// UTC offset
+/// UTC offset, with the provided number of colons.
+///
+/// When parsing, `Z` is accepted as UTC. Otherwise, the minutes and seconds
+/// are optional, such that `%z` accepts `+HH` and `+HHMM`. With one colon,
+/// the colon is also optional (`+HH`, `+HH:MM`, or `+HHMM`). Only with two
+/// colons are the seconds accepted, with both colons optional (`+HH`,
+/// `+HH:MM`, `+HH:MM:SS`, or `+HHMMSS`).
 #[inline(always)]
-pub(crate) fn parse_z(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+pub(crate) fn parse_z(items: &mut ParsedItems, s: &mut &str, colons: u8) -> ParseResult<()> {
+    if try_consume_char(s, 'Z').is_ok() {
+        items.offset = UtcOffset::UTC.into();
+        return Ok(());
+    }
+
     let sign = try_consume_first_match(
         s,
         [("+", Sign::Positive), ("-", Sign::Negative)]
@@ -37,12 +47,67 @@ pub(crate) fn parse_z(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()>
     )
     .ok_or(ParseError::InvalidOffset)?;
 
-    let hours: i16 = try_consume_exact_digits_in_range(s, 2, 0..24, Padding::Zero)
+    let hours: i32 = try_consume_exact_digits_in_range(s, 2, 0..24, Padding::Zero)
         .ok_or(ParseError::InvalidOffset)?;
+    let minutes = try_consume_component(s, colons >= 1)?;
+    let seconds = match minutes {
+        Some(_) if colons >= 2 => try_consume_component(s, true)?,
+        _ => None,
+    };
 
-    let minutes = try_consume_exact_digits_in_range(s, 2, 0..60, Padding::Zero)
-        .ok_or(ParseError::InvalidOffset)?;
+    items.offset = UtcOffset::seconds(
+        sign * (hours * 3_600 + minutes.unwrap_or(0) * 60 + seconds.unwrap_or(0)),
+    )
+    .into();
+    Ok(())
+}
+
+/// Time zone abbreviation. Only the offset is known, so this is `UTC` for a
+/// zero offset and `+HH:MM` (or `+HH:MM:SS` if needed) otherwise.
+#[inline(always)]
+pub(crate) fn fmt_Z(f: &mut Formatter<'_>, offset: UtcOffset) -> fmt::Result {
+    if offset == UtcOffset::UTC {
+        f.write_str("UTC")
+    } else if offset.as_seconds() % 60 == 0 {
+        fmt_z(f, offset, 1)
+    } else {
+        fmt_z(f, offset, 2)
+    }
+}
+
+/// Time zone abbreviation.
+///
+/// When parsing, an alphabetic abbreviation such as `PST` is consumed, but
+/// only `UTC` and `GMT` are resolved to an offset, as no time zone database is
+/// available. A numeric offset is accepted as with `%::z`.
+#[inline(always)]
+pub(crate) fn parse_Z(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    let len = s
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(s.len());
+    if len == 0 {
+        return parse_z(items, s, 2);
+    }
 
-    items.offset = UtcOffset::minutes(sign * hours * 60 + minutes).into();
+    let name = &s[..len];
+    if name == "UTC" || name == "GMT" {
+        items.offset = UtcOffset::UTC.into();
+    }
+    *s = &s[len..];
     Ok(())
 }
+
+/// Attempt to consume an optional two-digit component of the offset, which
+/// may be preceded by a colon if permitted. A colon must be followed by the
+/// component.
+#[inline(always)]
+fn try_consume_component(s: &mut &str, allow_colon: bool) -> ParseResult<Option<i32>> {
+    let has_colon = allow_colon && try_consume_char(s, ':').is_ok();
+    if !has_colon && !s.starts_with(|c: char| c.is_ascii_digit()) {
+        return Ok(None);
+    }
+
+    try_consume_exact_digits_in_range(s, 2, 0..60, Padding::Zero)
+        .map(Some)
+        .ok_or(ParseError::InvalidOffset)
+}
@@ -17,6 +17,8 @@ pub(crate) type ParseResult<T> = Result<T, ParseError>;
 /// An error ocurred while parsing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ParseError {
+    /// The subsecond present was not valid.
+    InvalidSubsecond,
     /// The second present was not valid.
     InvalidSecond,
     /// The minute present was not valid.
@@ -27,6 +29,8 @@ pub enum ParseError {
     InvalidAmPm,
     /// The month present was not valid.
     InvalidMonth,
+    /// The quarter present was not valid.
+    InvalidQuarter,
     /// The year present was not valid.
     InvalidYear,
     /// The week present was not valid.
@@ -39,6 +43,14 @@ pub enum ParseError {
     InvalidDayOfYear,
     /// The UTC offset present was not valid.
     InvalidOffset,
+    /// The Unix timestamp present was not valid.
+    InvalidTimestamp,
+    /// A component of the duration present was not valid.
+    InvalidDuration,
+    /// The unit of a duration component was not recognized.
+    InvalidUnit,
+    /// The language code was not recognized.
+    InvalidLanguage,
     /// There was no character following a `%`.
     MissingFormatSpecifier,
     /// The character following `%` is not valid.
@@ -52,8 +64,17 @@ pub enum ParseError {
     },
     /// The string ended, but there should be more content.
     UnexpectedEndOfString,
+    /// The format was exhausted, but the string contained more content. This is
+    /// only returned when parsing strictly.
+    UnexpectedTrailingCharacters,
     /// There was not enough information provided to create the requested type.
-    InsufficientInformation,
+    InsufficientInformation {
+        /// The component that could not be determined.
+        missing: Component,
+    },
+    /// The information provided was contradictory, such as a weekday that does
+    /// not match the date.
+    ComponentConflict,
     #[allow(non_camel_case_types)]
     #[doc(hidden)]
     __nonexhaustive,
@@ -64,26 +85,36 @@ impl Display for ParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         use ParseError::*;
         match self {
+            InvalidSubsecond => f.write_str("invalid subsecond"),
             InvalidSecond => f.write_str("invalid second"),
             InvalidMinute => f.write_str("invalid minute"),
             InvalidHour => f.write_str("invalid hour"),
             InvalidAmPm => f.write_str("invalid am/pm"),
             InvalidMonth => f.write_str("invalid month"),
+            InvalidQuarter => f.write_str("invalid quarter"),
             InvalidYear => f.write_str("invalid year"),
             InvalidWeek => f.write_str("invalid week"),
             InvalidDayOfWeek => f.write_str("invalid day of week"),
             InvalidDayOfMonth => f.write_str("invalid day of month"),
             InvalidDayOfYear => f.write_str("invalid day of year"),
             InvalidOffset => f.write_str("invalid offset"),
+            InvalidTimestamp => f.write_str("invalid timestamp"),
+            InvalidDuration => f.write_str("invalid duration"),
+            InvalidUnit => f.write_str("invalid unit"),
+            InvalidLanguage => f.write_str("invalid language"),
             MissingFormatSpecifier => f.write_str("missing format specifier after `%`"),
             InvalidFormatSpecifier(c) => write!(f, "invalid format specifier `{}` after `%`", c),
             UnexpectedCharacter { expected, actual } => {
                 write!(f, "expected character `{}`, found `{}`", expected, actual)
             }
             UnexpectedEndOfString => f.write_str("unexpected end of string"),
-            InsufficientInformation => {
-                f.write_str("insufficient information provided to create the requested type")
-            }
+            UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
+            InsufficientInformation { missing } => write!(
+                f,
+                "insufficient information provided to determine the {}",
+                missing
+            ),
+            ComponentConflict => f.write_str("the parsed components conflict with each other"),
             __nonexhaustive => panic!(
                 "`__nonexhaustive` is hidden in the documentation for a reason! Don't use it."
             ),
@@ -94,6 +125,90 @@ impl Display for ParseError {
 #[cfg(feature = "std")]
 impl Error for ParseError {}
 
+/// A component of a type that can be parsed. Used to indicate which component
+/// could not be determined from the information provided.
+///
+/// ```rust
+/// # use time::{Component, OffsetDateTime, ParseError};
+/// assert_eq!(
+///     OffsetDateTime::parse("2019-01-02", "%F"),
+///     Err(ParseError::InsufficientInformation {
+///         missing: Component::Time
+///     })
+/// );
+/// ```
+#[cfg_attr(feature = "unstable", non_exhaustive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Component {
+    /// The calendar date.
+    Date,
+    /// The time of day.
+    Time,
+    /// The offset from UTC.
+    Offset,
+}
+
+impl Display for Component {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Date => f.write_str("date"),
+            Self::Time => f.write_str("time"),
+            Self::Offset => f.write_str("offset"),
+        }
+    }
+}
+
+/// A `ParseError` along with the position in the input at which it occurred.
+///
+/// ```rust
+/// # use time::{Date, ParseError};
+/// let error = Date::parse_with_position("2019-1x-01", "%Y-%m-%d").unwrap_err();
+/// assert_eq!(error.kind(), ParseError::InvalidMonth);
+/// assert_eq!(error.index(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseErrorAt {
+    /// The error that occurred.
+    pub(crate) kind: ParseError,
+    /// The byte index into the input at which the error occurred.
+    pub(crate) index: usize,
+}
+
+impl ParseErrorAt {
+    /// Get the error that occurred.
+    #[inline(always)]
+    pub const fn kind(self) -> ParseError {
+        self.kind
+    }
+
+    /// Get the byte index into the input at which the error occurred. This is
+    /// the start of the component that could not be parsed. For errors that
+    /// are only detected once all components have been parsed, such as an
+    /// invalid day of the month, this is the end of the consumed input.
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self.index
+    }
+}
+
+impl Display for ParseErrorAt {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseErrorAt {}
+
+impl From<ParseErrorAt> for ParseError {
+    #[inline(always)]
+    fn from(error: ParseErrorAt) -> Self {
+        error.kind
+    }
+}
+
 /// A value representing a time that is either "AM" or "PM".
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum AmPm {
@@ -112,6 +227,8 @@ pub(crate) struct ParsedItems {
     pub(crate) year: Option<i32>,
     /// One-indexed month number.
     pub(crate) month: Option<NonZeroU8>,
+    /// Quarter of the year (`1`-`4`).
+    pub(crate) quarter: Option<NonZeroU8>,
     /// Day of the month.
     pub(crate) day: Option<NonZeroU8>,
     /// Day of the week.
@@ -132,10 +249,14 @@ pub(crate) struct ParsedItems {
     pub(crate) minute: Option<u8>,
     /// Second within the minute.
     pub(crate) second: Option<u8>,
+    /// Nanoseconds within the second.
+    pub(crate) nanosecond: Option<u32>,
     /// The UTC offset of the datetime.
     pub(crate) offset: Option<UtcOffset>,
     /// Whether the hour indicated is AM or PM.
     pub(crate) am_pm: Option<AmPm>,
+    /// Number of seconds since the Unix epoch.
+    pub(crate) timestamp: Option<i64>,
 }
 
 impl ParsedItems {
@@ -146,6 +267,7 @@ impl ParsedItems {
             week_based_year: None,
             year: None,
             month: None,
+            quarter: None,
             day: None,
             weekday: None,
             ordinal_day: None,
@@ -156,8 +278,10 @@ impl ParsedItems {
             hour_24: None,
             minute: None,
             second: None,
+            nanosecond: None,
             offset: None,
             am_pm: None,
+            timestamp: None,
         }
     }
 }
@@ -208,6 +332,38 @@ pub(crate) fn try_consume_first_match<T: Copy>(
     })
 }
 
+/// Attempt to find one of the strings provided, ignoring case, returning the
+/// first value.
+#[inline]
+pub(crate) fn try_consume_first_match_ci<T: Copy>(
+    s: &mut &str,
+    opts: impl IntoIterator<Item = (impl AsRef<str>, T)>,
+) -> Option<T> {
+    opts.into_iter().find_map(|(expected, value)| {
+        let len = case_insensitive_prefix_len(s, expected.as_ref())?;
+        *s = &s[len..];
+        Some(value)
+    })
+}
+
+/// If the string starts with the prefix when ignoring case, get the length of
+/// the matching portion of the string in bytes. This may differ from the
+/// length of the prefix, as a character and its other case are not always
+/// encoded with the same number of bytes.
+#[inline]
+fn case_insensitive_prefix_len(s: &str, prefix: &str) -> Option<usize> {
+    let mut chars = s.char_indices();
+
+    for expected in prefix.chars() {
+        let (_, actual) = chars.next()?;
+        if !actual.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+
+    Some(chars.next().map_or(s.len(), |(index, _)| index))
+}
+
 /// Attempt to consume a number of digits. Consumes the maximum amount possible
 /// within the range provided.
 #[inline]
@@ -275,11 +431,12 @@ pub(crate) fn try_consume_exact_digits<T: FromStr>(
     if padding == Padding::None {
         try_consume_digits(s, 1..=(num_digits - pad_size))
     } else {
-        // Ensure all the necessary characters are ASCII digits.
-        if !s
-            .chars()
-            .take(num_digits - pad_size)
-            .all(|c| c.is_ascii_digit())
+        // Ensure all the necessary characters are present and are ASCII digits.
+        if s.len() < num_digits - pad_size
+            || !s
+                .chars()
+                .take(num_digits - pad_size)
+                .all(|c| c.is_ascii_digit())
         {
             return None;
         }
@@ -288,7 +445,7 @@ pub(crate) fn try_consume_exact_digits<T: FromStr>(
         // length is equal to the number of bytes, as ASCII values are always one
         // byte in Unicode.
         let digits = &s[..(num_digits - pad_size)];
-        *s = &s[num_digits..];
+        *s = &s[(num_digits - pad_size)..];
         digits.parse::<T>().ok()
     }
 }
@@ -332,113 +489,206 @@ pub(crate) fn consume_padding(s: &mut &str, padding: Padding, max_chars: usize)
 
 /// Attempt to parse the string with the provided format and language, returning
 /// a struct containing all information found.
+#[inline(always)]
+pub(crate) fn parse(mut s: &str, format: &str, language: Language) -> ParseResult<ParsedItems> {
+    parse_items(&mut s, format, language)
+}
+
+/// Attempt to parse the string with the provided format and language,
+/// returning a struct containing all information found. Unlike `parse`, an
+/// error is returned if any input remains once the format is exhausted.
+#[inline]
+pub(crate) fn parse_strict(
+    mut s: &str,
+    format: &str,
+    language: Language,
+) -> ParseResult<ParsedItems> {
+    let items = parse_items(&mut s, format, language)?;
+
+    if s.is_empty() {
+        Ok(items)
+    } else {
+        Err(ParseError::UnexpectedTrailingCharacters)
+    }
+}
+
+/// Attempt to parse the string with the provided format and language,
+/// converting the information found with the provided function. Any error
+/// includes the position in the input at which parsing stopped.
 #[inline]
-pub(crate) fn parse(s: &str, format: &str, language: Language) -> ParseResult<ParsedItems> {
-    use super::{date, offset, time};
+pub(crate) fn parse_with_position<T>(
+    s: &str,
+    format: &str,
+    language: Language,
+    convert: impl FnOnce(&ParsedItems) -> ParseResult<T>,
+) -> Result<T, ParseErrorAt> {
+    let mut remaining = s;
+    parse_items(&mut remaining, format, language)
+        .and_then(|items| convert(&items))
+        .map_err(|kind| ParseErrorAt {
+            kind,
+            index: s.len() - remaining.len(),
+        })
+}
+
+/// Attempt to parse a value with each of the provided formats in turn,
+/// returning the first success. If every format fails, the error from the last
+/// one is returned. As nothing can be parsed without a format, an empty slice
+/// results in the date being reported as missing.
+#[inline]
+pub(crate) fn parse_any<T>(
+    formats: &[&str],
+    mut parse_fn: impl FnMut(&str) -> ParseResult<T>,
+) -> ParseResult<T> {
+    let mut result = Err(ParseError::InsufficientInformation {
+        missing: Component::Date,
+    });
+
+    for format in formats {
+        result = parse_fn(format);
+        if result.is_ok() {
+            break;
+        }
+    }
 
-    // Make a copy of the provided string, letting us mutate as necessary.
-    let mut s = <&str>::clone(&s);
+    result
+}
 
+/// Attempt to parse the string with the provided format and language, returning
+/// a struct containing all information found. The string is advanced past all
+/// input that was consumed. If an error occurs, the string is left at the
+/// start of the item that could not be parsed.
+#[inline]
+fn parse_items(s: &mut &str, format: &str, language: Language) -> ParseResult<ParsedItems> {
     let mut items = ParsedItems::new();
 
+    for item in parse_with_language(format, language) {
+        let start = *s;
+        if let Err(error) = parse_item(&mut items, s, &item) {
+            *s = start;
+            return Err(error);
+        }
+    }
+
+    Ok(items)
+}
+
+/// Parse a single item of the format, recording any information found.
+#[inline]
+#[allow(clippy::too_many_lines)]
+fn parse_item(items: &mut ParsedItems, input: &mut &str, item: &FormatItem<'_>) -> ParseResult<()> {
+    use super::{date, date_time, offset, time};
+
     /// Parse the provided specifier with the given parameters.
     macro_rules! parse {
         ($module:ident :: $specifier_fn:ident $( ( $($params:expr),* ) )?) => {
-            $module::$specifier_fn(&mut items, &mut s, $( $($params),* )?)?
+            $module::$specifier_fn(items, input, $( $($params),* )?)?
         };
     }
 
     macro_rules! parse_char {
         ($c:literal) => {
-            try_consume_char(&mut s, $c)?
+            try_consume_char(input, $c)?
         };
     }
 
-    for item in parse_with_language(format, language) {
-        match item {
-            FormatItem::Literal(expected) => try_consume_str(&mut s, expected)?,
-            FormatItem::Specifier(specifier) => {
-                use Specifier::*;
-                match specifier {
-                    a { language } => parse!(date::parse_a(language)),
-                    A { language } => parse!(date::parse_A(language)),
-                    b { language } => parse!(date::parse_b(language)),
-                    B { language } => parse!(date::parse_B(language)),
-                    c { language } => {
-                        parse!(date::parse_a(language));
-                        parse_char!(' ');
-                        parse!(date::parse_b(language));
-                        parse_char!(' ');
-                        parse!(date::parse_d(Padding::None));
-                        parse_char!(' ');
-                        parse!(time::parse_H(Padding::None));
-                        parse_char!(':');
-                        parse!(time::parse_M(Padding::Default));
-                        parse_char!(':');
-                        parse!(time::parse_S(Padding::Default));
-                        parse_char!(' ');
-                        parse!(date::parse_Y(Padding::None));
-                    }
-                    C { padding } => parse!(date::parse_C(padding)),
-                    d { padding } => parse!(date::parse_d(padding)),
-                    D => {
-                        parse!(date::parse_m(Padding::Default));
-                        parse_char!('/');
-                        parse!(date::parse_d(Padding::Default));
-                        parse_char!('/');
-                        parse!(date::parse_y(Padding::Default));
-                    }
-                    e { padding } => parse!(date::parse_e(padding)),
-                    F => {
-                        parse!(date::parse_Y(Padding::None));
-                        parse_char!('-');
-                        parse!(date::parse_m(Padding::Default));
-                        parse_char!('-');
-                        parse!(date::parse_d(Padding::Default));
-                    }
-                    g { padding } => parse!(date::parse_g(padding)),
-                    G { padding } => parse!(date::parse_G(padding)),
-                    H { padding } => parse!(time::parse_H(padding)),
-                    I { padding } => parse!(time::parse_I(padding)),
-                    j { padding } => parse!(date::parse_j(padding)),
-                    M { padding } => parse!(time::parse_M(padding)),
-                    m { padding } => parse!(date::parse_m(padding)),
-                    p => parse!(time::parse_p),
-                    P => parse!(time::parse_P),
-                    r => {
-                        parse!(time::parse_I(Padding::None));
-                        parse_char!(':');
-                        parse!(time::parse_M(Padding::Default));
-                        parse_char!(':');
-                        parse!(time::parse_S(Padding::Default));
-                        parse_char!(' ');
-                        parse!(time::parse_p);
-                    }
-                    R => {
-                        parse!(time::parse_H(Padding::None));
-                        parse_char!(':');
-                        parse!(time::parse_M(Padding::Default));
-                    }
-                    S { padding } => parse!(time::parse_S(padding)),
-                    T => {
-                        parse!(time::parse_H(Padding::None));
-                        parse_char!(':');
-                        parse!(time::parse_M(Padding::Default));
-                        parse_char!(':');
-                        parse!(time::parse_S(Padding::Default));
-                    }
-                    u => parse!(date::parse_u),
-                    U { padding } => parse!(date::parse_U(padding)),
-                    V { padding } => parse!(date::parse_V(padding)),
-                    w => parse!(date::parse_w),
-                    W { padding } => parse!(date::parse_W(padding)),
-                    y { padding } => parse!(date::parse_y(padding)),
-                    z => parse!(offset::parse_z),
-                    Y { padding } => parse!(date::parse_Y(padding)),
+    match *item {
+        FormatItem::Literal(expected) => try_consume_str(input, expected)?,
+        FormatItem::FixedWidth {
+            specifier,
+            width,
+            padding,
+        } => {
+            // Only the provided width is available to the specifier. Padding
+            // is removed beforehand, leaving at least one character.
+            let end = input
+                .char_indices()
+                .nth(width as usize)
+                .map_or(input.len(), |(i, _)| i);
+            let mut field = &input[..end];
+            if let Some(pad_char) = padding.pad_char() {
+                while field.len() > 1 && field.starts_with(pad_char) {
+                    field = &field[1..];
                 }
             }
+
+            parse_item(items, &mut field, &FormatItem::Specifier(specifier))?;
+            *input = &input[(end - field.len())..];
+        }
+        FormatItem::Specifier(specifier) => {
+            use Specifier::*;
+            match specifier {
+                a { language } => parse!(date::parse_a(language)),
+                A { language } => parse!(date::parse_A(language)),
+                b { language } => parse!(date::parse_b(language)),
+                B { language } => parse!(date::parse_B(language)),
+                C { padding } => parse!(date::parse_C(padding)),
+                d { padding } => parse!(date::parse_d(padding)),
+                D => {
+                    parse!(date::parse_m(Padding::None));
+                    parse_char!('/');
+                    parse!(date::parse_d(Padding::Default));
+                    parse_char!('/');
+                    parse!(date::parse_y(Padding::Default));
+                }
+                e { padding } => parse!(date::parse_e(padding)),
+                f { digits, trim, .. } => parse!(time::parse_f(digits, trim)),
+                F => {
+                    parse!(date::parse_Y(Padding::None));
+                    parse_char!('-');
+                    parse!(date::parse_m(Padding::Default));
+                    parse_char!('-');
+                    parse!(date::parse_d(Padding::Default));
+                }
+                g { padding } => parse!(date::parse_g(padding)),
+                G { padding } => parse!(date::parse_G(padding)),
+                H { padding } => parse!(time::parse_H(padding)),
+                I { padding } => parse!(time::parse_I(padding)),
+                j { padding } => parse!(date::parse_j(padding)),
+                K { padding } => parse!(date::parse_K(padding)),
+                L => parse!(time::parse_L),
+                M { padding } => parse!(time::parse_M(padding)),
+                m { padding } => parse!(date::parse_m(padding)),
+                OB { language } => parse!(date::parse_OB(language)),
+                o { language } => parse!(date::parse_o(language)),
+                q => parse!(date::parse_q),
+                p { language } => parse!(time::parse_p(language)),
+                P { language } => parse!(time::parse_P(language)),
+                r { language } => {
+                    parse!(time::parse_I(Padding::None));
+                    parse_char!(':');
+                    parse!(time::parse_M(Padding::Default));
+                    parse_char!(':');
+                    parse!(time::parse_S(Padding::Default));
+                    parse_char!(' ');
+                    parse!(time::parse_p(language));
+                }
+                R => {
+                    parse!(time::parse_H(Padding::None));
+                    parse_char!(':');
+                    parse!(time::parse_M(Padding::Default));
+                }
+                s => parse!(date_time::parse_s),
+                S { padding } => parse!(time::parse_S(padding)),
+                T => {
+                    parse!(time::parse_H(Padding::None));
+                    parse_char!(':');
+                    parse!(time::parse_M(Padding::Default));
+                    parse_char!(':');
+                    parse!(time::parse_S(Padding::Default));
+                }
+                u => parse!(date::parse_u),
+                U { padding } => parse!(date::parse_U(padding)),
+                V { padding } => parse!(date::parse_V(padding)),
+                w => parse!(date::parse_w),
+                W { padding } => parse!(date::parse_W(padding)),
+                y { padding } => parse!(date::parse_y(padding)),
+                z { colons } => parse!(offset::parse_z(colons)),
+                Z => parse!(offset::parse_Z),
+                Y { padding } => parse!(date::parse_Y(padding)),
+            }
         }
     }
 
-    Ok(items)
+    Ok(())
 }
@@ -9,20 +9,13 @@ use crate::{
 
 /// Parse the formatting string with the provided language.
 #[inline]
+#[allow(clippy::too_many_lines)]
 pub(crate) fn parse_with_language<'a>(s: &'a str, language: Language) -> Vec<FormatItem<'a>> {
     let mut items = vec![];
     let mut literal_start = 0;
     let mut chars = s.char_indices().peekable();
 
     while let Some((i, c)) = chars.next() {
-        /// Push the provided specifier to the list of items.
-        macro_rules! push_specifier {
-            ($i:ident, $specifier:expr) => {{
-                literal_start = $i + 1;
-                items.push(FormatItem::Specifier($specifier))
-            }};
-        }
-
         if c == '%' {
             // Avoid adding unnecessary empty strings.
             if literal_start != i {
@@ -47,28 +40,140 @@ pub(crate) fn parse_with_language<'a>(s: &'a str, language: Language) -> Vec<For
                 _ => Padding::Default,
             };
 
+            // A tilde indicates that the value should be rounded rather than
+            // truncated. This is only supported by `%f` and `%N`.
+            let round = match chars.peek().map(|v| v.1) {
+                Some('~') => {
+                    let _ = chars.next();
+                    true
+                }
+                _ => false,
+            };
+
+            // A single digit following the modifier (if any) is the width.
+            // This is supported by `%f` and all specifiers that can be padded.
+            let width = match chars.peek().map(|v| v.1) {
+                Some(c @ '1'..='9') => {
+                    let _ = chars.next();
+                    Some(c as u8 - b'0')
+                }
+                _ => None,
+            };
+
+            // Colons are only supported by `%z`, and indicate that the
+            // components of the offset should be separated.
+            let mut colons = 0;
+            while colons < 2 && chars.peek().map(|v| v.1) == Some(':') {
+                let _ = chars.next();
+                colons += 1;
+            }
+
+            // An `O` requests the alternative form of the value. This is
+            // only supported by `%OB`. An `E` requests the era-based form of
+            // the value, which is supported by `%EC`, `%Ey`, and `%EY`. As no
+            // era data is available, these are identical to the plain
+            // specifiers.
+            let modifier = match chars.peek().map(|v| v.1) {
+                Some(c @ 'O') | Some(c @ 'E') => {
+                    let _ = chars.next();
+                    Some(c)
+                }
+                _ => None,
+            };
+
+            /// Push the provided specifier to the list of items. If a width was
+            /// provided and the specifier supports padding, the value occupies
+            /// exactly that width. As the value could not otherwise be parsed,
+            /// the width cannot be narrower than the default.
+            macro_rules! push_specifier {
+                ($i:ident, $specifier:expr) => {{
+                    literal_start = $i + 1;
+                    let specifier = $specifier;
+                    items.push(match (width, specifier.split_padding()) {
+                        (Some(width), Some((specifier, default_padding, default_width))) => {
+                            if width < default_width {
+                                panic!(
+                                    "The width of `%{}` cannot be less than {}",
+                                    &s[$i..=$i],
+                                    default_width
+                                );
+                            }
+                            FormatItem::FixedWidth {
+                                specifier,
+                                width,
+                                padding: padding.default_to(default_padding),
+                            }
+                        }
+                        _ => FormatItem::Specifier(specifier),
+                    })
+                }};
+            }
+
             match chars.next() {
+                Some((_, c)) if round && c != 'f' && c != 'N' => {
+                    panic!("Invalid specifier `~{}`", c);
+                }
+                Some((_, c)) if colons != 0 && c != 'z' => {
+                    panic!("Invalid specifier `{}{}`", &"::"[..usize::from(colons)], c);
+                }
+                Some((_, c)) if modifier == Some('O') && c != 'B' => {
+                    panic!("Invalid specifier `O{}`", c);
+                }
+                Some((_, c)) if modifier == Some('E') && c != 'C' && c != 'y' && c != 'Y' => {
+                    panic!("Invalid specifier `E{}`", c);
+                }
                 Some((i, 'a')) => push_specifier!(i, Specifier::a { language }),
                 Some((i, 'A')) => push_specifier!(i, Specifier::A { language }),
                 Some((i, 'b')) => push_specifier!(i, Specifier::b { language }),
+                Some((i, 'B')) if modifier == Some('O') => {
+                    push_specifier!(i, Specifier::OB { language });
+                }
                 Some((i, 'B')) => push_specifier!(i, Specifier::B { language }),
-                Some((i, 'c')) => push_specifier!(i, Specifier::c { language }),
+                // The preferred representations are expanded to the template
+                // for the language, and any modifiers are ignored.
+                Some((i, 'c')) => {
+                    literal_start = i + 1;
+                    items.extend(parse_with_language(language.date_time_format(), language));
+                }
                 Some((i, 'C')) => push_specifier!(i, Specifier::C { padding }),
                 Some((i, 'd')) => push_specifier!(i, Specifier::d { padding }),
                 Some((i, 'D')) => push_specifier!(i, Specifier::D),
                 Some((i, 'e')) => push_specifier!(i, Specifier::e { padding }),
+                // Without a width, `%f` is preceded by a `.` and omits any
+                // trailing zeros, while `%N` always has nine digits.
+                Some((i, 'f')) => push_specifier!(
+                    i,
+                    Specifier::f {
+                        digits: width.unwrap_or(9),
+                        round,
+                        trim: width.is_none(),
+                    }
+                ),
+                Some((i, 'N')) => push_specifier!(
+                    i,
+                    Specifier::f {
+                        digits: width.unwrap_or(9),
+                        round,
+                        trim: false,
+                    }
+                ),
                 Some((i, 'F')) => push_specifier!(i, Specifier::F),
                 Some((i, 'g')) => push_specifier!(i, Specifier::g { padding }),
                 Some((i, 'G')) => push_specifier!(i, Specifier::G { padding }),
                 Some((i, 'H')) => push_specifier!(i, Specifier::H { padding }),
                 Some((i, 'I')) => push_specifier!(i, Specifier::I { padding }),
                 Some((i, 'j')) => push_specifier!(i, Specifier::j { padding }),
+                Some((i, 'K')) => push_specifier!(i, Specifier::K { padding }),
+                Some((i, 'L')) => push_specifier!(i, Specifier::L),
                 Some((i, 'm')) => push_specifier!(i, Specifier::m { padding }),
                 Some((i, 'M')) => push_specifier!(i, Specifier::M { padding }),
-                Some((i, 'p')) => push_specifier!(i, Specifier::p),
-                Some((i, 'P')) => push_specifier!(i, Specifier::P),
-                Some((i, 'r')) => push_specifier!(i, Specifier::r),
+                Some((i, 'o')) => push_specifier!(i, Specifier::o { language }),
+                Some((i, 'p')) => push_specifier!(i, Specifier::p { language }),
+                Some((i, 'P')) => push_specifier!(i, Specifier::P { language }),
+                Some((i, 'q')) => push_specifier!(i, Specifier::q),
+                Some((i, 'r')) => push_specifier!(i, Specifier::r { language }),
                 Some((i, 'R')) => push_specifier!(i, Specifier::R),
+                Some((i, 's')) => push_specifier!(i, Specifier::s),
                 Some((i, 'S')) => push_specifier!(i, Specifier::S { padding }),
                 Some((i, 'T')) => push_specifier!(i, Specifier::T),
                 Some((i, 'u')) => push_specifier!(i, Specifier::u),
@@ -76,9 +181,18 @@ pub(crate) fn parse_with_language<'a>(s: &'a str, language: Language) -> Vec<For
                 Some((i, 'V')) => push_specifier!(i, Specifier::V { padding }),
                 Some((i, 'w')) => push_specifier!(i, Specifier::w),
                 Some((i, 'W')) => push_specifier!(i, Specifier::W { padding }),
+                Some((i, 'x')) => {
+                    literal_start = i + 1;
+                    items.extend(parse_with_language(language.date_format(), language));
+                }
+                Some((i, 'X')) => {
+                    literal_start = i + 1;
+                    items.extend(parse_with_language(language.time_format(), language));
+                }
                 Some((i, 'y')) => push_specifier!(i, Specifier::y { padding }),
                 Some((i, 'Y')) => push_specifier!(i, Specifier::Y { padding }),
-                Some((i, 'z')) => push_specifier!(i, Specifier::z),
+                Some((i, 'z')) => push_specifier!(i, Specifier::z { colons }),
+                Some((i, 'Z')) => push_specifier!(i, Specifier::Z),
                 Some((i, '%')) => literal_start = i,
                 Some((_, c)) => panic!("Invalid specifier `{}`", c),
                 None => panic!(
@@ -5,18 +5,100 @@
 use crate::{
     format::{
         parse::{
-            try_consume_exact_digits_in_range, try_consume_first_match,
+            try_consume_exact_digits, try_consume_exact_digits_in_range,
+            try_consume_first_match_ci,
             AmPm::{AM, PM},
         },
         Padding, ParseError, ParseResult, ParsedItems,
     },
-    Time,
+    Language, Time,
 };
 use core::{
-    fmt::{self, Formatter},
+    fmt::{self, Formatter, Write},
     num::NonZeroU8,
 };
 
+/// Subsecond, truncated to the provided number of digits (`1`-`9`). If
+/// trimmed, trailing zeros are omitted and the digits are preceded by a `.`,
+/// such that nothing is written for zero.
+#[inline(always)]
+pub(crate) fn fmt_f(f: &mut Formatter<'_>, time: Time, digits: u8, trim: bool) -> fmt::Result {
+    write_subsecond(f, time.nanosecond(), digits, trim)
+}
+
+/// Subsecond, truncated to the provided number of digits (`1`-`9`). If
+/// trimmed, the subsecond is optional. When present, it is a `.` followed by
+/// at least one digit, and at most the provided number of digits.
+#[inline(always)]
+pub(crate) fn parse_f(
+    items: &mut ParsedItems,
+    s: &mut &str,
+    digits: u8,
+    trim: bool,
+) -> ParseResult<()> {
+    items.nanosecond = Some(parse_subsecond(s, digits, trim)?);
+    Ok(())
+}
+
+/// Write the nanoseconds, truncated to the provided number of digits. If
+/// trimmed, trailing zeros are omitted and the digits are preceded by a `.`,
+/// such that nothing is written for zero.
+#[inline]
+pub(crate) fn write_subsecond(
+    f: &mut impl Write,
+    nanoseconds: u32,
+    digits: u8,
+    trim: bool,
+) -> fmt::Result {
+    let mut value = nanoseconds / 10_u32.pow(9 - digits as u32);
+    let mut digits = digits;
+    if trim {
+        if value == 0 {
+            return Ok(());
+        }
+        while value % 10 == 0 {
+            value /= 10;
+            digits -= 1;
+        }
+        f.write_char('.')?;
+    }
+
+    write!(f, "{:0width$}", value, width = digits as usize)
+}
+
+/// Consume a subsecond of the provided number of digits, returning the value
+/// in nanoseconds. If trimmed, the subsecond is optional, and is zero if not
+/// present. When present, it is a `.` followed by between one and the provided
+/// number of digits.
+#[inline]
+pub(crate) fn parse_subsecond(s: &mut &str, digits: u8, trim: bool) -> ParseResult<u32> {
+    if !trim {
+        return try_consume_exact_digits::<u32>(s, digits as usize, Padding::Zero)
+            .map(|value| value * 10_u32.pow(9 - digits as u32))
+            .ok_or(ParseError::InvalidSubsecond);
+    }
+
+    if !s.starts_with('.') {
+        return Ok(0);
+    }
+
+    let len = s[1..]
+        .bytes()
+        .take(digits as usize)
+        .take_while(u8::is_ascii_digit)
+        .count();
+    if len == 0 {
+        return Err(ParseError::InvalidSubsecond);
+    }
+    let value = s[1..=len]
+        .bytes()
+        .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'));
+    *s = &s[len + 1..];
+
+    #[allow(clippy::cast_possible_truncation)]
+    Ok(value * 10_u32.pow(9 - len as u32))
+}
+
 /// Hour in 24h format (`00`-`23`)
 #[inline(always)]
 pub(crate) fn fmt_H(f: &mut Formatter<'_>, time: Time, padding: Padding) -> fmt::Result {
@@ -54,6 +136,22 @@ pub(crate) fn parse_I(items: &mut ParsedItems, s: &mut &str, padding: Padding) -
     Ok(())
 }
 
+/// Milliseconds, zero-padded (`000`-`999`)
+#[inline(always)]
+pub(crate) fn fmt_L(f: &mut Formatter<'_>, time: Time) -> fmt::Result {
+    write!(f, "{:03}", time.millisecond())
+}
+
+/// Milliseconds, zero-padded (`000`-`999`)
+#[inline(always)]
+pub(crate) fn parse_L(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    items.nanosecond = try_consume_exact_digits_in_range::<u32>(s, 3, 0..1_000, Padding::Zero)
+        .map(|millisecond| millisecond * 1_000_000)
+        .ok_or(ParseError::InvalidSubsecond)?
+        .into();
+    Ok(())
+}
+
 /// Minutes, zero-padded (`00`-`59`)
 #[inline(always)]
 pub(crate) fn fmt_M(f: &mut Formatter<'_>, time: Time, padding: Padding) -> fmt::Result {
@@ -72,39 +170,52 @@ pub(crate) fn parse_M(items: &mut ParsedItems, s: &mut &str, padding: Padding) -
 
 /// am/pm
 #[inline(always)]
-pub(crate) fn fmt_p(f: &mut Formatter<'_>, time: Time) -> fmt::Result {
-    if time.hour() < 12 {
-        f.write_str("am")
-    } else {
-        f.write_str("pm")
+pub(crate) fn fmt_p(f: &mut Formatter<'_>, time: Time, language: Language) -> fmt::Result {
+    let designator = language.am_pm()[(time.hour() >= 12) as usize];
+    for c in designator.chars().flat_map(char::to_lowercase) {
+        f.write_char(c)?;
     }
+    Ok(())
 }
 
 /// am/pm
 #[inline(always)]
-pub(crate) fn parse_p(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
-    items.am_pm = try_consume_first_match(s, [("am", AM), ("pm", PM)].iter().cloned())
-        .ok_or(ParseError::InvalidAmPm)?
-        .into();
-    Ok(())
+pub(crate) fn parse_p(
+    items: &mut ParsedItems,
+    s: &mut &str,
+    language: Language,
+) -> ParseResult<()> {
+    parse_P(items, s, language)
 }
 
 /// AM/PM
 #[inline(always)]
-pub(crate) fn fmt_P(f: &mut Formatter<'_>, time: Time) -> fmt::Result {
-    if time.hour() < 12 {
-        f.write_str("AM")
-    } else {
-        f.write_str("PM")
-    }
+pub(crate) fn fmt_P(f: &mut Formatter<'_>, time: Time, language: Language) -> fmt::Result {
+    f.write_str(language.am_pm()[(time.hour() >= 12) as usize])
 }
 
 /// AM/PM
+///
+/// Both the localized and English designators are accepted, without regard
+/// to case.
 #[inline(always)]
-pub(crate) fn parse_P(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
-    items.am_pm = try_consume_first_match(s, [("AM", AM), ("PM", PM)].iter().cloned())
-        .ok_or(ParseError::InvalidAmPm)?
-        .into();
+pub(crate) fn parse_P(
+    items: &mut ParsedItems,
+    s: &mut &str,
+    language: Language,
+) -> ParseResult<()> {
+    let localized = language.am_pm();
+    let english = Language::en.am_pm();
+    items.am_pm = try_consume_first_match_ci(
+        s,
+        localized
+            .iter()
+            .chain(english.iter())
+            .cloned()
+            .zip([AM, PM, AM, PM].iter().cloned()),
+    )
+    .ok_or(ParseError::InvalidAmPm)?
+    .into();
     Ok(())
 }
 
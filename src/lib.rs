@@ -65,23 +65,31 @@
 //! | `%A`      | Full weekday name                                                      | `Thursday`                 |
 //! | `%b`      | Abbreviated month name                                                 | `Aug`                      |
 //! | `%B`      | Full month name                                                        | `August`                   |
-//! | `%c`      | Date and time representation, equivalent to `%a %b %-d %-H:%M:%S %-Y`  | `Thu Aug 23 14:55:02 2001` |
+//! | `%c`      | Preferred date and time representation for the language               | `Thu Aug 23 14:55:02 2001` |
 //! | `%C`      | Year divided by 100 and truncated to integer (`00`-`99`)               | `20`                       |
 //! | `%d`      | Day of the month, zero-padded (`01`-`31`)                              | `23`                       |
 //! | `%D`      | Short MM/DD/YY date, equivalent to `%-m/%d/%y`                         | `8/23/01`                  |
 //! | `%e`      | Day of the month, space-padded (` 1`-`31`)                             | `23`                       |
+//! | `%f`      | `.` and subsecond without trailing zeros (`%3f` for three digits)      | `.123456789`               |
 //! | `%F`      | Short YYYY-MM-DD date, equivalent to `%-Y-%m-%d`                       | `2001-08-23`               |
 //! | `%g`      | Week-based year, last two digits (`00`-`99`)                           | `01`                       |
 //! | `%G`      | Week-based year                                                        | `2001`                     |
 //! | `%H`      | Hour in 24h format (`00`-`23`)                                         | `14`                       |
 //! | `%I`      | Hour in 12h format (`01`-`12`)                                         | `02`                       |
 //! | `%j`      | Day of the year (`001`-`366`)                                          | `235`                      |
+//! | `%K`      | Year in the Buddhist era (Gregorian year + 543)                        | `2544`                     |
+//! | `%L`      | Milliseconds, zero-padded (`000`-`999`)                                | `123`                      |
 //! | `%m`      | Month as a decimal number (`01`-`12`)                                  | `08`                       |
 //! | `%M`      | Minute (`00`-`59`)                                                     | `55`                       |
+//! | `%N`      | Nanoseconds, always nine digits (`%3N` for milliseconds, etc.)         | `123456789`                |
+//! | `%o`      | Day of the month with its ordinal suffix (`1st`-`31st`)                | `23rd`                     |
+//! | `%OB`     | Full month name in the genitive case, as used in a date context        | `August`                   |
 //! | `%p`      | `am` or `pm` designation                                               | `pm`                       |
 //! | `%P`      | `AM` or `PM` designation                                               | `PM`                       |
+//! | `%q`      | Quarter of the year (`1`-`4`)                                          | `3`                        |
 //! | `%r`      | 12-hour clock time, equivalent to `%-I:%M:%S %p`                       | `2:55:02 pm`               |
 //! | `%R`      | 24-hour HH:MM time, equivalent to `%-H:%M`                             | `14:55`                    |
+//! | `%s`      | Number of seconds since the Unix epoch, which may be negative          | `998574902`                |
 //! | `%S`      | Second (`00`-`59`)                                                     | `02`                       |
 //! | `%T`      | ISO 8601 time format (HH:MM:SS), equivalent to `%-H:%M:%S`             | `14:55:02`                 |
 //! | `%u`      | ISO 8601 weekday as number with Monday as 1 (`1`-`7`)                  | `4`                        |
@@ -89,11 +97,41 @@
 //! | `%V`      | ISO 8601 week number (`01`-`53`)                                       | `34`                       |
 //! | `%w`      | Weekday as a decimal number with Sunday as 0 (`0`-`6`)                 | `4`                        |
 //! | `%W`      | Week number with the first Monday as the start of week one (`00`-`53`) | `34`                       |
+//! | `%x`      | Preferred date representation for the language                         | `08/23/01`                 |
+//! | `%X`      | Preferred time representation for the language                         | `14:55:02`                 |
 //! | `%y`      | Year, last two digits (`00`-`99`)                                      | `01`                       |
 //! | `%Y`      | Full year, including `+` if ≥10,000                                    | `2001`                     |
 //! | `%z`      | ISO 8601 offset from UTC in timezone (+HHMM)                           | `+0100`                    |
+//! | `%:z`     | ISO 8601 offset from UTC in timezone (+HH:MM)                          | `+01:00`                   |
+//! | `%::z`    | ISO 8601 offset from UTC in timezone, including seconds (+HH:MM:SS)    | `+01:00:00`                |
+//! | `%Z`      | Time zone abbreviation (`UTC`, or the offset as `+HH:MM` if nonzero)   | `UTC`                      |
 //! | `%%`      | Literal `%`                                                            | `%`                        |
 //!
+//! When parsing, each of `%z`, `%:z`, and `%::z` accepts `Z` as UTC, and the
+//! minutes and seconds may be omitted. `%z` accepts `+HH` and `+HHMM`. `%:z`
+//! accepts `+HH`, `+HH:MM`, and `+HHMM`. `%::z` accepts
+//! `+HH`, `+HH:MM`, `+HH:MM:SS`, and the same without colons. Colons are not
+//! supported by any other specifier.
+//!
+//! As no time zone database is available, `%Z` cannot resolve abbreviations
+//! other than `UTC` and `GMT` when parsing. Any other alphabetic abbreviation,
+//! such as `PST`, is consumed, but does not provide an offset. A numeric
+//! offset is accepted as with `%::z`.
+//!
+//! When parsing `%y` (including as part of `%D`) without a century, years
+//! `69`-`99` are in the 1900s and `00`-`68` are in the 2000s, as in POSIX. A
+//! century provided by `%C` takes precedence.
+//!
+//! `%K` only offsets the year for display, as is common in Thailand. The value
+//! is still a Gregorian date; month and day are unchanged, and parsing
+//! subtracts 543 to recover the Gregorian year. It is not a full
+//! implementation of the Thai solar calendar.
+//!
+//! For portability with format strings written for C, the POSIX era
+//! specifiers `%EC`, `%Ey`, and `%EY` are accepted. Lacking any era data, they
+//! are currently identical to `%C`, `%y`, and `%Y` respectively. The `E`
+//! modifier is not supported by any other specifier.
+//!
 //! ## Modifiers
 //!
 //! All specifiers that are strictly numerical have modifiers for formatting.
@@ -105,6 +143,33 @@
 //! | `_` (underscore) | Pad with spaces | `%_d` => ` 5` instead of `05` |
 //! | `0`              | Pad with zeros  | `%0e` => `05` instead of ` 5` |
 //!
+//! Some specifiers additionally accept a single-digit width, following the
+//! modifier if one is present. For `%f` and `%N`, the width is the number of
+//! digits (`%3f` => `123` for 123,456,789 nanoseconds). For all
+//! specifiers that can be padded, the width is the exact width of the field:
+//! the value is padded to that width when formatting (`%04Y` => `0005`), and
+//! no more than that many characters are consumed when parsing, such that
+//! `%04Y%02m%02d` accepts `20010823`. The width cannot be narrower than the
+//! specifier's default (`%2Y` panics), as the value could not be parsed back.
+//! Values that are wider still, such as years after 9999, are formatted in full.
+//! Adding a width to a non-supporting specifier is a no-op.
+//!
+//! Without a width, `%f` writes its own leading `.` and omits trailing zeros,
+//! such that nothing at all is formatted for a zero subsecond. 500,000,000
+//! nanoseconds formatted with `%T%f` is `12:00:00.5`, while a whole second is
+//! `12:00:00`. When parsing, the subsecond is optional, but a `.` must be
+//! followed by between one and nine digits. `%N` and any explicit width always
+//! use exactly that many digits, without a leading `.`.
+//!
+//! By default, `%f` truncates the subsecond value. Preceding the width with a
+//! `~` rounds it (half away from zero) instead, such that `%~3f` formats
+//! 123,500,000 nanoseconds as `124`. When rounding reaches a full second, the
+//! carry is applied to the entire value being formatted, so 23:59:59.9996
+//! formatted with `%T.%~3f` is `0:00:00.000`, and the date (if present) is
+//! advanced by one day. On the last representable date, where this is not
+//! possible, the value is truncated instead. Rounding is only supported by
+//! `%f` and `%N`, and has no effect on parsing.
+//!
 //! ## Localization
 //!
 //! Some specifiers currently have the ability to change the language the value
@@ -116,12 +181,37 @@
 //! - `%b`
 //! - `%B`
 //! - `%c`
+//! - `%o`
+//! - `%OB`
+//! - `%p`
+//! - `%P`
+//! - `%r`
+//! - `%x`
+//! - `%X`
+//!
+//! `%c`, `%x`, and `%X` are replaced by the templates returned by
+//! `Language::date_time_format`, `Language::date_format`, and
+//! `Language::time_format` respectively, so the order of their components
+//! varies by language. In English, `%c` is `%a %b %-d %-H:%M:%S %-Y`, `%x` is
+//! `%m/%d/%y`, and `%X` is `%-H:%M:%S`. The order of other "combination
+//! specifiers" (such as `%D`) is not affected.
 //!
-//! The order of certain specifiers in "combination specifiers" (such as `%D`)
-//! is not affected, as the time crate only handles languages, not locale.
+//! Some languages inflect month names when they appear in a date, such as
+//! "5 января" in Russian. `%B` always uses the standalone (nominative) form,
+//! while `%OB` uses the form used in a date (genitive). For languages that make
+//! no such distinction, the two are identical. The `O` modifier is not
+//! supported by any other specifier.
+//!
+//! When parsing, the names of months and days of the week are matched without
+//! regard to case, such that `january`, `January`, and `JANUARY` are all
+//! accepted by `%B`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
-#![forbid(unsafe_code)]
+// The only unsafe code is in `local_offset`, which calls into the operating
+// system. As `forbid` cannot be overridden, it is only used when that module
+// is not compiled.
+#![cfg_attr(not(all(feature = "std", any(unix, windows))), forbid(unsafe_code))]
+#![cfg_attr(all(feature = "std", any(unix, windows)), deny(unsafe_code))]
 #![deny(
     anonymous_parameters,
     rust_2018_idioms,
@@ -234,6 +324,8 @@ macro_rules! assert_panics {
     };
 }
 
+/// Calendar systems and the rules they follow.
+mod calendar;
 /// The `Date` struct and its associated `impl`s.
 mod date;
 /// The `DateTime` struct and its associated `impl`s.
@@ -244,6 +336,9 @@ mod format;
 /// The `Instant` struct and its associated `impl`s.
 #[cfg(feature = "std")]
 mod instant;
+/// Obtaining the system's UTC offset from the operating system.
+#[cfg(feature = "std")]
+mod local_offset;
 /// A collection of traits extending built-in numerical types.
 mod numerical_traits;
 /// The `OffsetDateTime` struct and its associated `impl`s.
@@ -266,7 +361,7 @@ pub use date_time::DateTime;
 pub use duration::Duration;
 pub(crate) use format::DeferredFormat;
 #[allow(unreachable_pub)] // rust-lang/rust#64762
-pub use format::{Language, ParseError};
+pub use format::{Component, Language, ParseError, ParseErrorAt};
 #[cfg(feature = "std")]
 pub use instant::Instant;
 pub use numerical_traits::{NumericalDuration, NumericalStdDuration, NumericalStdDurationShort};
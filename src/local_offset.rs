@@ -0,0 +1,127 @@
+#[cfg(windows)]
+use crate::calendar::{Calendar, Gregorian};
+#[cfg(unix)]
+use crate::days_in_year;
+use crate::{Date, DateTime, UtcOffset};
+
+/// Attempt to obtain the system's UTC offset at the provided instant, which is
+/// interpreted as UTC.
+#[cfg(unix)]
+#[allow(unsafe_code)]
+pub(crate) fn try_local_offset_at(datetime: DateTime) -> Option<UtcOffset> {
+    use core::{convert::TryInto, mem::MaybeUninit};
+
+    // Allow the lint, as `time_t` is not `i64` on all platforms.
+    #[allow(clippy::useless_conversion)]
+    let timestamp: libc::time_t = datetime.timestamp().try_into().ok()?;
+    let mut tm = MaybeUninit::<libc::tm>::uninit();
+
+    // Safety: `localtime_r` is thread-safe and writes to `tm` on success,
+    // which is checked before `tm` is read.
+    let tm = unsafe {
+        if libc::localtime_r(&timestamp, tm.as_mut_ptr()).is_null() {
+            return None;
+        }
+        tm.assume_init()
+    };
+
+    let year = tm.tm_year.checked_add(1900)?;
+    let ordinal: u16 = (tm.tm_yday + 1).try_into().ok()?;
+    if ordinal == 0 || ordinal > days_in_year(year) {
+        return None;
+    }
+
+    // Leap seconds are not supported, so treat them as the preceding second.
+    offset_between(
+        Date::from_yo(year, ordinal),
+        (
+            tm.tm_hour.try_into().ok()?,
+            tm.tm_min.try_into().ok()?,
+            tm.tm_sec.min(59).try_into().ok()?,
+        ),
+        datetime,
+    )
+}
+
+/// Attempt to obtain the system's UTC offset at the provided instant, which is
+/// interpreted as UTC.
+#[cfg(windows)]
+#[allow(unsafe_code)]
+pub(crate) fn try_local_offset_at(datetime: DateTime) -> Option<UtcOffset> {
+    use core::{convert::TryInto, mem::MaybeUninit, ptr};
+    use winapi::{
+        shared::minwindef::FALSE,
+        um::{minwinbase::SYSTEMTIME, timezoneapi::SystemTimeToTzSpecificLocalTime},
+    };
+
+    let utc = SYSTEMTIME {
+        wYear: datetime.year().try_into().ok()?,
+        wMonth: datetime.month() as u16,
+        wDayOfWeek: 0, // ignored
+        wDay: datetime.day() as u16,
+        wHour: datetime.hour() as u16,
+        wMinute: datetime.minute() as u16,
+        wSecond: datetime.second() as u16,
+        wMilliseconds: 0,
+    };
+    let mut local = MaybeUninit::<SYSTEMTIME>::uninit();
+
+    // Safety: A null pointer indicates that the currently active timezone
+    // should be used (including any daylight saving rules for the year).
+    // `local` is written to on success, which is checked before it is read.
+    let local = unsafe {
+        if SystemTimeToTzSpecificLocalTime(ptr::null(), &utc, local.as_mut_ptr()) == FALSE {
+            return None;
+        }
+        local.assume_init()
+    };
+
+    let year = local.wYear.into();
+    let month: u8 = local.wMonth.try_into().ok()?;
+    let day: u8 = local.wDay.try_into().ok()?;
+    if month == 0 || month > 12 || day == 0 || day > Gregorian::days_in_year_month(year, month) {
+        return None;
+    }
+
+    offset_between(
+        Date::from_ymd(year, month, day),
+        (
+            local.wHour.try_into().ok()?,
+            local.wMinute.try_into().ok()?,
+            local.wSecond.try_into().ok()?,
+        ),
+        datetime,
+    )
+}
+
+/// The local offset cannot be determined on this platform.
+#[cfg(not(any(unix, windows)))]
+#[inline(always)]
+#[allow(clippy::missing_const_for_fn)]
+pub(crate) fn try_local_offset_at(_: DateTime) -> Option<UtcOffset> {
+    None
+}
+
+/// Get the offset of the local wall-clock time from the UTC instant, both of
+/// which have second precision. Returns `None` if the local time is not valid.
+#[cfg(any(unix, windows))]
+#[inline]
+fn offset_between(
+    local_date: Date,
+    (hour, minute, second): (u8, u8, u8),
+    utc: DateTime,
+) -> Option<UtcOffset> {
+    use core::convert::TryInto;
+
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    let local = local_date.with_hms(hour, minute, second);
+    let utc = utc.date().with_hms(utc.hour(), utc.minute(), utc.second());
+    (local - utc)
+        .whole_seconds()
+        .try_into()
+        .ok()
+        .map(UtcOffset::seconds)
+}
@@ -1,11 +1,19 @@
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
 use crate::{
-    format::parse::{parse, ParseResult, ParsedItems},
+    format::{
+        date_time::{format_rfc2822, parse_rfc2822},
+        parse::{
+            parse, parse_any, parse_strict, parse_with_position, ParseError, ParseErrorAt,
+            ParseResult, ParsedItems,
+        },
+        time::parse_subsecond,
+    },
     Date, DateTime, DeferredFormat, Duration, Language, Time, UtcOffset, Weekday,
 };
 use core::{
     cmp::Ordering,
+    fmt::Write,
     hash::{Hash, Hasher},
     ops::{Add, AddAssign, Sub, SubAssign},
     time::Duration as StdDuration,
@@ -43,6 +51,9 @@ impl OffsetDateTime {
     /// Convert the `OffsetDateTime` from the current `UtcOffset` to the
     /// provided `UtcOffset`.
     ///
+    /// The instant is unchanged; only the local date and time are shifted, and
+    /// the date rolls over if the new offset crosses midnight.
+    ///
     /// ```rust
     /// # use time::{Date, OffsetDateTime, UtcOffset};
     /// assert_eq!(
@@ -53,6 +64,14 @@ impl OffsetDateTime {
     ///         .year(),
     ///     1999,
     /// );
+    ///
+    /// let datetime = Date::from_ymd(2019, 12, 31)
+    ///     .with_hms(23, 0, 0)
+    ///     .using_offset(UtcOffset::UTC);
+    /// let tokyo = datetime.to_offset(UtcOffset::hours(9));
+    /// assert_eq!(tokyo.date(), Date::from_ymd(2020, 1, 1));
+    /// assert_eq!(tokyo.hour(), 8);
+    /// assert_eq!(tokyo, datetime);
     /// ```
     #[inline(always)]
     pub const fn to_offset(self, offset: UtcOffset) -> Self {
@@ -121,8 +140,11 @@ impl OffsetDateTime {
 
     /// Get the [Unix timestamp](https://en.wikipedia.org/wiki/Unix_time).
     ///
+    /// The timestamp is in whole seconds, and can be represented for every
+    /// value of an `OffsetDateTime`, including those far from the Unix epoch.
+    ///
     /// ```rust
-    /// # use time::{DateTime, UtcOffset};
+    /// # use time::{Date, DateTime, UtcOffset};
     /// assert_eq!(
     ///     DateTime::unix_epoch()
     ///         .using_offset(UtcOffset::UTC)
@@ -133,12 +155,19 @@ impl OffsetDateTime {
     ///     DateTime::unix_epoch()
     ///         .using_offset(UtcOffset::hours(-1))
     ///         .timestamp(),
-    ///     3_600,
+    ///     0,
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(100_000, 1, 1)
+    ///         .midnight()
+    ///         .using_offset(UtcOffset::UTC)
+    ///         .timestamp(),
+    ///     3_093_527_980_800,
     /// );
     /// ```
     #[inline(always)]
     pub fn timestamp(self) -> i64 {
-        self.datetime.timestamp() - self.offset.as_seconds() as i64
+        self.datetime.timestamp()
     }
 
     /// Get the `Date` in the stored offset.
@@ -603,6 +632,76 @@ impl OffsetDateTime {
     pub fn nanosecond(self) -> u32 {
         self.time().nanosecond()
     }
+
+    /// Get the `Duration` elapsed since an earlier `OffsetDateTime`. This is
+    /// equivalent to `self - earlier`, and is negative if `earlier` is actually
+    /// later.
+    ///
+    /// ```rust
+    /// # use time::{Date, Duration, UtcOffset};
+    /// let earlier = Date::from_ymd(2019, 1, 1).midnight().using_offset(UtcOffset::UTC);
+    /// let later = Date::from_ymd(2019, 1, 2).midnight().using_offset(UtcOffset::UTC);
+    /// assert_eq!(later.duration_since(earlier), Duration::days(1));
+    /// assert_eq!(earlier.duration_since(later), Duration::days(-1));
+    /// ```
+    #[inline(always)]
+    pub fn duration_since(self, earlier: Self) -> Duration {
+        self - earlier
+    }
+
+    /// Get the `Duration` remaining until a later `OffsetDateTime`. This is
+    /// equivalent to `later - self`, and is negative if `later` is actually
+    /// earlier.
+    ///
+    /// ```rust
+    /// # use time::{Date, Duration, UtcOffset};
+    /// let earlier = Date::from_ymd(2019, 1, 1).midnight().using_offset(UtcOffset::UTC);
+    /// let later = Date::from_ymd(2019, 1, 2).midnight().using_offset(UtcOffset::UTC);
+    /// assert_eq!(earlier.duration_until(later), Duration::days(1));
+    /// assert_eq!(later.duration_until(earlier), Duration::days(-1));
+    /// ```
+    #[inline(always)]
+    pub fn duration_until(self, later: Self) -> Duration {
+        later - self
+    }
+
+    /// Replace the time in the stored offset, keeping the date and the offset.
+    /// The instant represented is changed accordingly.
+    ///
+    /// ```rust
+    /// # use time::{Date, Time, UtcOffset};
+    /// let datetime = Date::from_ymd(2019, 1, 1)
+    ///     .with_hms(12, 0, 0)
+    ///     .using_offset(UtcOffset::UTC)
+    ///     .to_offset(UtcOffset::hours(2));
+    /// let replaced = datetime.replace_time(Time::from_hms(9, 30, 0));
+    /// assert_eq!(replaced.date(), Date::from_ymd(2019, 1, 1));
+    /// assert_eq!(replaced.time(), Time::from_hms(9, 30, 0));
+    /// assert_eq!(replaced.offset(), UtcOffset::hours(2));
+    /// ```
+    #[inline(always)]
+    pub fn replace_time(self, time: Time) -> Self {
+        (DateTime::new(self.date(), time) - self.offset.as_duration()).using_offset(self.offset)
+    }
+
+    /// Replace the date in the stored offset, keeping the time and the offset.
+    /// The instant represented is changed accordingly.
+    ///
+    /// ```rust
+    /// # use time::{Date, Time, UtcOffset};
+    /// let datetime = Date::from_ymd(2019, 1, 1)
+    ///     .with_hms(12, 0, 0)
+    ///     .using_offset(UtcOffset::UTC)
+    ///     .to_offset(UtcOffset::hours(-5));
+    /// let replaced = datetime.replace_date(Date::from_ymd(2020, 2, 29));
+    /// assert_eq!(replaced.date(), Date::from_ymd(2020, 2, 29));
+    /// assert_eq!(replaced.time(), Time::from_hms(7, 0, 0));
+    /// assert_eq!(replaced.offset(), UtcOffset::hours(-5));
+    /// ```
+    #[inline(always)]
+    pub fn replace_date(self, date: Date) -> Self {
+        (DateTime::new(date, self.time()) - self.offset.as_duration()).using_offset(self.offset)
+    }
 }
 
 /// Methods that allow formatting the `OffsetDateTime`.
@@ -647,7 +746,7 @@ impl OffsetDateTime {
     ///         .midnight()
     ///         .using_offset(UtcOffset::hours(2))
     ///         .format_language("%c %z", Language::es),
-    ///     "Mi enero 2 2:00:00 2019 +0200",
+    ///     "Mi 2 enero 2019 02:00:00 +0200",
     /// );
     /// ```
     #[inline(always)]
@@ -684,6 +783,70 @@ impl OffsetDateTime {
         Self::parse_language(s, format, Language::en)
     }
 
+    /// Attempt to parse an `OffsetDateTime` using each of the provided formats
+    /// in turn, returning the first success. If every format fails, the error
+    /// from the last one is returned.
+    /// If no formats are provided, `ParseError::InsufficientInformation` is
+    /// returned with the date as the missing component.
+    ///
+    /// ```rust
+    /// # use time::{Date, OffsetDateTime, UtcOffset};
+    /// let formats = ["%FT%T%:z", "%a, %d %b %Y %T %z"];
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_any("2019-01-02T03:04:05+01:00", &formats),
+    ///     Ok(Date::from_ymd(2019, 1, 2)
+    ///         .with_hms(2, 4, 5)
+    ///         .using_offset(UtcOffset::hours(1)))
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_any("Wed, 02 Jan 2019 03:04:05 +0100", &formats),
+    ///     Ok(Date::from_ymd(2019, 1, 2)
+    ///         .with_hms(2, 4, 5)
+    ///         .using_offset(UtcOffset::hours(1)))
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_any(s: &str, formats: &[&str]) -> ParseResult<Self> {
+        parse_any(formats, |format| Self::parse(s, format))
+    }
+
+    /// Attempt to parse an `OffsetDateTime` using the provided string. Any error includes
+    /// the position in the input at which it occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, OffsetDateTime, ParseError, UtcOffset};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_with_position("2019-01-02 00:00:00 +0000", "%F %T %z"),
+    ///     Ok(Date::from_ymd(2019, 1, 2).midnight().using_offset(UtcOffset::UTC))
+    /// );
+    /// let error = OffsetDateTime::parse_with_position("2019-01-02 00:00:00 0000", "%F %T %z").unwrap_err();
+    /// assert_eq!(error.kind(), ParseError::InvalidOffset);
+    /// assert_eq!(error.index(), 20);
+    /// ```
+    #[inline(always)]
+    pub fn parse_with_position(s: &str, format: &str) -> Result<Self, ParseErrorAt> {
+        parse_with_position(s, format, Language::en, Self::try_from_parsed_items)
+    }
+
+    /// Attempt to parse a `OffsetDateTime` using the provided string, returning an error if
+    /// any input remains once the format is exhausted.
+    ///
+    /// ```rust
+    /// # use time::{Date, OffsetDateTime, ParseError, UtcOffset};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_strict("2019-01-02 00:00:00 +0000", "%F %T %z"),
+    ///     Ok(Date::from_ymd(2019, 1, 2).midnight().using_offset(UtcOffset::UTC))
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_strict("2019-01-02 00:00:00 +0000 UTC", "%F %T %z"),
+    ///     Err(ParseError::UnexpectedTrailingCharacters)
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn parse_strict(s: &str, format: &str) -> ParseResult<Self> {
+        Self::try_from_parsed_items(&parse_strict(s, format, Language::en)?)
+    }
+
     /// Attempt to parse an `OffsetDateTime` using the provided string and language.
     ///
     /// ```rust
@@ -699,15 +862,316 @@ impl OffsetDateTime {
     /// ```
     #[inline(always)]
     pub fn parse_language(s: &str, format: &str, language: Language) -> ParseResult<Self> {
-        Self::try_from_parsed_items(parse(s, format, language)?)
+        Self::try_from_parsed_items(&parse(s, format, language)?)
+    }
+
+    /// Attempt to parse an `OffsetDateTime` using the provided string. If no
+    /// offset is present in the string, it is assumed to be UTC. As no
+    /// language is specified, English is used.
+    ///
+    /// ```rust
+    /// # use time::{Date, OffsetDateTime, UtcOffset};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_assume_utc("2019-01-02T03:04:05", "%Y-%m-%dT%H:%M:%S"),
+    ///     Ok(Date::from_ymd(2019, 1, 2).with_hms(3, 4, 5).using_offset(UtcOffset::UTC)),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_assume_utc("2019-01-02 03:04:05 +0000", "%F %T %z"),
+    ///     Ok(Date::from_ymd(2019, 1, 2).with_hms(3, 4, 5).using_offset(UtcOffset::UTC)),
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn parse_assume_utc(s: &str, format: &str) -> ParseResult<Self> {
+        Self::parse_language_assume_utc(s, format, Language::en)
+    }
+
+    /// Attempt to parse an `OffsetDateTime` using the provided string and
+    /// language. If no offset is present in the string, it is assumed to be
+    /// UTC.
+    ///
+    /// ```rust
+    /// # use time::{Date, OffsetDateTime, UtcOffset, Language::es};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_language_assume_utc("02 enero 2019 00:00:00", "%d %B %Y %T", es),
+    ///     Ok(Date::from_ymd(2019, 1, 2).midnight().using_offset(UtcOffset::UTC)),
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_language_assume_utc(
+        s: &str,
+        format: &str,
+        language: Language,
+    ) -> ParseResult<Self> {
+        let mut items = parse(s, format, language)?;
+        items.offset = items.offset.or(Some(UtcOffset::UTC));
+        Self::try_from_parsed_items(&items)
+    }
+
+    /// Format the `OffsetDateTime` as `YYYY-MM-DD HH:MM:SS±HH:MM`. This is the
+    /// representation expected by many databases. If the offset has a nonzero
+    /// seconds component, it is included as `±HH:MM:SS`.
+    ///
+    /// ```rust
+    /// # use time::{Date, UtcOffset};
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 1, 2)
+    ///         .with_hms(3, 4, 5)
+    ///         .using_offset(UtcOffset::UTC)
+    ///         .to_timezone_string(),
+    ///     "2019-01-02 03:04:05+00:00",
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 1, 2)
+    ///         .with_hms(3, 4, 5)
+    ///         .using_offset(UtcOffset::minutes(-330))
+    ///         .to_timezone_string(),
+    ///     "2019-01-01 21:34:05-05:30",
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_timezone_string(self) -> String {
+        if self.offset.as_seconds() % 60 == 0 {
+            self.format("%Y-%m-%d %H:%M:%S%:z")
+        } else {
+            self.format("%Y-%m-%d %H:%M:%S%::z")
+        }
+    }
+
+    /// Attempt to parse an `OffsetDateTime` in the format produced by
+    /// [`OffsetDateTime::to_timezone_string`], `YYYY-MM-DD HH:MM:SS±HH:MM` or
+    /// `YYYY-MM-DD HH:MM:SS±HH:MM:SS`.
+    ///
+    /// ```rust
+    /// # use time::{Date, OffsetDateTime, UtcOffset};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_timezone_string("2019-01-02 03:04:05+00:00"),
+    ///     Ok(Date::from_ymd(2019, 1, 2).with_hms(3, 4, 5).using_offset(UtcOffset::UTC)),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_timezone_string("2019-01-02 03:04:05-05:30")
+    ///         .map(|datetime| datetime.offset()),
+    ///     Ok(UtcOffset::minutes(-330)),
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_timezone_string(s: &str) -> ParseResult<Self> {
+        // The offset is always formatted with colons, so the last colon is the
+        // third character from the end.
+        let colon = s
+            .len()
+            .checked_sub(3)
+            .ok_or(ParseError::UnexpectedEndOfString)?;
+        if s.get(colon..=colon) != Some(":") {
+            return Err(ParseError::InvalidOffset);
+        }
+
+        Self::parse(s, "%Y-%m-%d %H:%M:%S%::z")
+    }
+
+    /// Format the `OffsetDateTime` as specified by [RFC
+    /// 2822](https://tools.ietf.org/html/rfc2822#section-3.3), such as `Wed, 02
+    /// Jan 2019 03:04:05 +0000`. This is the same representation as
+    /// [`DateTime::format_rfc2822`], but in the stored offset.
+    ///
+    /// RFC 2822 requires the day and month names be in English, so they are
+    /// never localized.
+    ///
+    /// ```rust
+    /// # use time::{Date, UtcOffset};
+    /// assert_eq!(
+    ///     Date::from_ymd(2019, 1, 2)
+    ///         .with_hms(3, 4, 5)
+    ///         .using_offset(UtcOffset::UTC)
+    ///         .format_rfc2822(),
+    ///     "Wed, 02 Jan 2019 03:04:05 +0000",
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn format_rfc2822(self) -> String {
+        format_rfc2822(self.date().with_time(self.time()), self.offset)
+    }
+
+    /// Attempt to parse an [RFC 2822](https://tools.ietf.org/html/rfc2822)
+    /// date and time, such as `Tue, 1 Jul 2003 10:52:37 +0200`, retaining the
+    /// offset. Only the English day and month names are accepted.
+    ///
+    /// The same strings are accepted as by [`DateTime::parse_rfc2822`]: the
+    /// weekday and seconds may be omitted, the day may have one or two digits,
+    /// and the offset may be `±HHMM`, `GMT`, `UT`, or `Z`.
+    ///
+    /// ```rust
+    /// # use time::{Date, OffsetDateTime, UtcOffset};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_rfc2822("Wed, 02 Jan 2019 03:04:05 +0000"),
+    ///     Ok(Date::from_ymd(2019, 1, 2).with_hms(3, 4, 5).using_offset(UtcOffset::UTC)),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_rfc2822("2 Jan 2019 05:04 +0200").map(|datetime| datetime.offset()),
+    ///     Ok(UtcOffset::hours(2)),
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn parse_rfc2822(s: &str) -> ParseResult<Self> {
+        Self::parse_well_known(s, parse_rfc2822)
+    }
+
+    /// Parse a value in a well-known format using the provided function. The
+    /// entire string must be consumed, and an offset must be present.
+    #[inline]
+    pub(crate) fn parse_well_known(
+        mut s: &str,
+        parse_fn: fn(&mut ParsedItems, &mut &str) -> ParseResult<()>,
+    ) -> ParseResult<Self> {
+        let mut items = ParsedItems::new();
+        parse_fn(&mut items, &mut s)?;
+
+        if !s.is_empty() {
+            return Err(ParseError::UnexpectedTrailingCharacters);
+        }
+
+        Self::try_from_parsed_items(&items)
+    }
+
+    /// Format the `OffsetDateTime` as Postgres does for a `timestamptz` value
+    /// with the `ISO` date style, such as `2020-01-02 03:04:05.123456+00`.
+    ///
+    /// The subsecond component is truncated to microseconds, with trailing
+    /// zeros omitted, and is omitted entirely if zero. The minutes and seconds
+    /// of the offset are only present when nonzero. Years prior to 1 are
+    /// written using the `BC` suffix, with 1 BC being year zero.
+    ///
+    /// ```rust
+    /// # use time::{Date, UtcOffset};
+    /// assert_eq!(
+    ///     Date::from_ymd(2020, 1, 2)
+    ///         .with_hms_micro(3, 4, 5, 123_456)
+    ///         .using_offset(UtcOffset::UTC)
+    ///         .format_postgres(),
+    ///     "2020-01-02 03:04:05.123456+00",
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd(2020, 1, 2)
+    ///         .with_hms(3, 4, 5)
+    ///         .using_offset(UtcOffset::minutes(-330))
+    ///         .format_postgres(),
+    ///     "2020-01-01 21:34:05-05:30",
+    /// );
+    /// ```
+    #[inline]
+    pub fn format_postgres(self) -> String {
+        let (year, month, day) = (self.year(), self.month(), self.day());
+        let mut s = String::new();
+
+        // Writing to a `String` cannot fail.
+        let _ = write!(
+            s,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            if year > 0 { year } else { 1 - year },
+            month,
+            day,
+            self.hour(),
+            self.minute(),
+            self.second()
+        );
+
+        if self.microsecond() != 0 {
+            let fraction = format!("{:06}", self.microsecond());
+            s.push('.');
+            s.push_str(fraction.trim_end_matches('0'));
+        }
+
+        let offset = self.offset.as_seconds();
+        let sign = if offset < 0 { '-' } else { '+' };
+        let offset = offset.abs();
+        let _ = write!(s, "{}{:02}", sign, offset / 3_600);
+        if offset % 3_600 != 0 {
+            let _ = write!(s, ":{:02}", offset / 60 % 60);
+        }
+        if offset % 60 != 0 {
+            let _ = write!(s, ":{:02}", offset % 60);
+        }
+
+        if year <= 0 {
+            s.push_str(" BC");
+        }
+
+        s
+    }
+
+    /// Attempt to parse an `OffsetDateTime` in the format produced by
+    /// [`OffsetDateTime::format_postgres`].
+    ///
+    /// The variations Postgres may produce are accepted: the date and time
+    /// may be separated by a `T`, the subsecond component may have up to nine
+    /// digits, the offset may have minutes and seconds, with or without
+    /// colons, and the value may be followed by `BC`.
+    ///
+    /// ```rust
+    /// # use time::{Date, OffsetDateTime, UtcOffset};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_postgres("2020-01-02 03:04:05.123456+00"),
+    ///     Ok(Date::from_ymd(2020, 1, 2)
+    ///         .with_hms_micro(3, 4, 5, 123_456)
+    ///         .using_offset(UtcOffset::UTC)),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_postgres("2020-01-01 21:34:05-05:30")
+    ///         .map(|datetime| datetime.offset()),
+    ///     Ok(UtcOffset::minutes(-330)),
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_postgres(s: &str) -> ParseResult<Self> {
+        let (s, is_bc) = match s.rfind(" BC") {
+            Some(index) if index + 3 == s.len() => (&s[..index], true),
+            _ => (s, false),
+        };
+
+        // The offset is the first sign following the separator, as the date
+        // itself may contain a sign.
+        let separator = s
+            .find(&[' ', 'T'][..])
+            .ok_or(ParseError::UnexpectedEndOfString)?;
+        let (date, time) = (&s[..separator], &s[separator + 1..]);
+        let (time, offset) = time.split_at(
+            time.find(&['+', '-'][..])
+                .ok_or(ParseError::InvalidOffset)?,
+        );
+        let (time, fraction) = time
+            .find('.')
+            .map_or((time, None), |index| (&time[..index], Some(&time[index..])));
+
+        let mut items = parse_strict(
+            &[date, " ", time, offset].concat(),
+            "%Y-%m-%d %H:%M:%S%::z",
+            Language::en,
+        )?;
+
+        if let Some(mut fraction) = fraction {
+            items.nanosecond = parse_subsecond(&mut fraction, 9, true)?.into();
+            if !fraction.is_empty() {
+                return Err(ParseError::InvalidSubsecond);
+            }
+        }
+
+        if is_bc {
+            items.year = items.year.map(|year| 1 - year);
+        }
+
+        Self::try_from_parsed_items(&items)
     }
 
     /// Given the items already parsed, attempt to create a `DateTime`.
     #[inline(always)]
-    pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
+    pub(crate) fn try_from_parsed_items(items: &ParsedItems) -> ParseResult<Self> {
+        let datetime = DateTime::try_from_parsed_items(items)?;
+        let offset = UtcOffset::try_from_parsed_items(items)?;
+
+        // The parsed values are in the provided offset, but the stored
+        // `DateTime` is always UTC.
         Ok(Self {
-            datetime: DateTime::try_from_parsed_items(items)?,
-            offset: UtcOffset::try_from_parsed_items(items)?,
+            datetime: datetime - offset.as_duration(),
+            offset,
         })
     }
 }
@@ -817,7 +1281,7 @@ impl Sub<OffsetDateTime> for OffsetDateTime {
 #[allow(clippy::zero_prefixed_literal)]
 mod test {
     use super::*;
-    use crate::prelude::*;
+    use crate::{prelude::*, Component};
 
     macro_rules! ymd {
         ($year:literal, $month:literal, $date:literal) => {
@@ -848,6 +1312,32 @@ mod test {
                 .year(),
             1999,
         );
+
+        // Rolling forward past midnight.
+        let datetime = ymd!(2019, 12, 31)
+            .with_hms(23, 30, 0)
+            .using_offset(UtcOffset::UTC);
+        let converted = datetime.to_offset(UtcOffset::minutes(330));
+        assert_eq!(converted.date(), ymd!(2020, 1, 1));
+        assert_eq!(converted.time(), Time::from_hms(5, 0, 0));
+        assert_eq!(converted.offset(), UtcOffset::minutes(330));
+        assert_eq!(converted.timestamp(), datetime.timestamp());
+
+        // Rolling back past midnight, between two non-UTC offsets.
+        let converted = datetime
+            .to_offset(UtcOffset::hours(9))
+            .to_offset(UtcOffset::hours(-5));
+        assert_eq!(converted.date(), ymd!(2019, 12, 31));
+        assert_eq!(converted.time(), Time::from_hms(18, 30, 0));
+        assert_eq!(converted, datetime);
+
+        // Across the end of February in a leap year.
+        let converted = ymd!(2020, 3, 1)
+            .midnight()
+            .using_offset(UtcOffset::UTC)
+            .to_offset(UtcOffset::seconds(-1));
+        assert_eq!(converted.date(), ymd!(2020, 2, 29));
+        assert_eq!(converted.time(), Time::from_hms(23, 59, 59));
     }
 
     #[test]
@@ -895,8 +1385,24 @@ mod test {
             DateTime::unix_epoch()
                 .using_offset(UtcOffset::hours(-1))
                 .timestamp(),
-            3_600,
+            0,
         );
+
+        // The stored date and time are already in UTC, so the offset must not
+        // be applied again.
+        let datetime = ymd!(2019, 1, 1)
+            .with_hms(12, 0, 0)
+            .using_offset(UtcOffset::UTC);
+        for &offset in &[
+            UtcOffset::hours(-5),
+            UtcOffset::minutes(330),
+            UtcOffset::seconds(1),
+        ] {
+            let converted = datetime.to_offset(offset);
+            assert_eq!(converted.timestamp(), datetime.timestamp());
+            assert_eq!(converted, datetime);
+            assert_eq!(converted.cmp(&datetime), Ordering::Equal);
+        }
     }
 
     #[test]
@@ -1199,6 +1705,72 @@ mod test {
         );
     }
 
+    #[test]
+    fn duration_since() {
+        let earlier = ymd!(2019, 1, 1).midnight().using_offset(UtcOffset::UTC);
+        let later = ymd!(2019, 1, 2)
+            .with_hms(1, 2, 3)
+            .using_offset(UtcOffset::UTC);
+        assert_eq!(later.duration_since(earlier), 1.days() + 3_723.seconds());
+        assert_eq!(earlier.duration_since(later), -(1.days() + 3_723.seconds()));
+        assert_eq!(earlier.duration_since(earlier), 0.seconds());
+        assert_eq!(later.duration_since(earlier), later - earlier);
+    }
+
+    #[test]
+    fn duration_until() {
+        let earlier = ymd!(2019, 1, 1).midnight().using_offset(UtcOffset::UTC);
+        let later = ymd!(2019, 1, 2)
+            .with_hms(1, 2, 3)
+            .using_offset(UtcOffset::UTC);
+        assert_eq!(earlier.duration_until(later), 1.days() + 3_723.seconds());
+        assert_eq!(later.duration_until(earlier), -(1.days() + 3_723.seconds()));
+        assert_eq!(later.duration_until(later), 0.seconds());
+        assert_eq!(earlier.duration_until(later), later - earlier);
+    }
+
+    #[test]
+    fn replace_time() {
+        // 2019-01-01 23:30 at +05:00 is 18:30 UTC.
+        let offset = UtcOffset::hours(5);
+        let datetime = ymd!(2019, 1, 1)
+            .with_hms(18, 30, 0)
+            .using_offset(UtcOffset::UTC)
+            .to_offset(offset);
+        assert_eq!(datetime.time(), time!(23:30:0));
+
+        let replaced = datetime.replace_time(time!(2:0:0));
+        assert_eq!(replaced.date(), ymd!(2019, 1, 1));
+        assert_eq!(replaced.time(), time!(2:0:0));
+        assert_eq!(replaced.offset(), offset);
+        let utc = replaced.to_offset(UtcOffset::UTC);
+        assert_eq!(
+            (utc.date(), utc.time()),
+            (ymd!(2018, 12, 31), time!(21:0:0))
+        );
+        assert_eq!(datetime - replaced, 21.hours() + 30.minutes());
+        assert_eq!(datetime.replace_time(datetime.time()), datetime);
+    }
+
+    #[test]
+    fn replace_date() {
+        // 2019-01-01 01:00 at -03:00 is 04:00 UTC.
+        let offset = UtcOffset::hours(-3);
+        let datetime = ymd!(2019, 1, 1)
+            .with_hms(4, 0, 0)
+            .using_offset(UtcOffset::UTC)
+            .to_offset(offset);
+
+        let replaced = datetime.replace_date(ymd!(2019, 3, 1));
+        assert_eq!(replaced.date(), ymd!(2019, 3, 1));
+        assert_eq!(replaced.time(), time!(1:0:0));
+        assert_eq!(replaced.offset(), offset);
+        let utc = replaced.to_offset(UtcOffset::UTC);
+        assert_eq!((utc.date(), utc.time()), (ymd!(2019, 3, 1), time!(4:0:0)));
+        assert_eq!(replaced - datetime, 59.days());
+        assert_eq!(datetime.replace_date(datetime.date()), datetime);
+    }
+
     #[test]
     fn format() {
         assert_eq!(
@@ -1210,6 +1782,36 @@ mod test {
         );
     }
 
+    #[test]
+    fn unix_timestamp_specifier() {
+        let datetime = DateTime::from_unix_timestamp(1_546_300_800);
+        assert_eq!(
+            datetime
+                .using_offset(UtcOffset::hours(2))
+                .format("%F %T %s"),
+            "2019-01-01 2:00:00 1546300800"
+        );
+        assert_eq!(
+            datetime.using_offset(UtcOffset::hours(-5)).format("%s"),
+            "1546300800"
+        );
+
+        assert_eq!(
+            OffsetDateTime::parse("1546300800 +0200", "%s %z"),
+            Ok(datetime.using_offset(UtcOffset::hours(2)))
+        );
+        assert_eq!(
+            OffsetDateTime::parse("1546300800", "%s"),
+            Err(ParseError::InsufficientInformation {
+                missing: Component::Offset
+            })
+        );
+        assert_eq!(
+            DateTime::parse("1546300800 +0200", "%s %z"),
+            Ok(ymd!(2019, 1, 1).with_hms(2, 0, 0))
+        );
+    }
+
     #[test]
     fn format_language() {
         assert_eq!(
@@ -1224,7 +1826,40 @@ mod test {
                 .midnight()
                 .using_offset(UtcOffset::hours(2))
                 .format_language("%c %z", Language::es),
-            "Mi enero 2 2:00:00 2019 +0200",
+            "Mi 2 enero 2019 02:00:00 +0200",
+        );
+    }
+
+    #[test]
+    fn parse_any() {
+        let formats = ["%FT%T%:z", "%a, %d %b %Y %T %z"];
+        let expected = ymd!(2019, 1, 2)
+            .with_hms(2, 4, 5)
+            .using_offset(UtcOffset::hours(1));
+
+        let parsed = OffsetDateTime::parse_any("2019-01-02T03:04:05+01:00", &formats);
+        assert_eq!(parsed, Ok(expected));
+        assert_eq!(parsed.map(OffsetDateTime::offset), Ok(UtcOffset::hours(1)));
+        // The first format fails, but the second succeeds.
+        assert_eq!(
+            OffsetDateTime::parse_any("Wed, 02 Jan 2019 03:04:05 +0100", &formats),
+            Ok(expected)
+        );
+
+        // The error from the last format is returned.
+        assert_eq!(
+            OffsetDateTime::parse_any("2019-01-02T03:04:05", &formats),
+            OffsetDateTime::parse("2019-01-02T03:04:05", formats[1])
+        );
+        assert_eq!(
+            OffsetDateTime::parse_any("2019-01-02T03:04:05", &formats[..1]),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_any("2019-01-02T03:04:05+01:00", &[]),
+            Err(ParseError::InsufficientInformation {
+                missing: Component::Date
+            })
         );
     }
 
@@ -1245,6 +1880,89 @@ mod test {
         );
     }
 
+    #[test]
+    fn timestamp_far_from_epoch() {
+        for &datetime in &[
+            ymd!(1970, 1, 1).with_hms(0, 0, 1),
+            ymd!(1969, 12, 31).with_hms(23, 59, 59),
+            ymd!(1969, 12, 31).with_hms_milli(23, 59, 59, 500),
+            ymd!(2019, 1, 1).midnight(),
+            ymd!(2200, 12, 31).with_hms(23, 59, 59),
+            ymd!(1700, 1, 1).midnight(),
+        ] {
+            for &offset in &[
+                UtcOffset::UTC,
+                UtcOffset::hours(-1),
+                UtcOffset::minutes(330),
+            ] {
+                assert_eq!(
+                    datetime.using_offset(offset).timestamp(),
+                    datetime.timestamp()
+                );
+            }
+        }
+
+        // Both ends of the guaranteed range, which are far beyond the range of
+        // a nanosecond count.
+        assert_eq!(
+            ymd!(100_000, 12, 31)
+                .with_hms(23, 59, 59)
+                .using_offset(UtcOffset::UTC)
+                .timestamp(),
+            3_093_527_980_800 + 366 * 86_400 - 1
+        );
+        assert_eq!(
+            ymd!(-100_000, 1, 1)
+                .midnight()
+                .using_offset(UtcOffset::UTC)
+                .timestamp(),
+            -3_217_862_419_200
+        );
+    }
+
+    #[test]
+    fn parse_insufficient_information() {
+        assert_eq!(
+            OffsetDateTime::parse("2019-01-02", "%F"),
+            Err(ParseError::InsufficientInformation {
+                missing: Component::Time
+            })
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2019-01-02 00:00:00", "%F %T"),
+            Err(ParseError::InsufficientInformation {
+                missing: Component::Offset
+            })
+        );
+        assert_eq!(
+            OffsetDateTime::parse("00:00:00 +0000", "%T %z"),
+            Err(ParseError::InsufficientInformation {
+                missing: Component::Date
+            })
+        );
+        assert_eq!(
+            ParseError::InsufficientInformation {
+                missing: Component::Offset
+            }
+            .to_string(),
+            "insufficient information provided to determine the offset"
+        );
+    }
+
+    #[test]
+    fn parse_with_position() {
+        assert_eq!(
+            OffsetDateTime::parse_with_position("2019-01-02 00:00:00 +0000", "%F %T %z"),
+            Ok(ymd!(2019, 1, 2).midnight().using_offset(UtcOffset::UTC))
+        );
+
+        assert_eq!(
+            OffsetDateTime::parse_with_position("2019-01-02 00:00:00 0000", "%F %T %z")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((ParseError::InvalidOffset, 20))
+        );
+    }
+
     #[test]
     fn parse_language() {
         use Language::*;
@@ -1258,11 +1976,397 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_assume_utc() {
+        assert_eq!(
+            OffsetDateTime::parse_assume_utc("2019-01-02T03:04:05", "%Y-%m-%dT%H:%M:%S"),
+            Ok(ymd!(2019, 1, 2)
+                .with_hms(3, 4, 5)
+                .using_offset(UtcOffset::UTC)),
+        );
+        assert_eq!(
+            OffsetDateTime::parse_assume_utc("2019-01-02T03:04:05", "%Y-%m-%dT%H:%M:%S")
+                .map(OffsetDateTime::offset),
+            Ok(UtcOffset::UTC),
+        );
+        assert_eq!(
+            OffsetDateTime::parse_assume_utc("2019-01-02 03:04:05 +0000", "%F %T %z"),
+            Ok(ymd!(2019, 1, 2)
+                .with_hms(3, 4, 5)
+                .using_offset(UtcOffset::UTC)),
+        );
+        assert!(OffsetDateTime::parse("2019-01-02T03:04:05", "%Y-%m-%dT%H:%M:%S").is_err());
+    }
+
+    #[test]
+    fn parse_language_assume_utc() {
+        assert_eq!(
+            OffsetDateTime::parse_language_assume_utc(
+                "02 enero 2019 00:00:00",
+                "%d %B %Y %T",
+                Language::es
+            ),
+            Ok(ymd!(2019, 1, 2).midnight().using_offset(UtcOffset::UTC)),
+        );
+    }
+
+    #[test]
+    fn parse_offset() {
+        let datetime = OffsetDateTime::parse("2019-01-02 00:00:00 +0100", "%F %T %z");
+        assert_eq!(datetime.map(OffsetDateTime::hour), Ok(0));
+        assert_eq!(
+            datetime.map(OffsetDateTime::offset),
+            Ok(UtcOffset::hours(1))
+        );
+        assert_eq!(
+            datetime.map(|datetime| datetime.to_offset(UtcOffset::UTC).time()),
+            Ok(Time::from_hms(23, 0, 0))
+        );
+
+        // Digits following `%z` belong to the next item.
+        let datetime = OffsetDateTime::parse("+01002019-01-02 03:04:05", "%z%Y-%m-%d %H:%M:%S");
+        assert_eq!(
+            datetime.map(OffsetDateTime::offset),
+            Ok(UtcOffset::hours(1))
+        );
+        assert_eq!(datetime.map(OffsetDateTime::year), Ok(2019));
+    }
+
+    #[test]
+    fn to_timezone_string() {
+        assert_eq!(
+            ymd!(2019, 1, 2)
+                .with_hms(3, 4, 5)
+                .using_offset(UtcOffset::UTC)
+                .to_timezone_string(),
+            "2019-01-02 03:04:05+00:00"
+        );
+        assert_eq!(
+            ymd!(2019, 1, 2)
+                .with_hms(3, 4, 5)
+                .using_offset(UtcOffset::hours(2))
+                .to_timezone_string(),
+            "2019-01-02 05:04:05+02:00"
+        );
+        assert_eq!(
+            ymd!(2019, 1, 2)
+                .midnight()
+                .using_offset(UtcOffset::minutes(-330))
+                .to_timezone_string(),
+            "2019-01-01 18:30:00-05:30"
+        );
+        assert_eq!(
+            ymd!(2019, 1, 2)
+                .midnight()
+                .using_offset(UtcOffset::seconds(3_723))
+                .to_timezone_string(),
+            "2019-01-02 01:02:03+01:02:03"
+        );
+    }
+
+    #[test]
+    fn format_rfc2822() {
+        assert_eq!(
+            ymd!(2019, 1, 2)
+                .with_hms(3, 4, 5)
+                .using_offset(UtcOffset::UTC)
+                .format_rfc2822(),
+            "Wed, 02 Jan 2019 03:04:05 +0000"
+        );
+        assert_eq!(
+            ymd!(2019, 1, 2)
+                .with_hms(3, 4, 5)
+                .using_offset(UtcOffset::hours(2))
+                .format_rfc2822(),
+            "Wed, 02 Jan 2019 05:04:05 +0200"
+        );
+
+        // The names are always English, even if French is used elsewhere.
+        let datetime = ymd!(2019, 1, 2)
+            .with_hms(3, 4, 5)
+            .using_offset(UtcOffset::UTC);
+        assert_ne!(
+            datetime.format_language("%a %b", Language::fr),
+            datetime.format_language("%a %b", Language::en)
+        );
+        assert_eq!(datetime.format_rfc2822(), "Wed, 02 Jan 2019 03:04:05 +0000");
+        assert_eq!(
+            datetime.format_rfc2822(),
+            ymd!(2019, 1, 2).with_hms(3, 4, 5).format_rfc2822()
+        );
+    }
+
+    #[test]
+    fn parse_rfc2822() {
+        assert_eq!(
+            OffsetDateTime::parse_rfc2822("Wed, 02 Jan 2019 03:04:05 +0000"),
+            Ok(ymd!(2019, 1, 2)
+                .with_hms(3, 4, 5)
+                .using_offset(UtcOffset::UTC))
+        );
+        assert_eq!(
+            OffsetDateTime::parse_rfc2822("Wed, 02 Jan 2019 05:04:05 +0200")
+                .map(OffsetDateTime::offset),
+            Ok(UtcOffset::hours(2))
+        );
+
+        let french = ymd!(2019, 1, 2)
+            .with_hms(3, 4, 5)
+            .using_offset(UtcOffset::UTC)
+            .format_language("%a, %d %b %Y %H:%M:%S %z", Language::fr);
+        assert!(OffsetDateTime::parse_rfc2822(&french).is_err());
+
+        // The same strings are accepted as by `DateTime::parse_rfc2822`.
+        for &s in &[
+            "Tue, 1 Jul 2003 10:52:37 +0200",
+            "1 Jul 2003 10:52:37 +0200",
+            "tue, 01 jul 2003 10:52 +0200",
+            "Tue, 1 Jul 2003 08:52:37 GMT",
+        ] {
+            assert_eq!(
+                OffsetDateTime::parse_rfc2822(s).map(|datetime| datetime.datetime),
+                DateTime::parse_rfc2822(s),
+            );
+        }
+
+        for &offset in &[
+            UtcOffset::UTC,
+            UtcOffset::hours(-5),
+            UtcOffset::minutes(330),
+        ] {
+            let datetime = ymd!(2019, 12, 31).with_hms(23, 59, 59).using_offset(offset);
+            assert_eq!(
+                OffsetDateTime::parse_rfc2822(&datetime.format_rfc2822()),
+                Ok(datetime)
+            );
+        }
+    }
+
+    #[test]
+    fn format_postgres() {
+        let datetime = ymd!(2020, 1, 2).with_hms_micro(3, 4, 5, 123_456);
+        assert_eq!(
+            datetime.using_offset(UtcOffset::UTC).format_postgres(),
+            "2020-01-02 03:04:05.123456+00"
+        );
+        assert_eq!(
+            ymd!(2020, 1, 2)
+                .with_hms_milli(3, 4, 5, 500)
+                .using_offset(UtcOffset::UTC)
+                .format_postgres(),
+            "2020-01-02 03:04:05.5+00"
+        );
+        assert_eq!(
+            ymd!(2020, 1, 2)
+                .with_hms_nano(3, 4, 5, 999)
+                .using_offset(UtcOffset::UTC)
+                .format_postgres(),
+            "2020-01-02 03:04:05+00"
+        );
+        assert_eq!(
+            ymd!(2000, 1, 1)
+                .with_hms(0, 0, 0)
+                .using_offset(UtcOffset::hours(-5))
+                .format_postgres(),
+            "1999-12-31 19:00:00-05"
+        );
+        assert_eq!(
+            ymd!(2020, 1, 2)
+                .with_hms(3, 4, 5)
+                .using_offset(UtcOffset::minutes(330))
+                .format_postgres(),
+            "2020-01-02 08:34:05+05:30"
+        );
+        assert_eq!(
+            ymd!(1883, 11, 18)
+                .with_hms(17, 0, 0)
+                .using_offset(UtcOffset::seconds(-(4 * 3_600 + 56 * 60 + 2)))
+                .format_postgres(),
+            "1883-11-18 12:03:58-04:56:02"
+        );
+        assert_eq!(
+            ymd!(-43, 3, 15)
+                .with_hms(12, 0, 0)
+                .using_offset(UtcOffset::UTC)
+                .format_postgres(),
+            "0044-03-15 12:00:00+00 BC"
+        );
+        assert_eq!(
+            ymd!(0, 1, 1)
+                .with_hms(0, 0, 0)
+                .using_offset(UtcOffset::UTC)
+                .format_postgres(),
+            "0001-01-01 00:00:00+00 BC"
+        );
+    }
+
+    #[test]
+    fn parse_postgres() {
+        // Output of `SELECT ...::timestamptz` with various time zones.
+        for &(s, expected) in &[
+            (
+                "2020-01-02 03:04:05.123456+00",
+                ymd!(2020, 1, 2).with_hms_micro(3, 4, 5, 123_456),
+            ),
+            ("2020-01-02 03:04:05+00", ymd!(2020, 1, 2).with_hms(3, 4, 5)),
+            (
+                "2020-01-02 03:04:05.5+00",
+                ymd!(2020, 1, 2).with_hms_milli(3, 4, 5, 500),
+            ),
+            ("1999-12-31 19:00:00-05", ymd!(2000, 1, 1).with_hms(0, 0, 0)),
+            (
+                "2020-01-02 08:34:05+05:30",
+                ymd!(2020, 1, 2).with_hms(3, 4, 5),
+            ),
+            (
+                "1883-11-18 12:03:58-04:56:02",
+                ymd!(1883, 11, 18).with_hms(17, 0, 0),
+            ),
+            (
+                "0044-03-15 12:00:00+00 BC",
+                ymd!(-43, 3, 15).with_hms(12, 0, 0),
+            ),
+            ("2020-01-02T03:04:05+00", ymd!(2020, 1, 2).with_hms(3, 4, 5)),
+            (
+                "2020-01-02 08:34:05+0530",
+                ymd!(2020, 1, 2).with_hms(3, 4, 5),
+            ),
+            (
+                "2020-01-02 03:04:05.123456789+00",
+                ymd!(2020, 1, 2).with_hms_nano(3, 4, 5, 123_456_789),
+            ),
+        ] {
+            assert_eq!(
+                OffsetDateTime::parse_postgres(s)
+                    .map(|datetime| datetime.to_offset(UtcOffset::UTC).date()),
+                Ok(expected.date()),
+                "{}",
+                s
+            );
+            assert_eq!(
+                OffsetDateTime::parse_postgres(s)
+                    .map(|datetime| datetime.to_offset(UtcOffset::UTC).time()),
+                Ok(expected.time()),
+                "{}",
+                s
+            );
+        }
+
+        assert_eq!(
+            OffsetDateTime::parse_postgres("2020-01-02 08:34:05+05:30").map(OffsetDateTime::offset),
+            Ok(UtcOffset::minutes(330))
+        );
+        assert_eq!(
+            OffsetDateTime::parse_postgres("2020-01-02 03:04:05."),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_postgres("2020-01-02 03:04:05.+00"),
+            Err(ParseError::InvalidSubsecond)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_postgres("2020-01-02 03:04:05.1x+00"),
+            Err(ParseError::InvalidSubsecond)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_postgres("2020-01-02 03:04:05"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_postgres("infinity"),
+            Err(ParseError::UnexpectedEndOfString)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_postgres("2020-01-02 03:04:05+00 AD"),
+            Err(ParseError::UnexpectedTrailingCharacters)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_postgres("2020-01-02 03:04:05.1234567890+00"),
+            Err(ParseError::InvalidSubsecond)
+        );
+        // A `.` before the separator is not a subsecond.
+        assert!(OffsetDateTime::parse_postgres("2020-01.02 03:04:05+00").is_err());
+        assert!(OffsetDateTime::parse_postgres("x.y z+00").is_err());
+        assert!(OffsetDateTime::parse_postgres("2020-01-02 .+00").is_err());
+        assert!(OffsetDateTime::parse_postgres("T+").is_err());
+    }
+
+    #[test]
+    fn postgres_round_trip() {
+        for &offset in &[
+            UtcOffset::UTC,
+            UtcOffset::hours(-5),
+            UtcOffset::minutes(330),
+            UtcOffset::seconds(-17_762),
+        ] {
+            for &datetime in &[
+                ymd!(2020, 1, 2).with_hms_micro(3, 4, 5, 123_456),
+                ymd!(2019, 12, 31).with_hms(23, 59, 59),
+                ymd!(-43, 3, 15).with_hms_micro(12, 0, 0, 1),
+                ymd!(10_000, 1, 1).with_hms(0, 0, 0),
+            ] {
+                // Compare the components, as the timestamp of early dates
+                // cannot be compared in nanoseconds.
+                let datetime = datetime.using_offset(offset);
+                assert_eq!(
+                    OffsetDateTime::parse_postgres(&datetime.format_postgres()).map(|parsed| (
+                        parsed.date(),
+                        parsed.time(),
+                        parsed.offset()
+                    )),
+                    Ok((datetime.date(), datetime.time(), datetime.offset()))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parse_timezone_string() {
+        assert_eq!(
+            OffsetDateTime::parse_timezone_string("2019-01-02 03:04:05+00:00"),
+            Ok(ymd!(2019, 1, 2)
+                .with_hms(3, 4, 5)
+                .using_offset(UtcOffset::UTC))
+        );
+        assert_eq!(
+            OffsetDateTime::parse_timezone_string("2019-01-02 05:04:05+02:00"),
+            Ok(ymd!(2019, 1, 2)
+                .with_hms(3, 4, 5)
+                .using_offset(UtcOffset::hours(2)))
+        );
+        assert_eq!(
+            OffsetDateTime::parse_timezone_string("2019-01-02 03:04:05+0000"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_timezone_string("00"),
+            Err(ParseError::UnexpectedEndOfString)
+        );
+
+        for &offset in &[
+            UtcOffset::UTC,
+            UtcOffset::hours(2),
+            UtcOffset::hours(-12),
+            UtcOffset::minutes(345),
+            UtcOffset::minutes(-330),
+            UtcOffset::seconds(3_723),
+            UtcOffset::seconds(-1_172),
+        ] {
+            let datetime = ymd!(2019, 12, 31).with_hms(23, 59, 59).using_offset(offset);
+            let s = datetime.to_timezone_string();
+            assert_eq!(OffsetDateTime::parse_timezone_string(&s), Ok(datetime));
+            assert_eq!(
+                OffsetDateTime::parse_timezone_string(&s).map(OffsetDateTime::offset),
+                Ok(offset)
+            );
+        }
+    }
+
     #[test]
     fn partial_eq() {
         assert_eq!(
-            ymd!(1999, 12, 31)
-                .with_hms(23, 0, 0)
+            ymd!(2000, 1, 1)
+                .midnight()
                 .using_offset(UtcOffset::hours(-1)),
             ymd!(2000, 1, 1).midnight().using_offset(UtcOffset::UTC),
         );
@@ -1271,8 +2375,8 @@ mod test {
     #[test]
     fn partial_ord() {
         let t1 = ymd!(2019, 1, 1).midnight().using_offset(UtcOffset::UTC);
-        let t2 = ymd!(2018, 12, 31)
-            .with_hms(23, 0, 0)
+        let t2 = ymd!(2019, 1, 1)
+            .midnight()
             .using_offset(UtcOffset::hours(-1));
         assert_eq!(t1.partial_cmp(&t2), Some(Ordering::Equal));
     }
@@ -1280,8 +2384,8 @@ mod test {
     #[test]
     fn ord() {
         let t1 = ymd!(2019, 1, 1).midnight().using_offset(UtcOffset::UTC);
-        let t2 = ymd!(2018, 12, 31)
-            .with_hms(23, 0, 0)
+        let t2 = ymd!(2019, 1, 1)
+            .midnight()
             .using_offset(UtcOffset::hours(-1));
         assert_eq!(t1, t2);
     }
@@ -1302,8 +2406,8 @@ mod test {
             },
             {
                 let mut hasher = DefaultHasher::new();
-                ymd!(2018, 12, 31)
-                    .with_hms(23, 0, 0)
+                ymd!(2019, 1, 1)
+                    .midnight()
                     .using_offset(UtcOffset::hours(-1))
                     .hash(&mut hasher);
                 hasher.finish()
@@ -1346,6 +2450,27 @@ mod test {
                 + 1.hours(),
             ymd!(2000, 1, 1).midnight().using_offset(UtcOffset::UTC),
         );
+
+        let datetime = ymd!(2019, 12, 31)
+            .with_hms(22, 30, 0)
+            .using_offset(UtcOffset::hours(2));
+        let next = datetime + 1.days();
+        assert_eq!(datetime.date(), ymd!(2020, 1, 1));
+        assert_eq!(next.date(), ymd!(2020, 1, 2));
+        assert_eq!(next.time(), time!(0:30:00));
+        assert_eq!(next.offset(), UtcOffset::hours(2));
+        assert_eq!(next.timestamp() - datetime.timestamp(), 86_400);
+        assert_eq!(next.to_offset(UtcOffset::UTC).date(), ymd!(2020, 1, 1));
+        assert_eq!(next - datetime, 1.days());
+
+        let datetime = ymd!(2019, 3, 1)
+            .with_hms(3, 0, 0)
+            .using_offset(UtcOffset::hours(-5));
+        let next = datetime + 1.days();
+        assert_eq!(datetime.date(), ymd!(2019, 2, 28));
+        assert_eq!(next.date(), ymd!(2019, 3, 1));
+        assert_eq!(next.time(), time!(22:00:00));
+        assert_eq!(next.offset(), UtcOffset::hours(-5));
     }
 
     #[test]
@@ -1471,6 +2596,17 @@ mod test {
                 - (-1).hours(),
             ymd!(2000, 1, 1).midnight().using_offset(UtcOffset::UTC),
         );
+
+        let datetime = ymd!(2020, 1, 1)
+            .with_hms(22, 30, 0)
+            .using_offset(UtcOffset::hours(2));
+        let previous = datetime - 1.days();
+        assert_eq!(datetime.date(), ymd!(2020, 1, 2));
+        assert_eq!(previous.date(), ymd!(2020, 1, 1));
+        assert_eq!(previous.time(), time!(0:30:00));
+        assert_eq!(previous.offset(), UtcOffset::hours(2));
+        assert_eq!(datetime.timestamp() - previous.timestamp(), 86_400);
+        assert_eq!(datetime - previous, 1.days());
     }
 
     #[test]
@@ -1,4 +1,8 @@
-use core::ops::{Div, DivAssign, Mul, MulAssign, Neg, Not};
+use crate::shim::NumberExt;
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Div, DivAssign, Mul, MulAssign, Neg, Not},
+};
 use Sign::{Negative, Positive, Zero};
 
 /// Contains the sign of a value: positive, negative, or zero.
@@ -138,7 +142,36 @@ impl Not for Sign {
     }
 }
 
+impl Display for Sign {
+    /// Display the sign as `+` or `-`. As zero is conventionally written
+    /// without a negative sign, `Zero` is displayed as `+`.
+    ///
+    /// ```rust
+    /// # use time::Sign;
+    /// assert_eq!(Sign::Positive.to_string(), "+");
+    /// assert_eq!(Sign::Negative.to_string(), "-");
+    /// assert_eq!(Sign::Zero.to_string(), "+");
+    /// ```
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.is_negative() { "-" } else { "+" })
+    }
+}
+
 impl Sign {
+    /// Get the sign of the provided value.
+    ///
+    /// ```rust
+    /// # use time::Sign;
+    /// assert_eq!(Sign::from_value(5), Sign::Positive);
+    /// assert_eq!(Sign::from_value(-5), Sign::Negative);
+    /// assert_eq!(Sign::from_value(0), Sign::Zero);
+    /// ```
+    #[inline(always)]
+    pub fn from_value(value: i64) -> Self {
+        value.sign()
+    }
+
     /// Return the opposite of the current sign.
     ///
     /// ```rust
@@ -199,6 +232,8 @@ impl Sign {
 #[cfg(test)]
 mod test {
     use super::*;
+    #[cfg(not(feature = "std"))]
+    use crate::no_std_prelude::*;
 
     macro_rules! op_assign {
         ($a:ident $op:tt $b:ident) => {{
@@ -302,6 +337,24 @@ mod test {
         assert_eq!(Zero.negate(), Zero);
     }
 
+    #[test]
+    fn display() {
+        assert_eq!(Positive.to_string(), "+");
+        assert_eq!(Negative.to_string(), "-");
+        assert_eq!(Zero.to_string(), "+");
+        assert_eq!(format!("{}{}", Negative, 5), "-5");
+    }
+
+    #[test]
+    fn from_value() {
+        assert_eq!(Sign::from_value(1), Positive);
+        assert_eq!(Sign::from_value(i64::max_value()), Positive);
+        assert_eq!(Sign::from_value(-1), Negative);
+        assert_eq!(Sign::from_value(i64::min_value()), Negative);
+        assert_eq!(Sign::from_value(0), Zero);
+        assert_eq!(Sign::from_value(-7) * 7, -7);
+    }
+
     #[test]
     fn is_positive() {
         assert!(Positive.is_positive());
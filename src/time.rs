@@ -1,10 +1,11 @@
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
-#[cfg(feature = "std")]
-use crate::DateTime;
 use crate::{
-    format::{parse, parse::AmPm, ParseError, ParseResult, ParsedItems},
-    DeferredFormat, Duration, Language,
+    format::{
+        parse, parse::AmPm, parse_strict, parse_with_position, Component, ParseError, ParseErrorAt,
+        ParseResult, ParsedItems,
+    },
+    DateTime, DeferredFormat, Duration, Language,
 };
 use core::{
     num::NonZeroU8,
@@ -22,7 +23,6 @@ pub(crate) const NANOS_PER_DAY: u64 = 24 * 60 * 60 * 1_000_000_000;
 ///
 /// As order is dependent on context (is noon before or after midnight?), this
 /// type does not implement `PartialOrd` or `Ord`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Time {
     #[allow(clippy::missing_docs_in_private_items)]
@@ -337,6 +337,89 @@ impl Time {
         self.nanosecond
     }
 
+    /// Get the number of whole seconds since midnight. Any subsecond component
+    /// is truncated.
+    ///
+    /// The returned value will always be in the range `0..86_400`.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// assert_eq!(Time::midnight().as_seconds_since_midnight(), 0);
+    /// assert_eq!(Time::from_hms(12, 0, 0).as_seconds_since_midnight(), 43_200);
+    /// assert_eq!(
+    ///     Time::from_hms_milli(23, 59, 59, 999).as_seconds_since_midnight(),
+    ///     86_399
+    /// );
+    /// ```
+    #[inline(always)]
+    pub const fn as_seconds_since_midnight(self) -> u32 {
+        self.hour as u32 * 60 * 60 + self.minute as u32 * 60 + self.second as u32
+    }
+
+    /// Create a `Time` from the number of seconds since midnight, returning
+    /// `None` if the value is not in the range `0..86_400`.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// assert_eq!(Time::from_seconds_since_midnight(0), Some(Time::midnight()));
+    /// assert_eq!(
+    ///     Time::from_seconds_since_midnight(43_200),
+    ///     Some(Time::from_hms(12, 0, 0))
+    /// );
+    /// assert_eq!(Time::from_seconds_since_midnight(86_400), None);
+    /// ```
+    #[inline]
+    pub fn from_seconds_since_midnight(seconds: u32) -> Option<Self> {
+        if seconds >= 86_400 {
+            return None;
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        Some(Self {
+            hour: (seconds / 3_600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+            second: (seconds % 60) as u8,
+            nanosecond: 0,
+        })
+    }
+
+    /// Truncate the time to the whole second, setting the subsecond component
+    /// to zero.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// assert_eq!(
+    ///     Time::from_hms_nano(12, 34, 56, 789).truncate_to_second(),
+    ///     Time::from_hms(12, 34, 56)
+    /// );
+    /// ```
+    #[inline(always)]
+    pub const fn truncate_to_second(self) -> Self {
+        Self {
+            nanosecond: 0,
+            ..self
+        }
+    }
+
+    /// Truncate the time to the whole minute, setting the second and subsecond
+    /// components to zero.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// assert_eq!(
+    ///     Time::from_hms_nano(12, 34, 56, 789).truncate_to_minute(),
+    ///     Time::from_hms(12, 34, 0)
+    /// );
+    /// ```
+    #[inline(always)]
+    pub const fn truncate_to_minute(self) -> Self {
+        Self {
+            second: 0,
+            nanosecond: 0,
+            ..self
+        }
+    }
+
     /// Get the number of nanoseconds since midnight.
     #[inline(always)]
     pub(crate) const fn nanoseconds_since_midnight(self) -> u64 {
@@ -405,12 +488,49 @@ impl Time {
     /// ```
     #[inline(always)]
     pub fn parse(s: &str, format: &str) -> ParseResult<Self> {
-        Self::try_from_parsed_items(parse(s, format, Language::en)?)
+        Self::try_from_parsed_items(&parse(s, format, Language::en)?)
+    }
+
+    /// Attempt to parse a `Time` using the provided string. Any error includes
+    /// the position in the input at which it occurred.
+    ///
+    /// ```rust
+    /// # use time::{ParseError, Time};
+    /// assert_eq!(
+    ///     Time::parse_with_position("12:34:56", "%T"),
+    ///     Ok(Time::from_hms(12, 34, 56))
+    /// );
+    /// let error = Time::parse_with_position("12:60:00", "%H:%M:%S").unwrap_err();
+    /// assert_eq!(error.kind(), ParseError::InvalidMinute);
+    /// assert_eq!(error.index(), 3);
+    /// ```
+    #[inline(always)]
+    pub fn parse_with_position(s: &str, format: &str) -> Result<Self, ParseErrorAt> {
+        parse_with_position(s, format, Language::en, Self::try_from_parsed_items)
+    }
+
+    /// Attempt to parse a `Time` using the provided string, returning an error if
+    /// any input remains once the format is exhausted.
+    ///
+    /// ```rust
+    /// # use time::{ParseError, Time};
+    /// assert_eq!(
+    ///     Time::parse_strict("12:34", "%R"),
+    ///     Ok(Time::from_hms(12, 34, 0))
+    /// );
+    /// assert_eq!(
+    ///     Time::parse_strict("12:34:56", "%R"),
+    ///     Err(ParseError::UnexpectedTrailingCharacters)
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn parse_strict(s: &str, format: &str) -> ParseResult<Self> {
+        Self::try_from_parsed_items(&parse_strict(s, format, Language::en)?)
     }
 
     /// Given the items already parsed, attempt to create a `Time`.
     #[inline]
-    pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
+    pub(crate) fn try_from_parsed_items(items: &ParsedItems) -> ParseResult<Self> {
         macro_rules! items {
             ($($item:ident),* $(,)?) => {
                 ParsedItems { $($item: Some($item)),*, .. }
@@ -429,7 +549,11 @@ impl Time {
             }
         }
 
-        match items {
+        if let Some(datetime) = DateTime::from_parsed_timestamp(items)? {
+            return Ok(datetime.time());
+        }
+
+        let time = match *items {
             items!(hour_24, minute, second) => Ok(Self::from_hms(hour_24, minute, second)),
             items!(hour_12, minute, second, am_pm) => Ok(Self::from_hms(
                 hour_12_to_24(hour_12, am_pm),
@@ -442,8 +566,15 @@ impl Time {
             }
             items!(hour_24) => Ok(Self::from_hms(hour_24, 0, 0)),
             items!(hour_12, am_pm) => Ok(Self::from_hms(hour_12_to_24(hour_12, am_pm), 0, 0)),
-            _ => Err(ParseError::InsufficientInformation),
-        }
+            _ => Err(ParseError::InsufficientInformation {
+                missing: Component::Time,
+            }),
+        }?;
+
+        Ok(Self {
+            nanosecond: items.nanosecond.unwrap_or(0),
+            ..time
+        })
     }
 }
 
@@ -662,6 +793,72 @@ impl Sub<Time> for Time {
     }
 }
 
+/// Human-readable formats (such as JSON) use the `HH:MM:SS` representation,
+/// followed by the subsecond component when it is nonzero. The subsecond
+/// component uses the shortest of three, six, or nine digits that represents
+/// the value exactly. Compact formats use the number of nanoseconds since
+/// midnight.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Time {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use core::fmt::Write;
+
+        if !serializer.is_human_readable() {
+            return self.nanoseconds_since_midnight().serialize(serializer);
+        }
+
+        let mut s = self.format("%H:%M:%S");
+
+        // Writing to a `String` cannot fail.
+        let _ = match self.nanosecond {
+            0 => Ok(()),
+            n if n % 1_000_000 == 0 => write!(s, ".{:03}", n / 1_000_000),
+            n if n % 1_000 == 0 => write!(s, ".{:06}", n / 1_000),
+            n => write!(s, ".{:09}", n),
+        };
+
+        serializer.serialize_str(&s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for Time {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        use crate::format::time::parse_subsecond;
+        use serde::de::Error;
+
+        if !deserializer.is_human_readable() {
+            let nanoseconds = u64::deserialize(deserializer)?;
+
+            if nanoseconds >= NANOS_PER_DAY {
+                return Err(D::Error::custom(
+                    "nanoseconds since midnight is out of range",
+                ));
+            }
+
+            return Ok(Self::from_nanoseconds_since_midnight(nanoseconds));
+        }
+
+        let s = String::deserialize(deserializer)?;
+        let (hms, subsecond) = s
+            .find('.')
+            .map_or((&s[..], None), |index| (&s[..index], Some(&s[index..])));
+
+        let mut time = Self::parse_strict(hms, "%H:%M:%S").map_err(D::Error::custom)?;
+
+        if let Some(mut subsecond) = subsecond {
+            time.nanosecond = parse_subsecond(&mut subsecond, 9, true).map_err(D::Error::custom)?;
+            if !subsecond.is_empty() {
+                return Err(D::Error::custom(ParseError::InvalidSubsecond));
+            }
+        }
+
+        Ok(time)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -686,6 +883,36 @@ mod test {
         assert_eq!(Time::midnight(), Time::from_hms(0, 0, 0));
     }
 
+    #[test]
+    fn seconds_since_midnight() {
+        assert_eq!(Time::midnight().as_seconds_since_midnight(), 0);
+        assert_eq!(Time::from_hms(12, 0, 0).as_seconds_since_midnight(), 43_200);
+        assert_eq!(Time::from_hms(1, 2, 3).as_seconds_since_midnight(), 3_723);
+        assert_eq!(
+            Time::from_hms_nano(23, 59, 59, 999_999_999).as_seconds_since_midnight(),
+            86_399
+        );
+
+        assert_eq!(Time::from_seconds_since_midnight(0), Some(Time::midnight()));
+        assert_eq!(
+            Time::from_seconds_since_midnight(43_200),
+            Some(Time::from_hms(12, 0, 0))
+        );
+        assert_eq!(
+            Time::from_seconds_since_midnight(86_399),
+            Some(Time::from_hms(23, 59, 59))
+        );
+        assert_eq!(Time::from_seconds_since_midnight(86_400), None);
+        assert_eq!(Time::from_seconds_since_midnight(u32::max_value()), None);
+
+        for seconds in (0..86_400).step_by(997) {
+            assert_eq!(
+                Time::from_seconds_since_midnight(seconds).map(Time::as_seconds_since_midnight),
+                Some(seconds)
+            );
+        }
+    }
+
     #[test]
     fn from_hms() {
         let time = Time::from_hms(1, 2, 3);
@@ -812,6 +1039,31 @@ mod test {
         }
     }
 
+    #[test]
+    fn truncate_to_second() {
+        let time = Time::from_hms_nano(12, 34, 56, 789_012_345).truncate_to_second();
+        assert_eq!(time, Time::from_hms(12, 34, 56));
+        assert_eq!(time.nanosecond(), 0);
+        assert_eq!(
+            Time::from_hms_nano(23, 59, 59, 999_999_999).truncate_to_second(),
+            Time::from_hms(23, 59, 59)
+        );
+        assert_eq!(Time::midnight().truncate_to_second(), Time::midnight());
+    }
+
+    #[test]
+    fn truncate_to_minute() {
+        let time = Time::from_hms_nano(12, 34, 56, 789_012_345).truncate_to_minute();
+        assert_eq!(time, Time::from_hms(12, 34, 0));
+        assert_eq!(time.second(), 0);
+        assert_eq!(time.nanosecond(), 0);
+        assert_eq!(
+            Time::from_hms_nano(23, 59, 59, 999_999_999).truncate_to_minute(),
+            Time::from_hms(23, 59, 0)
+        );
+        assert_eq!(Time::midnight().truncate_to_minute(), Time::midnight());
+    }
+
     #[test]
     fn format() {
         assert_eq!(Time::from_hms(0, 0, 0).format("%T"), "0:00:00");
@@ -842,6 +1094,37 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_with_position() {
+        assert_eq!(
+            Time::parse_with_position("12:34:56", "%T"),
+            Ok(Time::from_hms(12, 34, 56))
+        );
+
+        assert_eq!(
+            Time::parse_with_position("12:60:00", "%H:%M:%S")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((ParseError::InvalidMinute, 3))
+        );
+
+        // Combination specifiers are reported at their start.
+        assert_eq!(
+            Time::parse_with_position("at 12:60:00", "at %T")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((ParseError::InvalidMinute, 3))
+        );
+
+        assert_eq!(
+            Time::parse_with_position("12", "%I").map_err(|error| (error.kind(), error.index())),
+            Err((
+                ParseError::InsufficientInformation {
+                    missing: Component::Time
+                },
+                2
+            ))
+        );
+    }
+
     #[test]
     fn parse_missing_seconds() {
         // Missing seconds defaults to zero.
@@ -857,6 +1140,45 @@ mod test {
         );
     }
 
+    #[test]
+    fn twelve_hour_clock() {
+        assert_eq!(
+            Time::parse("02:30 PM", "%I:%M %p"),
+            Ok(Time::from_hms(14, 30, 0))
+        );
+
+        // Each side of both midnight and noon.
+        for &(s, hour, minute) in &[
+            ("11:59 PM", 23, 59),
+            ("12:00 AM", 0, 0),
+            ("12:59 AM", 0, 59),
+            ("01:00 AM", 1, 0),
+            ("11:59 AM", 11, 59),
+            ("12:00 PM", 12, 0),
+            ("12:59 PM", 12, 59),
+            ("01:00 PM", 13, 0),
+        ] {
+            let time = Time::from_hms(hour, minute, 0);
+            assert_eq!(Time::parse(s, "%I:%M %P"), Ok(time));
+            assert_eq!(time.format("%I:%M %P"), s);
+        }
+
+        for hour in 0..24 {
+            let time = Time::from_hms(hour, 0, 0);
+            assert_eq!(Time::parse(&time.format("%I %p"), "%I %p"), Ok(time));
+            assert_eq!(Time::parse(&time.format("%-I%P"), "%-I%P"), Ok(time));
+        }
+
+        assert_eq!(
+            Time::parse("00:30 AM", "%I:%M %p"),
+            Err(ParseError::InvalidHour)
+        );
+        assert_eq!(
+            Time::parse("13:30 PM", "%I:%M %p"),
+            Err(ParseError::InvalidHour)
+        );
+    }
+
     #[test]
     fn parse_missing_minutes() {
         // Missing minutes defaults to zero.
@@ -866,12 +1188,224 @@ mod test {
         assert_eq!(Time::parse("12pm", "%I%p"), Ok(Time::from_hms(12, 0, 0)));
     }
 
+    #[test]
+    fn format_milliseconds() {
+        assert_eq!(Time::from_hms(0, 0, 0).format("%L"), "000");
+        assert_eq!(
+            Time::from_hms_milli(12, 34, 56, 123).format("%T.%L"),
+            "12:34:56.123"
+        );
+        assert_eq!(Time::from_hms_milli(0, 0, 0, 7).format("%L"), "007");
+        // Smaller units are truncated.
+        assert_eq!(
+            Time::from_hms_nano(0, 0, 0, 999_999_999).format("%L"),
+            "999"
+        );
+    }
+
+    #[test]
+    fn parse_milliseconds() {
+        assert_eq!(
+            Time::parse("12:34:56.123", "%T.%L"),
+            Ok(Time::from_hms_milli(12, 34, 56, 123))
+        );
+        assert_eq!(
+            Time::parse("00:00:00.007", "%H:%M:%S.%L"),
+            Ok(Time::from_hms_milli(0, 0, 0, 7))
+        );
+        assert_eq!(
+            Time::parse("12:34:56.12", "%T.%L"),
+            Err(ParseError::InvalidSubsecond)
+        );
+        assert_eq!(
+            Time::parse("12:34:56.abc", "%T.%L"),
+            Err(ParseError::InvalidSubsecond)
+        );
+
+        let time = Time::from_hms_milli(23, 59, 59, 999);
+        assert_eq!(Time::parse(&time.format("%T.%L"), "%T.%L"), Ok(time));
+    }
+
+    #[test]
+    fn format_subsecond() {
+        let time = Time::from_hms_nano(12, 34, 56, 123_456_789);
+        assert_eq!(time.format("%f"), ".123456789");
+        assert_eq!(time.format("%9f"), "123456789");
+        assert_eq!(time.format("%6f"), "123456");
+        assert_eq!(time.format("%3f"), "123");
+        assert_eq!(time.format("%1f"), "1");
+        assert_eq!(time.format("%T.%3f"), "12:34:56.123");
+
+        // Values are truncated, not rounded.
+        let time = Time::from_hms_nano(0, 0, 0, 999_999_999);
+        assert_eq!(time.format("%3f"), "999");
+        assert_eq!(time.format("%6f"), "999999");
+
+        let time = Time::from_hms_nano(0, 0, 0, 1_000);
+        assert_eq!(time.format("%9f"), "000001000");
+        assert_eq!(time.format("%6f"), "000001");
+        assert_eq!(time.format("%3f"), "000");
+    }
+
+    #[test]
+    fn format_subsecond_rounded() {
+        let time = Time::from_hms_nano(12, 34, 56, 123_456_789);
+        assert_eq!(time.format("%~9f"), "123456789");
+        assert_eq!(time.format("%~6f"), "123457");
+        assert_eq!(time.format("%~3f"), "123");
+        assert_eq!(time.format("%~1f"), "1");
+
+        // Exactly half rounds up.
+        assert_eq!(Time::from_hms_nano(0, 0, 0, 500_000).format("%~3f"), "001");
+        assert_eq!(Time::from_hms_nano(0, 0, 0, 499_999).format("%~3f"), "000");
+
+        // Rounding to a full second carries into the other components.
+        let time = Time::from_hms_nano(12, 34, 56, 999_999_999);
+        assert_eq!(time.format("%T.%~3f"), "12:34:57.000");
+        assert_eq!(time.format("%T.%3f"), "12:34:56.999");
+        assert_eq!(
+            Time::from_hms_nano(12, 59, 59, 999_500_000).format("%T.%~3f"),
+            "13:00:00.000"
+        );
+        assert_eq!(
+            Time::from_hms_nano(23, 59, 59, 999_500_000).format("%T.%~3f"),
+            "0:00:00.000"
+        );
+
+        // The coarsest rounding applies to all components.
+        assert_eq!(time.format("%~3f %~6f"), "000 000000");
+        assert_eq!(time.format("%~3N"), "000");
+    }
+
+    #[test]
+    fn parse_subsecond() {
+        assert_eq!(
+            Time::parse("12:34:56.123456789", "%T%f"),
+            Ok(Time::from_hms_nano(12, 34, 56, 123_456_789))
+        );
+        assert_eq!(
+            Time::parse("12:34:56.123456789", "%T.%9f"),
+            Ok(Time::from_hms_nano(12, 34, 56, 123_456_789))
+        );
+        assert_eq!(
+            Time::parse("12:34:56.123456", "%T.%6f"),
+            Ok(Time::from_hms_micro(12, 34, 56, 123_456))
+        );
+        assert_eq!(
+            Time::parse("12:34:56.123", "%T.%3f"),
+            Ok(Time::from_hms_milli(12, 34, 56, 123))
+        );
+        assert_eq!(
+            Time::parse("12:34:56.12", "%T.%3f"),
+            Err(ParseError::InvalidSubsecond)
+        );
+        assert_eq!(
+            Time::parse("12:34:56.123", "%T.%~3f"),
+            Ok(Time::from_hms_milli(12, 34, 56, 123))
+        );
+    }
+
+    #[test]
+    fn nanosecond_specifier() {
+        let time = Time::from_hms_nano(12, 34, 56, 123_456_789);
+        assert_eq!(time.format("%N"), "123456789");
+        assert_eq!(time.format("%9N"), "123456789");
+        assert_eq!(time.format("%6N"), "123456");
+        assert_eq!(time.format("%3N"), "123");
+        assert_eq!(Time::from_hms_milli(12, 0, 0, 120).format("%6N"), "120000");
+        assert_eq!(Time::from_hms(12, 0, 0).format("%3N"), "000");
+
+        assert_eq!(
+            Time::parse("12:00:00.123", "%H:%M:%S.%3N"),
+            Ok(Time::from_hms_milli(12, 0, 0, 123))
+        );
+        assert_eq!(
+            Time::parse("12:00:00.000123", "%H:%M:%S.%6N"),
+            Ok(Time::from_hms_micro(12, 0, 0, 123))
+        );
+        assert_eq!(
+            Time::parse("12:00:00.123456789", "%T.%N"),
+            Ok(Time::from_hms_nano(12, 0, 0, 123_456_789))
+        );
+        assert_eq!(
+            Time::parse("12:00:00.12", "%T.%3N"),
+            Err(ParseError::InvalidSubsecond)
+        );
+    }
+
+    #[test]
+    fn trimmed_subsecond() {
+        assert_eq!(
+            Time::from_hms_milli(12, 0, 0, 500).format("%T%f"),
+            "12:00:00.5"
+        );
+        assert_eq!(Time::from_hms_milli(12, 0, 0, 123).format("%f"), ".123");
+        assert_eq!(Time::from_hms_micro(12, 0, 0, 1).format("%f"), ".000001");
+        assert_eq!(Time::from_hms_nano(12, 0, 0, 10).format("%f"), ".00000001");
+        // The fractional part is omitted entirely, including the `.`.
+        assert_eq!(Time::from_hms(12, 0, 0).format("%T%f"), "12:00:00");
+        assert_eq!(Time::from_hms(12, 0, 0).format("%N"), "000000000");
+        assert_eq!(
+            Time::from_hms_milli(12, 0, 0, 500).format("%N"),
+            "500000000"
+        );
+
+        assert_eq!(
+            Time::parse("12:00:00.5", "%T%f"),
+            Ok(Time::from_hms_milli(12, 0, 0, 500))
+        );
+        assert_eq!(
+            Time::parse("12:00:00.000001", "%T%f"),
+            Ok(Time::from_hms_micro(12, 0, 0, 1))
+        );
+        assert_eq!(
+            Time::parse_strict("12:00:00", "%T%f"),
+            Ok(Time::from_hms(12, 0, 0))
+        );
+        assert_eq!(
+            Time::parse("12:00:00.", "%T%f"),
+            Err(ParseError::InvalidSubsecond)
+        );
+        // At most nine digits are consumed.
+        assert_eq!(
+            Time::parse_strict("12:00:00.1234567891", "%T%f1"),
+            Ok(Time::from_hms_nano(12, 0, 0, 123_456_789))
+        );
+        assert_eq!(
+            Time::parse("12:00:00.5", "%T.%N"),
+            Err(ParseError::InvalidSubsecond)
+        );
+
+        for &nanosecond in &[0, 1, 10, 500_000_000, 123_456_789, 999_999_999] {
+            let time = Time::from_hms_nano(12, 0, 0, nanosecond);
+            assert_eq!(Time::parse_strict(&time.format("%T%f"), "%T%f"), Ok(time));
+        }
+    }
+
     #[test]
     fn add_duration() {
         assert_eq!(Time::midnight() + 1.seconds(), Time::from_hms(0, 0, 1));
         assert_eq!(Time::midnight() + 1.minutes(), Time::from_hms(0, 1, 0));
         assert_eq!(Time::midnight() + 1.hours(), Time::from_hms(1, 0, 0));
         assert_eq!(Time::midnight() + 1.days(), Time::midnight());
+
+        // Overflow
+        assert_eq!(
+            Time::from_hms(23, 59, 59) + 2.seconds(),
+            Time::from_hms(0, 0, 1)
+        );
+        assert_eq!(
+            Time::from_hms(22, 0, 0) + 3.hours(),
+            Time::from_hms(1, 0, 0)
+        );
+        assert_eq!(
+            Time::from_hms(12, 0, 0) + 3.days() + 1.hours(),
+            Time::from_hms(13, 0, 0)
+        );
+        assert_eq!(
+            Time::from_hms(0, 0, 1) + (-2).seconds(),
+            Time::from_hms(23, 59, 59)
+        );
     }
 
     #[test]
@@ -903,6 +1437,20 @@ mod test {
         assert_eq!(Time::midnight() - 1.minutes(), Time::from_hms(23, 59, 0));
         assert_eq!(Time::midnight() - 1.hours(), Time::from_hms(23, 0, 0));
         assert_eq!(Time::midnight() - 1.days(), Time::midnight());
+        assert_eq!(
+            Time::from_hms(1, 0, 0) - 3.hours(),
+            Time::from_hms(22, 0, 0)
+        );
+        assert_eq!(
+            Time::from_hms(12, 0, 0) - 3.days() - 1.hours(),
+            Time::from_hms(11, 0, 0)
+        );
+
+        // Overflow
+        assert_eq!(
+            Time::from_hms(23, 59, 59) - (-2).seconds(),
+            Time::from_hms(0, 0, 1)
+        );
     }
 
     #[test]
@@ -993,4 +1541,91 @@ mod test {
             59.minutes() + 59.seconds()
         );
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let mut times = HashSet::new();
+        assert!(times.insert(Time::from_hms(12, 30, 0)));
+        assert!(!times.insert(Time::from_hms_nano(12, 30, 0, 0)));
+        assert_eq!(
+            Time::from_seconds_since_midnight(45_000).map(|time| times.insert(time)),
+            Some(false)
+        );
+        assert!(!times.insert(Time::from_hms(12, 0, 0) + 30.minutes()));
+        assert_eq!(
+            Time::parse("12:30:00", "%T").map(|time| times.insert(time)),
+            Ok(false)
+        );
+        assert_eq!(times.len(), 1);
+
+        assert!(times.insert(Time::from_hms_nano(12, 30, 0, 1)));
+        assert!(times.insert(Time::from_hms(0, 30, 0)));
+        assert_eq!(times.len(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        use serde_test::{
+            assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Readable,
+            Token,
+        };
+
+        assert_tokens(
+            &Time::from_hms(0, 0, 0).readable(),
+            &[Token::Str("00:00:00")],
+        );
+        assert_tokens(
+            &Time::from_hms(23, 59, 59).readable(),
+            &[Token::Str("23:59:59")],
+        );
+        assert_tokens(
+            &Time::from_hms_milli(12, 34, 56, 789).readable(),
+            &[Token::Str("12:34:56.789")],
+        );
+        assert_tokens(
+            &Time::from_hms_micro(12, 34, 56, 789_012).readable(),
+            &[Token::Str("12:34:56.789012")],
+        );
+        assert_tokens(
+            &Time::from_hms_nano(12, 34, 56, 789_012_345).readable(),
+            &[Token::Str("12:34:56.789012345")],
+        );
+        assert_de_tokens(
+            &Time::from_hms_milli(12, 34, 56, 500).readable(),
+            &[Token::Str("12:34:56.5")],
+        );
+
+        assert_tokens(&Time::midnight().compact(), &[Token::U64(0)]);
+        assert_tokens(
+            &Time::from_hms_nano(23, 59, 59, 999_999_999).compact(),
+            &[Token::U64(NANOS_PER_DAY - 1)],
+        );
+        assert_tokens(
+            &Time::from_hms_milli(12, 34, 56, 789).compact(),
+            &[Token::U64(45_296_789_000_000)],
+        );
+
+        assert_de_tokens_error::<Readable<Time>>(&[Token::Str("24:00:00")], "invalid hour");
+        assert_de_tokens_error::<Readable<Time>>(
+            &[Token::Str("12:00:00junk")],
+            "unexpected trailing characters",
+        );
+        assert_de_tokens_error::<Readable<Time>>(
+            &[Token::Str("12:00:00.5junk")],
+            "invalid subsecond",
+        );
+        assert_de_tokens_error::<Readable<Time>>(&[Token::Str("12:00:00.")], "invalid subsecond");
+        assert_de_tokens_error::<Readable<Time>>(
+            &[Token::Str("12:00:00.1234567890")],
+            "invalid subsecond",
+        );
+        assert_de_tokens_error::<Compact<Time>>(
+            &[Token::U64(NANOS_PER_DAY)],
+            "nanoseconds since midnight is out of range",
+        );
+    }
 }
@@ -1,9 +1,18 @@
 #[cfg(not(feature = "std"))]
 use crate::no_std_prelude::*;
+#[cfg(feature = "std")]
+use crate::DateTime;
 use crate::{
-    format::{parse, ParseError, ParseResult, ParsedItems},
+    format::{
+        parse, parse_strict, parse_with_position, Component, ParseError, ParseErrorAt, ParseResult,
+        ParsedItems,
+    },
     DeferredFormat, Duration, Language,
 };
+use core::{
+    ops::{Add, AddAssign, Sub, SubAssign},
+    str::FromStr,
+};
 
 /// An offset from UTC.
 ///
@@ -11,7 +20,6 @@ use crate::{
 /// may have incidental support that can change at any time without notice. If
 /// you need support outside this range, please file an issue with your use
 /// case.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct UtcOffset {
     /// The number of seconds offset from UTC. Positive is east, negative is
@@ -187,11 +195,104 @@ impl UtcOffset {
         (self.as_seconds() / 3_600) as i8
     }
 
+    /// Round the offset to the nearest whole minute, with halfway values
+    /// rounded away from zero. This is useful when displaying the offset in a
+    /// format that cannot represent seconds.
+    ///
+    /// If rounding would result in a magnitude greater than 23:59:59, the
+    /// offset is rounded towards zero instead.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(
+    ///     UtcOffset::seconds(5 * 3_600 + 30 * 60 + 44).round_to_minutes(),
+    ///     UtcOffset::minutes(331)
+    /// );
+    /// assert_eq!(UtcOffset::seconds(-90).round_to_minutes(), UtcOffset::minutes(-2));
+    /// ```
+    #[inline(always)]
+    pub fn round_to_minutes(self) -> Self {
+        self.round_to(60)
+    }
+
+    /// Round the offset to the nearest whole hour, with halfway values rounded
+    /// away from zero.
+    ///
+    /// If rounding would result in a magnitude greater than 23:59:59, the
+    /// offset is rounded towards zero instead.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::minutes(330).round_to_hours(), UtcOffset::hours(6));
+    /// assert_eq!(UtcOffset::minutes(-329).round_to_hours(), UtcOffset::hours(-5));
+    /// ```
+    #[inline(always)]
+    pub fn round_to_hours(self) -> Self {
+        self.round_to(3_600)
+    }
+
+    /// Round the offset to the nearest multiple of `unit` seconds, with
+    /// halfway values rounded away from zero.
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn round_to(self, unit: i32) -> Self {
+        let mut magnitude = (self.seconds.abs() + unit / 2) / unit * unit;
+        if magnitude > MAX_ARITHMETIC_SECONDS as i32 {
+            magnitude -= unit;
+        }
+        Self::seconds(self.seconds.signum() * magnitude)
+    }
+
     /// Convert a `UtcOffset` to ` Duration`. Useful for implementing operators.
     #[inline(always)]
     pub(crate) fn as_duration(self) -> Duration {
         Duration::seconds(self.seconds as i64)
     }
+
+    /// Obtain the system's UTC offset at the provided instant, which is
+    /// interpreted as UTC. If the offset cannot be determined, UTC is
+    /// returned.
+    ///
+    /// The value is obtained from the operating system's timezone database.
+    /// As such, it depends on the environment of the process (such as the `TZ`
+    /// environment variable on Unix), and may differ between two instants on
+    /// either side of a daylight saving time transition. On Unix, the `TZ`
+    /// environment variable may only be read the first time an offset is
+    /// obtained.
+    ///
+    /// ```rust
+    /// # use time::{DateTime, UtcOffset};
+    /// let offset = UtcOffset::local_offset_at(DateTime::unix_epoch());
+    /// assert!(offset.as_hours().abs() < 24);
+    /// ```
+    ///
+    /// This method is not available with `#![no_std]`.
+    #[inline(always)]
+    #[cfg(feature = "std")]
+    pub fn local_offset_at(datetime: DateTime) -> Self {
+        Self::try_local_offset_at(datetime).unwrap_or(Self::UTC)
+    }
+
+    /// Attempt to obtain the system's UTC offset at the provided instant, which
+    /// is interpreted as UTC. Returns `None` if the offset cannot be
+    /// determined.
+    ///
+    /// See [`UtcOffset::local_offset_at`] for details on how the value is
+    /// obtained.
+    ///
+    /// ```rust
+    /// # use time::{DateTime, UtcOffset};
+    /// if let Some(offset) = UtcOffset::try_local_offset_at(DateTime::now()) {
+    ///     assert!(offset.as_hours().abs() < 24);
+    /// }
+    /// ```
+    ///
+    /// This method is not available with `#![no_std]`.
+    #[inline(always)]
+    #[cfg(feature = "std")]
+    pub fn try_local_offset_at(datetime: DateTime) -> Option<Self> {
+        crate::local_offset::try_local_offset_at(datetime)
+    }
 }
 
 /// Methods that allow parsing and formatting the `UtcOffset`.
@@ -220,16 +321,414 @@ impl UtcOffset {
     /// # use time::UtcOffset;
     /// assert_eq!(UtcOffset::parse("+0200", "%z"), Ok(UtcOffset::hours(2)));
     /// assert_eq!(UtcOffset::parse("-0200", "%z"), Ok(UtcOffset::hours(-2)));
+    /// assert_eq!(UtcOffset::parse("+05", "%z"), Ok(UtcOffset::hours(5)));
+    /// assert_eq!(UtcOffset::parse("+05:30", "%:z"), Ok(UtcOffset::minutes(330)));
+    /// assert_eq!(UtcOffset::parse("Z", "%z"), Ok(UtcOffset::UTC));
     /// ```
     #[inline(always)]
     pub fn parse(s: &str, format: &str) -> ParseResult<Self> {
-        Self::try_from_parsed_items(parse(s, format, Language::en)?)
+        Self::try_from_parsed_items(&parse(s, format, Language::en)?)
+    }
+
+    /// Attempt to parse a `UtcOffset` using the provided string. Any error includes
+    /// the position in the input at which it occurred.
+    ///
+    /// ```rust
+    /// # use time::{ParseError, UtcOffset};
+    /// assert_eq!(
+    ///     UtcOffset::parse_with_position("+0200", "%z"),
+    ///     Ok(UtcOffset::hours(2))
+    /// );
+    /// let error = UtcOffset::parse_with_position("offset +25:00", "offset %:z").unwrap_err();
+    /// assert_eq!(error.kind(), ParseError::InvalidOffset);
+    /// assert_eq!(error.index(), 7);
+    /// ```
+    #[inline(always)]
+    pub fn parse_with_position(s: &str, format: &str) -> Result<Self, ParseErrorAt> {
+        parse_with_position(s, format, Language::en, Self::try_from_parsed_items)
+    }
+
+    /// Attempt to parse a `UtcOffset` using the provided string, returning an error if
+    /// any input remains once the format is exhausted.
+    ///
+    /// ```rust
+    /// # use time::{ParseError, UtcOffset};
+    /// assert_eq!(UtcOffset::parse_strict("+0200", "%z"), Ok(UtcOffset::hours(2)));
+    /// assert_eq!(
+    ///     UtcOffset::parse_strict("+0200garbage", "%z"),
+    ///     Err(ParseError::UnexpectedTrailingCharacters)
+    /// );
+    /// ```
+    #[inline(always)]
+    pub fn parse_strict(s: &str, format: &str) -> ParseResult<Self> {
+        Self::try_from_parsed_items(&parse_strict(s, format, Language::en)?)
+    }
+
+    /// Attempt to parse a `UtcOffset` from a string such as `GMT`, `UTC-08`, or
+    /// `GMT+5:30`. The `GMT` or `UTC` prefix is required, and may be followed by
+    /// a signed hour of one or two digits and optionally two digits of minutes,
+    /// which may be separated from the hour by a colon.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::from_offset_string("GMT"), Ok(UtcOffset::UTC));
+    /// assert_eq!(UtcOffset::from_offset_string("UTC-08"), Ok(UtcOffset::hours(-8)));
+    /// assert_eq!(UtcOffset::from_offset_string("GMT+5:30"), Ok(UtcOffset::minutes(330)));
+    /// ```
+    #[inline]
+    pub fn from_offset_string(mut s: &str) -> ParseResult<Self> {
+        use crate::{
+            format::parse::{
+                try_consume_char, try_consume_digits_in_range, try_consume_exact_digits_in_range,
+                try_consume_first_match,
+            },
+            format::Padding,
+            Sign,
+        };
+
+        try_consume_first_match(&mut s, [("GMT", ()), ("UTC", ())].iter().cloned())
+            .ok_or(ParseError::InvalidOffset)?;
+
+        if s.is_empty() {
+            return Ok(Self::UTC);
+        }
+
+        let sign = try_consume_first_match(
+            &mut s,
+            [("+", Sign::Positive), ("-", Sign::Negative)]
+                .iter()
+                .cloned(),
+        )
+        .ok_or(ParseError::InvalidOffset)?;
+
+        let hours: i16 =
+            try_consume_digits_in_range(&mut s, 1..=2, 0..24).ok_or(ParseError::InvalidOffset)?;
+
+        let minutes: i16 = if s.is_empty() {
+            0
+        } else {
+            let _ = try_consume_char(&mut s, ':');
+            try_consume_exact_digits_in_range(&mut s, 2, 0..60, Padding::Zero)
+                .ok_or(ParseError::InvalidOffset)?
+        };
+
+        if !s.is_empty() {
+            return Err(ParseError::InvalidOffset);
+        }
+
+        Ok(Self::minutes(sign * (hours * 60 + minutes)))
+    }
+
+    /// Attempt to parse a `UtcOffset` from a signed number of hours, which may
+    /// have a fractional part, such as `+5.5` or `-8`. The fractional part may
+    /// have up to nine digits, and must correspond to a whole number of
+    /// seconds.
+    ///
+    /// ```rust
+    /// # use time::{ParseError, UtcOffset};
+    /// assert_eq!(UtcOffset::from_decimal_hours_string("+5.5"), Ok(UtcOffset::minutes(330)));
+    /// assert_eq!(UtcOffset::from_decimal_hours_string("-8"), Ok(UtcOffset::hours(-8)));
+    /// assert_eq!(UtcOffset::from_decimal_hours_string("+5.25"), Ok(UtcOffset::minutes(315)));
+    /// assert_eq!(
+    ///     UtcOffset::from_decimal_hours_string("+5.0001"),
+    ///     Err(ParseError::InvalidOffset)
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_decimal_hours_string(mut s: &str) -> ParseResult<Self> {
+        use crate::{
+            format::parse::{
+                try_consume_char, try_consume_digits, try_consume_digits_in_range,
+                try_consume_first_match,
+            },
+            Sign,
+        };
+
+        let sign = try_consume_first_match(
+            &mut s,
+            [("+", Sign::Positive), ("-", Sign::Negative)]
+                .iter()
+                .cloned(),
+        )
+        .ok_or(ParseError::InvalidOffset)?;
+
+        let hours: i32 =
+            try_consume_digits_in_range(&mut s, 1..=2, 0..24).ok_or(ParseError::InvalidOffset)?;
+
+        let seconds: i32 = if try_consume_char(&mut s, '.').is_ok() {
+            let length = s.len();
+            let fraction: u64 =
+                try_consume_digits(&mut s, 1..=9).ok_or(ParseError::InvalidOffset)?;
+            #[allow(clippy::cast_possible_truncation)]
+            let scale = 10_u64.pow((length - s.len()) as u32);
+
+            let seconds = fraction * 3_600 / scale;
+            if seconds * scale != fraction * 3_600 {
+                return Err(ParseError::InvalidOffset);
+            }
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                seconds as i32
+            }
+        } else {
+            0
+        };
+
+        if !s.is_empty() {
+            return Err(ParseError::InvalidOffset);
+        }
+
+        Ok(Self::seconds(sign * (hours * 3_600 + seconds)))
     }
 
     /// Given the items already parsed, attempt to create a `UtcOffset`.
     #[inline(always)]
-    pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
-        items.offset.ok_or(ParseError::InsufficientInformation)
+    pub(crate) fn try_from_parsed_items(items: &ParsedItems) -> ParseResult<Self> {
+        items.offset.ok_or(ParseError::InsufficientInformation {
+            missing: Component::Offset,
+        })
+    }
+}
+
+impl FromStr for UtcOffset {
+    type Err = ParseError;
+
+    /// Parse a `UtcOffset` in any of the common ISO 8601 forms: `Z`, `±HH`,
+    /// `±HHMM`, `±HH:MM`, or `±HH:MM:SS`.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!("Z".parse::<UtcOffset>(), Ok(UtcOffset::UTC));
+    /// assert_eq!("+02".parse::<UtcOffset>(), Ok(UtcOffset::hours(2)));
+    /// assert_eq!("-0530".parse::<UtcOffset>(), Ok(UtcOffset::minutes(-330)));
+    /// assert_eq!("+02:00".parse::<UtcOffset>(), Ok(UtcOffset::hours(2)));
+    /// assert_eq!("+01:02:03".parse::<UtcOffset>(), Ok(UtcOffset::seconds(3_723)));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> ParseResult<Self> {
+        let bytes = s.as_bytes();
+        let format = match bytes.len() {
+            1 | 3 | 5 => "%z",
+            6 if bytes[3] == b':' => "%:z",
+            9 if bytes[3] == b':' && bytes[6] == b':' => "%::z",
+            _ => return Err(ParseError::InvalidOffset),
+        };
+
+        Self::parse(s, format)
+    }
+}
+
+/// The largest magnitude of a `UtcOffset` resulting from arithmetic, in
+/// seconds.
+const MAX_ARITHMETIC_SECONDS: i64 = 86_399;
+
+/// Clamp the number of seconds to the range supported by arithmetic on a
+/// `UtcOffset`.
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)]
+fn saturating_offset(seconds: i64) -> UtcOffset {
+    UtcOffset::seconds(
+        seconds
+            .max(-MAX_ARITHMETIC_SECONDS)
+            .min(MAX_ARITHMETIC_SECONDS) as i32,
+    )
+}
+
+impl Add<Duration> for UtcOffset {
+    type Output = Self;
+
+    /// Add the `Duration` to the `UtcOffset`. Any fractional seconds of the
+    /// `Duration` are truncated, and the result saturates at ±23:59:59.
+    ///
+    /// ```rust
+    /// # use time::{Duration, UtcOffset};
+    /// assert_eq!(
+    ///     UtcOffset::hours(2) + Duration::minutes(30),
+    ///     UtcOffset::minutes(150)
+    /// );
+    /// assert_eq!(
+    ///     UtcOffset::hours(23) + Duration::hours(2),
+    ///     UtcOffset::seconds(86_399)
+    /// );
+    /// ```
+    #[inline(always)]
+    fn add(self, duration: Duration) -> Self::Output {
+        saturating_offset((self.seconds as i64).saturating_add(duration.whole_seconds()))
+    }
+}
+
+impl AddAssign<Duration> for UtcOffset {
+    /// Add the `Duration` to the existing `UtcOffset`. Any fractional seconds
+    /// of the `Duration` are truncated, and the result saturates at ±23:59:59.
+    ///
+    /// ```rust
+    /// # use time::{Duration, UtcOffset};
+    /// let mut offset = UtcOffset::hours(2);
+    /// offset += Duration::minutes(30);
+    /// assert_eq!(offset, UtcOffset::minutes(150));
+    /// ```
+    #[inline(always)]
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for UtcOffset {
+    type Output = Self;
+
+    /// Subtract the `Duration` from the `UtcOffset`. Any fractional seconds of
+    /// the `Duration` are truncated, and the result saturates at ±23:59:59.
+    ///
+    /// ```rust
+    /// # use time::{Duration, UtcOffset};
+    /// assert_eq!(
+    ///     UtcOffset::hours(2) - Duration::minutes(30),
+    ///     UtcOffset::minutes(90)
+    /// );
+    /// assert_eq!(
+    ///     UtcOffset::hours(-23) - Duration::hours(2),
+    ///     UtcOffset::seconds(-86_399)
+    /// );
+    /// ```
+    #[inline(always)]
+    fn sub(self, duration: Duration) -> Self::Output {
+        saturating_offset((self.seconds as i64).saturating_sub(duration.whole_seconds()))
+    }
+}
+
+impl SubAssign<Duration> for UtcOffset {
+    /// Subtract the `Duration` from the existing `UtcOffset`. Any fractional
+    /// seconds of the `Duration` are truncated, and the result saturates at
+    /// ±23:59:59.
+    ///
+    /// ```rust
+    /// # use time::{Duration, UtcOffset};
+    /// let mut offset = UtcOffset::hours(2);
+    /// offset -= Duration::minutes(30);
+    /// assert_eq!(offset, UtcOffset::minutes(90));
+    /// ```
+    #[inline(always)]
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+/// Human-readable formats (such as JSON) use the `±HH:MM` representation,
+/// followed by `:SS` when the offset has a seconds component. Compact formats
+/// use the number of seconds as an `i32`.
+///
+/// Previous versions serialized the `UtcOffset` as a struct with a single
+/// `seconds` field. For compact formats, this is identical to the current
+/// representation. For human-readable formats, the struct representation is
+/// still accepted when deserializing, allowing existing data to be read and
+/// rewritten in the new format.
+#[cfg(feature = "serde")]
+impl serde::Serialize for UtcOffset {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use core::fmt::Write;
+
+        if !serializer.is_human_readable() {
+            return serializer.serialize_i32(self.seconds);
+        }
+
+        let seconds = self.seconds.abs();
+        let mut s = String::new();
+
+        // Writing to a `String` cannot fail.
+        let _ = write!(
+            s,
+            "{}{:02}:{:02}",
+            if self.seconds < 0 { '-' } else { '+' },
+            seconds / 3_600,
+            seconds / 60 % 60,
+        );
+        if seconds % 60 != 0 {
+            let _ = write!(s, ":{:02}", seconds % 60);
+        }
+
+        serializer.serialize_str(&s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for UtcOffset {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        use crate::format::{
+            parse::{try_consume_char, try_consume_exact_digits_in_range, try_consume_first_match},
+            Padding,
+        };
+        use core::fmt::{self, Formatter};
+        use serde::de::{Error, MapAccess, Unexpected, Visitor};
+
+        /// Visitor accepting either the `±HH:MM[:SS]` string or the legacy
+        /// struct representation.
+        struct UtcOffsetVisitor;
+
+        impl<'de> Visitor<'de> for UtcOffsetVisitor {
+            type Value = UtcOffset;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("a UTC offset in the format `±HH:MM[:SS]`")
+            }
+
+            #[inline]
+            fn visit_str<E: Error>(self, value: &str) -> Result<UtcOffset, E> {
+                let mut s = value;
+                let seconds = (|| {
+                    let sign =
+                        try_consume_first_match(&mut s, [("+", 1), ("-", -1)].iter().cloned())?;
+                    let hours: i32 =
+                        try_consume_exact_digits_in_range(&mut s, 2, 0..24, Padding::Zero)?;
+                    try_consume_char(&mut s, ':').ok()?;
+                    let minutes: i32 =
+                        try_consume_exact_digits_in_range(&mut s, 2, 0..60, Padding::Zero)?;
+                    let seconds: i32 = if s.is_empty() {
+                        0
+                    } else {
+                        try_consume_char(&mut s, ':').ok()?;
+                        try_consume_exact_digits_in_range(&mut s, 2, 0..60, Padding::Zero)?
+                    };
+
+                    if s.is_empty() {
+                        Some(sign * (hours * 3_600 + minutes * 60 + seconds))
+                    } else {
+                        None
+                    }
+                })();
+
+                seconds
+                    .map(UtcOffset::seconds)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+            }
+
+            #[inline]
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<UtcOffset, A::Error> {
+                let mut seconds = None;
+
+                while let Some(key) = map.next_key::<String>()? {
+                    if key != "seconds" {
+                        return Err(A::Error::unknown_field(&key, &["seconds"]));
+                    }
+                    if seconds.is_some() {
+                        return Err(A::Error::duplicate_field("seconds"));
+                    }
+                    seconds = Some(map.next_value()?);
+                }
+
+                seconds
+                    .map(UtcOffset::seconds)
+                    .ok_or_else(|| A::Error::missing_field("seconds"))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(UtcOffsetVisitor)
+        } else {
+            i32::deserialize(deserializer).map(Self::seconds)
+        }
     }
 }
 
@@ -279,6 +778,65 @@ mod test {
         assert_eq!(UtcOffset::west_seconds(1), UtcOffset::seconds(-1));
     }
 
+    #[test]
+    fn round_to_minutes() {
+        let offset = UtcOffset::seconds(5 * 3_600 + 30 * 60 + 44);
+        assert_eq!(offset.round_to_minutes(), UtcOffset::minutes(331));
+        assert_eq!(
+            UtcOffset::seconds(-offset.as_seconds()).round_to_minutes(),
+            UtcOffset::minutes(-331)
+        );
+        assert_eq!(UtcOffset::seconds(29).round_to_minutes(), UtcOffset::UTC);
+        assert_eq!(
+            UtcOffset::seconds(30).round_to_minutes(),
+            UtcOffset::minutes(1)
+        );
+        assert_eq!(
+            UtcOffset::seconds(-30).round_to_minutes(),
+            UtcOffset::minutes(-1)
+        );
+        assert_eq!(
+            UtcOffset::minutes(90).round_to_minutes(),
+            UtcOffset::minutes(90)
+        );
+        assert_eq!(
+            UtcOffset::seconds(86_399).round_to_minutes(),
+            UtcOffset::minutes(1_439)
+        );
+        assert_eq!(
+            UtcOffset::seconds(-86_399).round_to_minutes(),
+            UtcOffset::minutes(-1_439)
+        );
+    }
+
+    #[test]
+    fn round_to_hours() {
+        let offset = UtcOffset::seconds(5 * 3_600 + 30 * 60 + 44);
+        assert_eq!(offset.round_to_hours(), UtcOffset::hours(6));
+        assert_eq!(
+            UtcOffset::seconds(-offset.as_seconds()).round_to_hours(),
+            UtcOffset::hours(-6)
+        );
+        assert_eq!(
+            UtcOffset::minutes(329).round_to_hours(),
+            UtcOffset::hours(5)
+        );
+        assert_eq!(
+            UtcOffset::minutes(-30).round_to_hours(),
+            UtcOffset::hours(-1)
+        );
+        assert_eq!(UtcOffset::hours(5).round_to_hours(), UtcOffset::hours(5));
+        assert_eq!(UtcOffset::UTC.round_to_hours(), UtcOffset::UTC);
+        assert_eq!(
+            UtcOffset::minutes(1_430).round_to_hours(),
+            UtcOffset::hours(23)
+        );
+        assert_eq!(
+            UtcOffset::minutes(-1_430).round_to_hours(),
+            UtcOffset::hours(-23)
+        );
+    }
+
     #[test]
     fn as_hours() {
         assert_eq!(UtcOffset::hours(1).as_hours(), 1);
@@ -305,6 +863,114 @@ mod test {
         assert_eq!(UtcOffset::hours(-1).as_duration(), Duration::hours(-1));
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn local_offset_at() {
+        use crate::Date;
+
+        let datetime = Date::from_ymd(2019, 1, 1).midnight();
+        let offset = UtcOffset::local_offset_at(datetime);
+        assert!(offset.as_seconds().abs() < 86_400);
+        assert_eq!(
+            UtcOffset::try_local_offset_at(datetime).unwrap_or(UtcOffset::UTC),
+            offset
+        );
+    }
+
+    #[test]
+    fn add_duration() {
+        assert_eq!(
+            UtcOffset::hours(2) + Duration::minutes(30),
+            UtcOffset::minutes(150)
+        );
+        assert_eq!(
+            UtcOffset::hours(2) + Duration::minutes(-30),
+            UtcOffset::minutes(90)
+        );
+        assert_eq!(
+            UtcOffset::hours(-1) + Duration::hours(2),
+            UtcOffset::hours(1)
+        );
+
+        // Fractional seconds are truncated.
+        assert_eq!(
+            UtcOffset::UTC + Duration::milliseconds(1_999),
+            UtcOffset::seconds(1)
+        );
+        assert_eq!(
+            UtcOffset::UTC + Duration::milliseconds(-1_999),
+            UtcOffset::seconds(-1)
+        );
+        assert_eq!(
+            UtcOffset::UTC + Duration::nanoseconds(999_999_999),
+            UtcOffset::UTC
+        );
+
+        // Saturation
+        assert_eq!(
+            UtcOffset::hours(23) + Duration::hours(2),
+            UtcOffset::seconds(86_399)
+        );
+        assert_eq!(
+            UtcOffset::hours(-23) + Duration::hours(-2),
+            UtcOffset::seconds(-86_399)
+        );
+        assert_eq!(
+            UtcOffset::UTC + Duration::seconds(i64::max_value()),
+            UtcOffset::seconds(86_399)
+        );
+        assert_eq!(
+            UtcOffset::hours(1) + -Duration::seconds(i64::max_value()),
+            UtcOffset::seconds(-86_399)
+        );
+    }
+
+    #[test]
+    fn add_assign_duration() {
+        let mut offset = UtcOffset::hours(2);
+        offset += Duration::minutes(30);
+        assert_eq!(offset, UtcOffset::minutes(150));
+        offset += Duration::days(1);
+        assert_eq!(offset, UtcOffset::seconds(86_399));
+    }
+
+    #[test]
+    fn sub_duration() {
+        assert_eq!(
+            UtcOffset::hours(2) - Duration::minutes(30),
+            UtcOffset::minutes(90)
+        );
+        assert_eq!(
+            UtcOffset::hours(1) - Duration::hours(2),
+            UtcOffset::hours(-1)
+        );
+        assert_eq!(
+            UtcOffset::UTC - Duration::milliseconds(1_500),
+            UtcOffset::seconds(-1)
+        );
+        assert_eq!(
+            UtcOffset::hours(-23) - Duration::hours(2),
+            UtcOffset::seconds(-86_399)
+        );
+        assert_eq!(
+            UtcOffset::hours(23) - Duration::hours(-2),
+            UtcOffset::seconds(86_399)
+        );
+        assert_eq!(
+            UtcOffset::hours(-1) - -Duration::seconds(i64::max_value()),
+            UtcOffset::seconds(86_399)
+        );
+    }
+
+    #[test]
+    fn sub_assign_duration() {
+        let mut offset = UtcOffset::hours(2);
+        offset -= Duration::minutes(30);
+        assert_eq!(offset, UtcOffset::minutes(90));
+        offset -= Duration::days(2);
+        assert_eq!(offset, UtcOffset::seconds(-86_399));
+    }
+
     #[test]
     fn utc_is_zero() {
         assert_eq!(UtcOffset::UTC, UtcOffset::hours(0));
@@ -324,6 +990,50 @@ mod test {
         // Seconds are not displayed, but the sign can still change.
         assert_eq!(UtcOffset::seconds(1).format("%z"), "+0000");
         assert_eq!(UtcOffset::seconds(-1).format("%z"), "-0000");
+
+        assert_eq!(UtcOffset::hours(2).format("%:z"), "+02:00");
+        assert_eq!(UtcOffset::minutes(-330).format("%:z"), "-05:30");
+        assert_eq!(UtcOffset::UTC.format("%:z"), "+00:00");
+        assert_eq!(UtcOffset::hours(2).format("%::z"), "+02:00:00");
+        assert_eq!(UtcOffset::seconds(-3_723).format("%::z"), "-01:02:03");
+        assert_eq!(UtcOffset::seconds(59).format("%::z"), "+00:00:59");
+    }
+
+    #[test]
+    fn format_zone_name() {
+        assert_eq!(UtcOffset::UTC.format("%Z"), "UTC");
+        assert_eq!(UtcOffset::hours(-8).format("%Z"), "-08:00");
+        assert_eq!(UtcOffset::seconds(1_172).format("%Z"), "+00:19:32");
+    }
+
+    #[test]
+    fn parse_zone_name() {
+        use crate::format::{parse, Component};
+
+        /// Parse the string, returning the offset found, if any.
+        fn zone(s: &str, format: &str) -> ParseResult<Option<UtcOffset>> {
+            parse(s, format, Language::en).map(|items| items.offset)
+        }
+
+        // Other abbreviations are consumed, but cannot be resolved.
+        assert_eq!(zone("PST", "%Z"), Ok(None));
+        assert_eq!(zone("EST)", "%Z)"), Ok(None));
+        assert_eq!(
+            UtcOffset::parse("PST", "%Z"),
+            Err(ParseError::InsufficientInformation {
+                missing: Component::Offset
+            })
+        );
+
+        for &name in &["UTC", "GMT"] {
+            assert_eq!(zone(name, "%Z"), Ok(Some(UtcOffset::UTC)));
+            assert_eq!(UtcOffset::parse(name, "%Z"), Ok(UtcOffset::UTC));
+        }
+
+        assert_eq!(UtcOffset::parse("-08:00", "%Z"), Ok(UtcOffset::hours(-8)));
+        assert_eq!(zone("-08:00", "%Z"), Ok(Some(UtcOffset::hours(-8))));
+        assert_eq!(UtcOffset::parse("", "%Z"), Err(ParseError::InvalidOffset));
+        assert_eq!(UtcOffset::parse("1", "%Z"), Err(ParseError::InvalidOffset));
     }
 
     #[test]
@@ -332,12 +1042,395 @@ mod test {
         assert_eq!(UtcOffset::parse("-0100", "%z"), Ok(UtcOffset::hours(-1)));
         assert_eq!(UtcOffset::parse("+0000", "%z"), Ok(UtcOffset::UTC));
         assert_eq!(UtcOffset::parse("-0000", "%z"), Ok(UtcOffset::UTC));
+        assert_eq!(
+            UtcOffset::parse("-0530", "%z"),
+            Ok(UtcOffset::minutes(-330))
+        );
+        assert_eq!(UtcOffset::parse("+01", "%z"), Ok(UtcOffset::hours(1)));
+        assert_eq!(UtcOffset::parse("-05", "%z"), Ok(UtcOffset::hours(-5)));
+        assert_eq!(UtcOffset::parse("Z", "%z"), Ok(UtcOffset::UTC));
+        assert_eq!(
+            UtcOffset::parse("+010", "%z"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(UtcOffset::parse("z", "%z"), Err(ParseError::InvalidOffset));
 
         assert_eq!(UtcOffset::minutes(1).format("%z"), "+0001");
         assert_eq!(UtcOffset::minutes(-1).format("%z"), "-0001");
 
-        // Seconds are not displayed, but the sign can still change.
-        assert_eq!(UtcOffset::seconds(1).format("%z"), "+0000");
-        assert_eq!(UtcOffset::seconds(-1).format("%z"), "-0000");
+        // Seconds are only accepted by `%::z`, so digits following `%z` are
+        // left for the next item.
+        assert_eq!(
+            UtcOffset::parse("+010203", "%z"),
+            Ok(UtcOffset::minutes(62))
+        );
+        assert_eq!(
+            UtcOffset::parse("+010260", "%::z"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::parse("+01020", "%::z"),
+            Err(ParseError::InvalidOffset)
+        );
+
+        // Local mean time in Amsterdam was +00:19:32.
+        for &offset in &[
+            UtcOffset::UTC,
+            UtcOffset::seconds(1),
+            UtcOffset::seconds(-1),
+            UtcOffset::seconds(1_172),
+            UtcOffset::minutes(-330),
+            UtcOffset::seconds(-86_399),
+        ] {
+            assert_eq!(UtcOffset::parse(&offset.format("%::z"), "%::z"), Ok(offset));
+        }
+
+        assert_eq!(UtcOffset::parse("+02:00", "%:z"), Ok(UtcOffset::hours(2)));
+        assert_eq!(UtcOffset::parse("+0200", "%:z"), Ok(UtcOffset::hours(2)));
+        assert_eq!(
+            UtcOffset::parse("-05:30", "%:z"),
+            Ok(UtcOffset::minutes(-330))
+        );
+        assert_eq!(
+            UtcOffset::parse("+02:", "%:z"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::parse("-01:02:03", "%::z"),
+            Ok(UtcOffset::seconds(-3_723))
+        );
+        assert_eq!(
+            UtcOffset::parse("+010203", "%::z"),
+            Ok(UtcOffset::seconds(3_723))
+        );
+        assert_eq!(
+            UtcOffset::parse("+01:02", "%::z"),
+            Ok(UtcOffset::minutes(62))
+        );
+        assert_eq!(UtcOffset::parse("+01", "%::z"), Ok(UtcOffset::hours(1)));
+        assert_eq!(UtcOffset::parse("Z", "%::z"), Ok(UtcOffset::UTC));
+        assert_eq!(
+            UtcOffset::parse("+01:02:", "%::z"),
+            Err(ParseError::InvalidOffset)
+        );
+
+        // Each of these forms is accepted by `%:z`.
+        assert_eq!(UtcOffset::parse("+05", "%:z"), Ok(UtcOffset::hours(5)));
+        assert_eq!(
+            UtcOffset::parse("+0530", "%:z"),
+            Ok(UtcOffset::minutes(330))
+        );
+        assert_eq!(
+            UtcOffset::parse("+05:30", "%:z"),
+            Ok(UtcOffset::minutes(330))
+        );
+        assert_eq!(
+            UtcOffset::parse("-05:30", "%:z"),
+            Ok(UtcOffset::minutes(-330))
+        );
+        assert_eq!(UtcOffset::parse("Z", "%:z"), Ok(UtcOffset::UTC));
+
+        for &offset in &[
+            UtcOffset::UTC,
+            UtcOffset::hours(2),
+            UtcOffset::minutes(-330),
+            UtcOffset::seconds(-3_723),
+        ] {
+            assert_eq!(UtcOffset::parse(&offset.format("%::z"), "%::z"), Ok(offset));
+            assert_eq!(
+                UtcOffset::parse(&offset.format("%:z"), "%:z"),
+                Ok(UtcOffset::minutes(offset.as_minutes()))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_with_position() {
+        assert_eq!(
+            UtcOffset::parse_with_position("+0200", "%z"),
+            Ok(UtcOffset::hours(2))
+        );
+
+        assert_eq!(
+            UtcOffset::parse_with_position("+2500", "%z")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((ParseError::InvalidOffset, 0))
+        );
+
+        assert_eq!(
+            UtcOffset::parse_with_position("offset +25:00", "offset %:z")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((ParseError::InvalidOffset, 7))
+        );
+
+        assert_eq!(
+            UtcOffset::parse_with_position("UTC+02:00", "GMT%:z")
+                .map_err(|error| (error.kind(), error.index())),
+            Err((
+                ParseError::UnexpectedCharacter {
+                    expected: 'G',
+                    actual: 'U'
+                },
+                0
+            ))
+        );
+        assert_eq!(
+            UtcOffset::parse_with_position("UTC+02:00", "GMT%:z")
+                .map_err(|error| error.to_string()),
+            Err("expected character `G`, found `U` at byte 0".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_strict() {
+        assert_eq!(
+            UtcOffset::parse_strict("+0200", "%z"),
+            Ok(UtcOffset::hours(2))
+        );
+        assert_eq!(
+            UtcOffset::parse_strict("+0200garbage", "%z"),
+            Err(ParseError::UnexpectedTrailingCharacters)
+        );
+        assert_eq!(
+            UtcOffset::parse_strict("+0200 ", "%z"),
+            Err(ParseError::UnexpectedTrailingCharacters)
+        );
+        assert_eq!(
+            UtcOffset::parse_strict("+2500", "%z"),
+            Err(ParseError::InvalidOffset)
+        );
+
+        // Non-strict parsing is unchanged.
+        assert_eq!(
+            UtcOffset::parse("+0200garbage", "%z"),
+            Ok(UtcOffset::hours(2))
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("Z".parse::<UtcOffset>(), Ok(UtcOffset::UTC));
+        assert_eq!("+00".parse::<UtcOffset>(), Ok(UtcOffset::UTC));
+        assert_eq!("-00:00".parse::<UtcOffset>(), Ok(UtcOffset::UTC));
+        assert_eq!("+02".parse::<UtcOffset>(), Ok(UtcOffset::hours(2)));
+        assert_eq!("-08".parse::<UtcOffset>(), Ok(UtcOffset::hours(-8)));
+        assert_eq!("+0200".parse::<UtcOffset>(), Ok(UtcOffset::hours(2)));
+        assert_eq!("-0530".parse::<UtcOffset>(), Ok(UtcOffset::minutes(-330)));
+        assert_eq!("+02:00".parse::<UtcOffset>(), Ok(UtcOffset::hours(2)));
+        assert_eq!("-05:30".parse::<UtcOffset>(), Ok(UtcOffset::minutes(-330)));
+        assert_eq!(
+            "+01:02:03".parse::<UtcOffset>(),
+            Ok(UtcOffset::seconds(3_723))
+        );
+        assert_eq!(
+            "-01:02:03".parse::<UtcOffset>(),
+            Ok(UtcOffset::seconds(-3_723))
+        );
+
+        for s in &[
+            "",
+            "z",
+            "+",
+            "+2",
+            "02",
+            "+24",
+            "+02:",
+            "+0260",
+            "+020000",
+            "+02000",
+            "+02:00:",
+            "+0200:00",
+            "+02:00:60",
+            "02:00",
+            "Z+02",
+            "+02:00Z",
+        ] {
+            assert_eq!(
+                s.parse::<UtcOffset>(),
+                Err(ParseError::InvalidOffset),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn from_offset_string() {
+        assert_eq!(UtcOffset::from_offset_string("GMT"), Ok(UtcOffset::UTC));
+        assert_eq!(UtcOffset::from_offset_string("UTC"), Ok(UtcOffset::UTC));
+        assert_eq!(UtcOffset::from_offset_string("GMT+0"), Ok(UtcOffset::UTC));
+        assert_eq!(UtcOffset::from_offset_string("GMT-0"), Ok(UtcOffset::UTC));
+        assert_eq!(
+            UtcOffset::from_offset_string("GMT+5"),
+            Ok(UtcOffset::hours(5))
+        );
+        assert_eq!(
+            UtcOffset::from_offset_string("UTC-08"),
+            Ok(UtcOffset::hours(-8))
+        );
+        assert_eq!(
+            UtcOffset::from_offset_string("GMT+5:30"),
+            Ok(UtcOffset::minutes(330))
+        );
+        assert_eq!(
+            UtcOffset::from_offset_string("UTC-0930"),
+            Ok(UtcOffset::minutes(-570))
+        );
+        assert_eq!(
+            UtcOffset::from_offset_string("UTC+23:59"),
+            Ok(UtcOffset::minutes(1_439))
+        );
+
+        assert_eq!(
+            UtcOffset::from_offset_string("+05"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::from_offset_string("GMT5"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::from_offset_string("GMT+24"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::from_offset_string("GMT+5:60"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::from_offset_string("GMT+5:3"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::from_offset_string("GMT+5:30 "),
+            Err(ParseError::InvalidOffset)
+        );
+    }
+
+    #[test]
+    fn from_decimal_hours_string() {
+        assert_eq!(
+            UtcOffset::from_decimal_hours_string("+5.5"),
+            Ok(UtcOffset::minutes(330))
+        );
+        assert_eq!(
+            UtcOffset::from_decimal_hours_string("-8"),
+            Ok(UtcOffset::hours(-8))
+        );
+        assert_eq!(
+            UtcOffset::from_decimal_hours_string("+5.25"),
+            Ok(UtcOffset::minutes(315))
+        );
+        assert_eq!(
+            UtcOffset::from_decimal_hours_string("+5.75"),
+            Ok(UtcOffset::minutes(345))
+        );
+        assert_eq!(
+            UtcOffset::from_decimal_hours_string("-03.5"),
+            Ok(UtcOffset::minutes(-210))
+        );
+        assert_eq!(
+            UtcOffset::from_decimal_hours_string("+0"),
+            Ok(UtcOffset::UTC)
+        );
+        assert_eq!(
+            UtcOffset::from_decimal_hours_string("-0.0"),
+            Ok(UtcOffset::UTC)
+        );
+        assert_eq!(
+            UtcOffset::from_decimal_hours_string("+5.500000000"),
+            Ok(UtcOffset::minutes(330))
+        );
+        assert_eq!(
+            UtcOffset::from_decimal_hours_string("+0.0025"),
+            Ok(UtcOffset::seconds(9))
+        );
+        assert_eq!(
+            UtcOffset::from_decimal_hours_string("+23.99"),
+            Ok(UtcOffset::seconds(86_364))
+        );
+
+        for s in &[
+            "",
+            "5.5",
+            "+",
+            "+.5",
+            "+5.",
+            "+5,5",
+            "+5.0001",
+            "+5.123456789",
+            "+5.5000000000",
+            "+24",
+            "+123",
+            "+5.5 ",
+            "UTC+5.5",
+        ] {
+            assert_eq!(
+                UtcOffset::from_decimal_hours_string(s),
+                Err(ParseError::InvalidOffset),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        use serde_test::{
+            assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Readable, Token,
+        };
+
+        assert_tokens(&UtcOffset::UTC.readable(), &[Token::Str("+00:00")]);
+        assert_tokens(&UtcOffset::hours(2).readable(), &[Token::Str("+02:00")]);
+        assert_tokens(&UtcOffset::hours(-2).readable(), &[Token::Str("-02:00")]);
+        assert_tokens(
+            &UtcOffset::minutes(-330).readable(),
+            &[Token::Str("-05:30")],
+        );
+        assert_tokens(
+            &UtcOffset::seconds(3_723).readable(),
+            &[Token::Str("+01:02:03")],
+        );
+        assert_tokens(
+            &UtcOffset::seconds(-86_399).readable(),
+            &[Token::Str("-23:59:59")],
+        );
+
+        assert_tokens(&UtcOffset::UTC.compact(), &[Token::I32(0)]);
+        assert_tokens(&UtcOffset::hours(2).compact(), &[Token::I32(7_200)]);
+        assert_tokens(&UtcOffset::hours(-2).compact(), &[Token::I32(-7_200)]);
+
+        // The legacy struct representation is still accepted.
+        assert_de_tokens(
+            &UtcOffset::hours(2).readable(),
+            &[
+                Token::Struct {
+                    name: "UtcOffset",
+                    len: 1,
+                },
+                Token::Str("seconds"),
+                Token::I32(7_200),
+                Token::StructEnd,
+            ],
+        );
+
+        for &invalid in &[
+            "02:00",
+            "+2:00",
+            "+0200",
+            "+24:00",
+            "+02:60",
+            "+02:00:",
+            "+02:00:00:00",
+        ] {
+            assert_de_tokens_error::<Readable<UtcOffset>>(
+                &[Token::Str(invalid)],
+                &format!(
+                    "invalid value: string \"{}\", expected a UTC offset in the format `±HH:MM[:SS]`",
+                    invalid
+                ),
+            );
+        }
     }
 }
@@ -1,9 +1,14 @@
+use crate::{
+    format::parse::{try_consume_first_match_ci, ParseResult},
+    Language, ParseError,
+};
+use core::str::FromStr;
+
 /// Days of the week.
 ///
 /// As order is dependent on context (Sunday could be either
 /// two days after or five days before Friday), this type does not implement
 /// `PartialOrd` or `Ord`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Weekday {
     #[allow(clippy::missing_docs_in_private_items)]
@@ -23,7 +28,53 @@ pub enum Weekday {
 }
 use Weekday::{Friday, Monday, Saturday, Sunday, Thursday, Tuesday, Wednesday};
 
+/// All weekdays, starting with Monday.
+const WEEKDAYS: [Weekday; 7] = [
+    Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+];
+
 impl Weekday {
+    /// Iterate over all weekdays, starting with Monday.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// let mut weekdays = Weekday::iter();
+    /// assert_eq!(weekdays.next(), Some(Weekday::Monday));
+    /// assert_eq!(weekdays.last(), Some(Weekday::Sunday));
+    /// ```
+    #[inline(always)]
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        WEEKDAYS.iter().copied()
+    }
+
+    /// Get the weekday that is `n` days after Monday, wrapping around after
+    /// Sunday.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::nth_from_monday(0), Weekday::Monday);
+    /// assert_eq!(Weekday::nth_from_monday(6), Weekday::Sunday);
+    /// assert_eq!(Weekday::nth_from_monday(8), Weekday::Tuesday);
+    /// ```
+    #[inline(always)]
+    pub fn nth_from_monday(n: u8) -> Self {
+        WEEKDAYS[(n % 7) as usize]
+    }
+
+    /// Get the weekday that is `n` days after Sunday, wrapping around after
+    /// Saturday.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::nth_from_sunday(0), Weekday::Sunday);
+    /// assert_eq!(Weekday::nth_from_sunday(1), Weekday::Monday);
+    /// assert_eq!(Weekday::nth_from_sunday(13), Weekday::Saturday);
+    /// ```
+    #[inline(always)]
+    pub fn nth_from_sunday(n: u8) -> Self {
+        Self::nth_from_monday(n % 7 + 6)
+    }
+
     /// Get the previous weekday.
     ///
     /// ```rust
@@ -117,12 +168,246 @@ impl Weekday {
     pub const fn number_days_from_sunday(self) -> u8 {
         (self as u8 + 1) % 7
     }
+
+    /// Parse a weekday from its full or abbreviated name in the provided
+    /// language, without regard to case.
+    ///
+    /// ```rust
+    /// # use time::{Language, ParseError, Weekday};
+    /// assert_eq!(Weekday::parse("Monday", Language::en), Ok(Weekday::Monday));
+    /// assert_eq!(Weekday::parse("mon", Language::en), Ok(Weekday::Monday));
+    /// assert_eq!(Weekday::parse("Dienstag", Language::de), Ok(Weekday::Tuesday));
+    /// assert_eq!(
+    ///     Weekday::parse("Mond", Language::en),
+    ///     Err(ParseError::InvalidDayOfWeek)
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse(s: &str, language: Language) -> ParseResult<Self> {
+        let mut remaining = s;
+        let names = language.week_days();
+        let short_names = language.short_week_days();
+
+        match try_consume_first_match_ci(
+            &mut remaining,
+            names
+                .iter()
+                .zip(WEEKDAYS.iter())
+                .chain(short_names.iter().zip(WEEKDAYS.iter()))
+                .map(|(&name, &weekday)| (name, weekday)),
+        ) {
+            Some(weekday) if remaining.is_empty() => Ok(weekday),
+            _ => Err(ParseError::InvalidDayOfWeek),
+        }
+    }
+
+    /// Get the weekday from its full or abbreviated name in the provided
+    /// language, without regard to case. Equivalent to [`Weekday::parse`],
+    /// returning `None` if the name is not recognized.
+    ///
+    /// ```rust
+    /// # use time::{Language, Weekday};
+    /// assert_eq!(Weekday::from_str_in("jeudi", Language::fr), Some(Weekday::Thursday));
+    /// assert_eq!(Weekday::from_str_in("JU", Language::es), Some(Weekday::Thursday));
+    /// assert_eq!(Weekday::from_str_in("Thursday", Language::fr), None);
+    /// ```
+    #[inline(always)]
+    pub fn from_str_in(s: &str, language: Language) -> Option<Self> {
+        Self::parse(s, language).ok()
+    }
+
+    /// Get the weekday with the provided ISO 8601 weekday number (`1`-`7`).
+    /// Equivalent to [`Weekday::from_number_from_monday`].
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_iso_weekday_number(1), Some(Weekday::Monday));
+    /// assert_eq!(Weekday::from_iso_weekday_number(0), None);
+    /// ```
+    #[inline(always)]
+    pub fn from_iso_weekday_number(number: u8) -> Option<Self> {
+        Self::from_number_from_monday(number)
+    }
+
+    /// Get the weekday that is the provided one-indexed number of days from
+    /// Monday (`1`-`7`).
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_number_from_monday(7), Some(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_number_from_monday(8), None);
+    /// ```
+    #[inline(always)]
+    pub fn from_number_from_monday(number: u8) -> Option<Self> {
+        Self::from_number_days_from_monday(number.checked_sub(1)?)
+    }
+
+    /// Get the weekday that is the provided one-indexed number of days from
+    /// Sunday (`1`-`7`).
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_number_from_sunday(1), Some(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_number_from_sunday(0), None);
+    /// ```
+    #[inline(always)]
+    pub fn from_number_from_sunday(number: u8) -> Option<Self> {
+        Self::from_number_days_from_sunday(number.checked_sub(1)?)
+    }
+
+    /// Get the weekday that is the provided zero-indexed number of days from
+    /// Monday (`0`-`6`).
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_number_days_from_monday(0), Some(Weekday::Monday));
+    /// assert_eq!(Weekday::from_number_days_from_monday(7), None);
+    /// ```
+    #[inline(always)]
+    pub fn from_number_days_from_monday(number: u8) -> Option<Self> {
+        WEEKDAYS.get(number as usize).copied()
+    }
+
+    /// Get the weekday that is the provided zero-indexed number of days from
+    /// Sunday (`0`-`6`).
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_number_days_from_sunday(0), Some(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_number_days_from_sunday(7), None);
+    /// ```
+    #[inline(always)]
+    pub fn from_number_days_from_sunday(number: u8) -> Option<Self> {
+        match number {
+            0 => Some(Sunday),
+            1..=6 => Self::from_number_days_from_monday(number - 1),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Weekday {
+    type Err = ParseError;
+
+    /// Parse a weekday from its full or abbreviated English name, without
+    /// regard to case.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!("Friday".parse(), Ok(Weekday::Friday));
+    /// assert_eq!("fri".parse(), Ok(Weekday::Friday));
+    /// ```
+    #[inline(always)]
+    fn from_str(s: &str) -> ParseResult<Self> {
+        Self::parse(s, Language::en)
+    }
+}
+
+/// Human-readable formats (such as JSON) use the English name of the weekday.
+/// Compact formats use the [ISO weekday number](Weekday::iso_weekday_number).
+/// When deserializing a human-readable format, either representation is
+/// accepted.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Weekday {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer
+                .serialize_str(Language::en.week_days()[self.number_days_from_monday() as usize])
+        } else {
+            serializer.serialize_u8(self.iso_weekday_number())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for Weekday {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::fmt::{self, Formatter};
+        use serde::de::{Error, Unexpected, Visitor};
+
+        /// Visitor accepting either the English name or the ISO number.
+        struct WeekdayVisitor;
+
+        impl Visitor<'_> for WeekdayVisitor {
+            type Value = Weekday;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("the name of a weekday or its ISO number (1-7)")
+            }
+
+            #[inline]
+            fn visit_str<E: Error>(self, value: &str) -> Result<Weekday, E> {
+                Language::en
+                    .week_days()
+                    .iter()
+                    .position(|&name| name == value)
+                    .map(|index| WEEKDAYS[index])
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+            }
+
+            // Allow the lint, as the value is checked to be in range.
+            #[inline]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn visit_u64<E: Error>(self, value: u64) -> Result<Weekday, E> {
+                match value {
+                    1..=7 => Ok(WEEKDAYS[value as usize - 1]),
+                    _ => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+                }
+            }
+
+            // Allow the lint, as the value is checked to be in range.
+            #[inline]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn visit_i64<E: Error>(self, value: i64) -> Result<Weekday, E> {
+                match value {
+                    1..=7 => Ok(WEEKDAYS[value as usize - 1]),
+                    _ => Err(E::invalid_value(Unexpected::Signed(value), &self)),
+                }
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(WeekdayVisitor)
+        } else {
+            deserializer.deserialize_u8(WeekdayVisitor)
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn iter() {
+        assert_eq!(Weekday::iter().len(), 7);
+        assert!(Weekday::iter().eq(WEEKDAYS.iter().copied()));
+        assert_eq!(Weekday::iter().next_back(), Some(Sunday));
+        for (weekday, next) in Weekday::iter().zip(Weekday::iter().skip(1)) {
+            assert_eq!(weekday.next(), next);
+        }
+    }
+
+    #[test]
+    fn nth() {
+        for (n, weekday) in (0..).zip(Weekday::iter()) {
+            assert_eq!(Weekday::nth_from_monday(n), weekday);
+            assert_eq!(Weekday::nth_from_monday(n + 7), weekday);
+            assert_eq!(Weekday::nth_from_sunday(n + 1), weekday);
+            assert_eq!(Weekday::nth_from_sunday(n + 8), weekday);
+            assert_eq!(
+                Weekday::nth_from_sunday(weekday.number_days_from_sunday()),
+                weekday
+            );
+        }
+        assert_eq!(Weekday::nth_from_sunday(0), Sunday);
+        assert_eq!(Weekday::nth_from_monday(u8::max_value()), Thursday);
+        assert_eq!(Weekday::nth_from_sunday(u8::max_value()), Wednesday);
+    }
+
     #[test]
     fn previous() {
         assert_eq!(Sunday.previous(), Saturday);
@@ -199,4 +484,164 @@ mod test {
         assert_eq!(Friday.number_days_from_sunday(), 5);
         assert_eq!(Saturday.number_days_from_sunday(), 6);
     }
+
+    #[test]
+    fn number_round_trip() {
+        for &weekday in &WEEKDAYS {
+            assert_eq!(
+                Weekday::from_iso_weekday_number(weekday.iso_weekday_number()),
+                Some(weekday)
+            );
+            assert_eq!(
+                Weekday::from_number_from_monday(weekday.number_from_monday()),
+                Some(weekday)
+            );
+            assert_eq!(
+                Weekday::from_number_from_sunday(weekday.number_from_sunday()),
+                Some(weekday)
+            );
+            assert_eq!(
+                Weekday::from_number_days_from_monday(weekday.number_days_from_monday()),
+                Some(weekday)
+            );
+            assert_eq!(
+                Weekday::from_number_days_from_sunday(weekday.number_days_from_sunday()),
+                Some(weekday)
+            );
+        }
+
+        for number in 0..=6 {
+            assert_eq!(
+                Weekday::from_number_days_from_monday(number).map(Weekday::number_days_from_monday),
+                Some(number)
+            );
+            assert_eq!(
+                Weekday::from_number_days_from_sunday(number).map(Weekday::number_days_from_sunday),
+                Some(number)
+            );
+        }
+        for number in 1..=7 {
+            assert_eq!(
+                Weekday::from_iso_weekday_number(number).map(Weekday::iso_weekday_number),
+                Some(number)
+            );
+            assert_eq!(
+                Weekday::from_number_from_monday(number).map(Weekday::number_from_monday),
+                Some(number)
+            );
+            assert_eq!(
+                Weekday::from_number_from_sunday(number).map(Weekday::number_from_sunday),
+                Some(number)
+            );
+        }
+
+        assert_eq!(Weekday::from_iso_weekday_number(0), None);
+        assert_eq!(Weekday::from_iso_weekday_number(8), None);
+        assert_eq!(Weekday::from_number_from_monday(0), None);
+        assert_eq!(Weekday::from_number_from_sunday(8), None);
+        assert_eq!(Weekday::from_number_days_from_monday(7), None);
+        assert_eq!(Weekday::from_number_days_from_sunday(7), None);
+        assert_eq!(Weekday::from_number_days_from_sunday(u8::max_value()), None);
+    }
+
+    #[test]
+    fn parse() {
+        for (&weekday, (&name, &short_name)) in WEEKDAYS.iter().zip(
+            Language::en
+                .week_days()
+                .iter()
+                .zip(Language::en.short_week_days().iter()),
+        ) {
+            assert_eq!(name.parse(), Ok(weekday));
+            assert_eq!(short_name.parse(), Ok(weekday));
+            assert_eq!(name.to_lowercase().parse(), Ok(weekday));
+            assert_eq!(short_name.to_uppercase().parse(), Ok(weekday));
+        }
+
+        assert_eq!(Weekday::parse("MIÉRCOLES", Language::es), Ok(Wednesday));
+        assert_eq!(Weekday::parse("sáb", Language::pt), Ok(Saturday));
+        assert_eq!(Weekday::parse("dim", Language::fr), Ok(Sunday));
+
+        for &s in &["", "M", "Mond", "Mondays", " Monday", "Montag"] {
+            assert_eq!(s.parse::<Weekday>(), Err(ParseError::InvalidDayOfWeek));
+        }
+    }
+
+    #[test]
+    fn from_str_in() {
+        for &language in &[Language::en, Language::es, Language::fr] {
+            for (&weekday, (&name, &short_name)) in WEEKDAYS.iter().zip(
+                language
+                    .week_days()
+                    .iter()
+                    .zip(language.short_week_days().iter()),
+            ) {
+                assert_eq!(Weekday::from_str_in(name, language), Some(weekday));
+                assert_eq!(Weekday::from_str_in(short_name, language), Some(weekday));
+                assert_eq!(
+                    Weekday::from_str_in(&name.to_uppercase(), language),
+                    Some(weekday)
+                );
+                assert_eq!(
+                    Weekday::from_str_in(&short_name.to_lowercase(), language),
+                    Some(weekday)
+                );
+            }
+        }
+
+        assert_eq!(Weekday::from_str_in("Sunday", Language::en), Some(Sunday));
+        assert_eq!(Weekday::from_str_in("DOMINGO", Language::es), Some(Sunday));
+        assert_eq!(Weekday::from_str_in("Sáb", Language::es), None);
+        assert_eq!(
+            Weekday::from_str_in("mercredi", Language::fr),
+            Some(Wednesday)
+        );
+        assert_eq!(Weekday::from_str_in("MER", Language::fr), Some(Wednesday));
+        assert_eq!(Weekday::from_str_in("Wednesday", Language::fr), None);
+        assert_eq!(Weekday::from_str_in("miércole", Language::es), None);
+        assert_eq!(Weekday::from_str_in("", Language::en), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        use serde_test::{
+            assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Readable,
+            Token,
+        };
+
+        for (&weekday, &name) in WEEKDAYS.iter().zip(&[
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ]) {
+            assert_tokens(&weekday.readable(), &[Token::Str(name)]);
+            assert_tokens(
+                &weekday.compact(),
+                &[Token::U8(weekday.iso_weekday_number())],
+            );
+            assert_de_tokens(
+                &weekday.readable(),
+                &[Token::U8(weekday.iso_weekday_number())],
+            );
+        }
+
+        assert_de_tokens_error::<Readable<Weekday>>(
+            &[Token::Str("monday")],
+            "invalid value: string \"monday\", expected the name of a weekday or its ISO number \
+             (1-7)",
+        );
+        assert_de_tokens_error::<Readable<Weekday>>(
+            &[Token::U8(0)],
+            "invalid value: integer `0`, expected the name of a weekday or its ISO number (1-7)",
+        );
+        assert_de_tokens_error::<Compact<Weekday>>(
+            &[Token::U8(8)],
+            "invalid value: integer `8`, expected the name of a weekday or its ISO number (1-7)",
+        );
+    }
 }
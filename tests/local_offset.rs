@@ -0,0 +1,29 @@
+//! The `TZ` environment variable is changed here, which must not happen while
+//! other threads may be reading it. This is the only test in the binary, so
+//! nothing else runs alongside it.
+
+#![cfg(all(feature = "std", unix))]
+
+use time::{Date, UtcOffset};
+
+#[test]
+fn local_offset_at() {
+    std::env::set_var("TZ", "EST5EDT,M3.2.0,M11.1.0");
+
+    assert_eq!(
+        UtcOffset::local_offset_at(Date::from_ymd(2019, 1, 1).midnight()),
+        UtcOffset::hours(-5)
+    );
+    assert_eq!(
+        UtcOffset::local_offset_at(Date::from_ymd(2019, 7, 1).midnight()),
+        UtcOffset::hours(-4)
+    );
+    assert_eq!(
+        UtcOffset::local_offset_at(Date::from_ymd(2019, 3, 10).with_hms(6, 59, 59)),
+        UtcOffset::hours(-5)
+    );
+    assert_eq!(
+        UtcOffset::local_offset_at(Date::from_ymd(2019, 3, 10).with_hms(7, 0, 0)),
+        UtcOffset::hours(-4)
+    );
+}